@@ -0,0 +1,10 @@
+module and_2_seq_swapped
+(
+input a,
+input b,
+input c,
+output y
+);
+
+assign y = (c & (b & a));
+endmodule
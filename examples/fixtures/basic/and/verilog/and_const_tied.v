@@ -0,0 +1,8 @@
+module and_const_tied
+(
+input a,
+output y
+);
+
+assign y = a & 1'b0;
+endmodule
@@ -0,0 +1,10 @@
+module and_inout
+(
+    input a,
+    inout p,
+    output y
+);
+
+    assign y = a & p;
+
+endmodule
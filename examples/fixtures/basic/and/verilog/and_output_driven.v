@@ -0,0 +1,13 @@
+module and_output_driven
+(
+    input a,
+    input b,
+    input c,
+    output t,
+    output y
+);
+
+    assign t = b | c;
+    assign y = a & t;
+
+endmodule
@@ -0,0 +1,12 @@
+module and_tree_cross
+(
+input a,
+input b,
+input c,
+output y
+);
+
+wire t1 = a & b;
+wire t2 = t1 & c;
+assign y = t1 & t2;
+endmodule
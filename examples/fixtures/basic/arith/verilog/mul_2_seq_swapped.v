@@ -0,0 +1,10 @@
+module mul_2_seq_swapped
+(
+input [3:0] a,
+input [3:0] b,
+input [3:0] c,
+output [3:0] y
+);
+
+assign y = (c * (b * a));
+endmodule
@@ -0,0 +1,24 @@
+module cwe1191_fixed (
+    input [15:0] Data_in,
+    input Clk,
+    input resetn,
+    input write,
+    input lock_status,
+    input jtag_debug_en,
+    input debug_auth_ok,
+    output reg [15:0] Data_out
+);
+
+// The JTAG debug enable only takes effect once the debugger has authenticated.
+always @(posedge Clk or negedge resetn)
+if (~resetn) begin
+    Data_out <= 16'h0000;
+end
+else if ((write & ~lock_status) | (jtag_debug_en & debug_auth_ok)) begin
+    Data_out <= Data_in;
+end
+else begin
+    Data_out <= Data_out;
+end
+
+endmodule
@@ -0,0 +1,23 @@
+module cwe1191_simple (
+    input [15:0] Data_in,
+    input Clk,
+    input resetn,
+    input write,
+    input lock_status,
+    input jtag_debug_en,
+    output reg [15:0] Data_out
+);
+
+// The JTAG debug enable overrides the lock without any authentication.
+always @(posedge Clk or negedge resetn)
+if (~resetn) begin
+    Data_out <= 16'h0000;
+end
+else if ((write & ~lock_status) | jtag_debug_en) begin
+    Data_out <= Data_in;
+end
+else begin
+    Data_out <= Data_out;
+end
+
+endmodule
@@ -0,0 +1,36 @@
+module cwe1231_fixed (
+    input [15:0] Data_in,
+    input Clk,
+    input resetn,
+    input write,
+    input lock_write,
+    input lock_in,
+    output reg [15:0] Data_out
+);
+
+reg lock_status;
+
+// Once set, the lock bit can only be cleared by reset.
+always @(posedge Clk or negedge resetn)
+if (~resetn) begin
+    lock_status <= 1'b0;
+end
+else if (lock_write & ~lock_status) begin
+    lock_status <= lock_in;
+end
+else begin
+    lock_status <= lock_status;
+end
+
+always @(posedge Clk or negedge resetn)
+if (~resetn) begin
+    Data_out <= 16'h0000;
+end
+else if (write & ~lock_status) begin
+    Data_out <= Data_in;
+end
+else begin
+    Data_out <= Data_out;
+end
+
+endmodule
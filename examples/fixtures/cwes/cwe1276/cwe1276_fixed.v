@@ -0,0 +1,19 @@
+module cwe1276_fixed (
+    input [7:0] Data_in,
+    input Clk,
+    input resetn,
+    input write,
+    output reg [7:0] Data_out
+);
+
+// The reset value agrees with the documented one.
+(* svql_expected_reset = "8'h00" *)
+always @(posedge Clk or negedge resetn)
+if (~resetn) begin
+    Data_out <= 8'h00;
+end
+else if (write) begin
+    Data_out <= Data_in;
+end
+
+endmodule
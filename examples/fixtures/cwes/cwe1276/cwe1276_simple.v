@@ -0,0 +1,19 @@
+module cwe1276_simple (
+    input [7:0] Data_in,
+    input Clk,
+    input resetn,
+    input write,
+    output reg [7:0] Data_out
+);
+
+// Documented to reset to all zeros, but the logic resets to all ones.
+(* svql_expected_reset = "8'h00" *)
+always @(posedge Clk or negedge resetn)
+if (~resetn) begin
+    Data_out <= 8'hFF;
+end
+else if (write) begin
+    Data_out <= Data_in;
+end
+
+endmodule
@@ -0,0 +1,9 @@
+module and_gate_reordered
+(
+input b,
+input a,
+output y
+);
+
+assign y = a & b;
+endmodule
@@ -0,0 +1 @@
+yosys examples/patterns/security/debug/debug_enable/generate_il.ys
@@ -0,0 +1,8 @@
+module debug_enable(func_en, dbg_en, en);
+input wire func_en;
+input wire dbg_en;
+output wire en;
+
+assign en = func_en | dbg_en;
+
+endmodule
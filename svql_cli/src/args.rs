@@ -4,16 +4,26 @@ use clap::{Parser, ValueEnum};
 use std::fmt;
 use svql_common::{Config, MatchLength, ModuleConfig};
 use svql_driver::DriverKey;
+use svql_query::report::ReportFilter;
 
 // Import the registration macro
 use crate::register_queries;
 
 register_queries!(QueryArg {
-    Cwe1234 => svql_query_lib::security::cwe1234::Cwe1234,
-    Cwe1271 => svql_query_lib::security::cwe1271::Cwe1271,
-    Cwe1280 => svql_query_lib::security::cwe1280::Cwe1280,
-    LockedRegister => svql_query_lib::security::locked_register::LockedRegister,
-
+    Cwe1191 => svql_query_lib::security::cwe1191::Cwe1191
+        : "On-Chip Debug and Test Interface With Improper Access Control",
+    Cwe1231 => svql_query_lib::security::cwe1231::Cwe1231
+        : "Improper Prevention of Lock Bit Modification",
+    Cwe1234 => svql_query_lib::security::cwe1234::Cwe1234
+        : "Internal or Debug Modes Allow Override of Locks",
+    Cwe1271 => svql_query_lib::security::cwe1271::Cwe1271
+        : "Uninitialized Value on Reset of Security Sensitive Register",
+    Cwe1276 => svql_query_lib::security::cwe1276::Cwe1276
+        : "Register Reset Value Contradicts Its Documented Expected Reset",
+    Cwe1280 => svql_query_lib::security::cwe1280::Cwe1280
+        : "Access Control with Stale Access Check",
+    LockedRegister => svql_query_lib::security::locked_register::LockedRegister
+        : "Register with a gating or locking enable signal",
 });
 
 /// Specification for a single design with its own sub-flags.
@@ -25,8 +35,8 @@ pub struct DesignSpec {
     pub module: String,
     /// Use raw import (default: false)
     pub use_raw: bool,
-    /// Match length strategy (default: subset)
-    pub match_length: MatchLengthArg,
+    /// Match length strategy (`None` keeps the base config's value)
+    pub match_length: Option<MatchLengthArg>,
 }
 
 impl DesignSpec {
@@ -34,17 +44,27 @@ impl DesignSpec {
         DriverKey::new(&self.path, &self.module)
     }
 
-    pub fn to_config(&self, parallel: bool) -> Config {
+    /// Layers this design's flags over `base`.
+    ///
+    /// Flags given on the command line win; anything left unset keeps the
+    /// value from `base` (see `Args::base_config`).
+    pub fn to_config(&self, base: &Config, parallel: Option<bool>) -> Config {
         let haystack_options = ModuleConfig {
-            load_raw: self.use_raw,
-            ..Default::default()
+            load_raw: base.haystack_options.load_raw || self.use_raw,
+            ..base.haystack_options.clone()
         };
 
-        Config::builder()
-            .match_length(self.match_length.clone().into())
-            .haystack_options(haystack_options)
-            .parallel(parallel)
-            .build()
+        let mut builder = base
+            .clone()
+            .into_builder()
+            .haystack_options(haystack_options);
+        if let Some(match_length) = &self.match_length {
+            builder = builder.match_length(match_length.clone().into());
+        }
+        if let Some(parallel) = parallel {
+            builder = builder.parallel(parallel);
+        }
+        builder.build()
     }
 }
 
@@ -61,7 +81,7 @@ fn parse_design_spec(s: &str) -> Result<DesignSpec, String> {
     let path = args[0].clone();
     let mut module = None;
     let mut use_raw = false;
-    let mut match_length = MatchLengthArg::NeedleSubsetHaystack;
+    let mut match_length = None;
 
     let mut i = 1;
     while i < args.len() {
@@ -80,20 +100,23 @@ fn parse_design_spec(s: &str) -> Result<DesignSpec, String> {
                 i += 1;
                 if i >= args.len() {
                     return Err(
-                        "--match-len requires a value (first, subset, or exact)".to_string()
+                        "--match-len requires a value (first, subset, exact, partial, or induced)"
+                            .to_string(),
                     );
                 }
-                match_length = match args[i].as_str() {
+                match_length = Some(match args[i].as_str() {
                     "first" => MatchLengthArg::First,
                     "subset" | "needle-subset-haystack" => MatchLengthArg::NeedleSubsetHaystack,
                     "exact" => MatchLengthArg::Exact,
+                    "partial" | "superset-needle" => MatchLengthArg::SupersetNeedle,
+                    "induced" => MatchLengthArg::Induced,
                     other => {
                         return Err(format!(
-                            "Unknown match-len: {}. Use first, subset, or exact",
+                            "Unknown match-len: {}. Use first, subset, exact, partial, or induced",
                             other
                         ));
                     }
-                };
+                });
             }
             _ => {
                 return Err(format!(
@@ -115,6 +138,69 @@ fn parse_design_spec(s: &str) -> Result<DesignSpec, String> {
     })
 }
 
+/// Expands a design spec whose file name contains `*` or `?` wildcards.
+///
+/// Only the final path component may contain wildcards. Each matching file
+/// inherits the spec's module and flags.
+fn expand_design_glob(spec: &DesignSpec) -> std::io::Result<Vec<DesignSpec>> {
+    let pattern = std::path::Path::new(&spec.path);
+    let dir = match pattern.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => std::path::Path::new("."),
+    };
+    let file_pattern = pattern
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let mut paths: Vec<_> = std::fs::read_dir(dir)?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_file())
+        .filter(|entry| wildcard_match(&file_pattern, &entry.file_name().to_string_lossy()))
+        .map(|entry| entry.path())
+        .collect();
+    paths.sort();
+
+    Ok(paths
+        .into_iter()
+        .map(|path| DesignSpec {
+            path: path.to_string_lossy().into_owned(),
+            ..spec.clone()
+        })
+        .collect())
+}
+
+/// Matches `name` against a pattern where `*` is any run of characters and
+/// `?` is any single character.
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star_p, star_n)) => {
+                    p = star_p + 1;
+                    n = star_n + 1;
+                    backtrack = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
 /// SVQL Pattern Matcher - Search for hardware security vulnerabilities
 #[derive(Parser, Debug)]
 #[command(name = "svql")]
@@ -126,15 +212,34 @@ pub struct Args {
         long = "design",
         value_name = "SPEC",
         value_parser = parse_design_spec,
-        required = true,
-        help = "Design spec: \"path.json --module NAME [--raw] [--match-len first|subset|exact]\""
+        required_unless_present_any = ["design_globs", "dedup_patterns"],
+        help = "Design spec: \"path.json --module NAME [--raw] [--match-len first|subset|exact|partial|induced]\""
     )]
     pub designs: Vec<DesignSpec>,
 
+    /// Design specification(s) whose path is a wildcard pattern.
+    #[arg(
+        long = "design-glob",
+        value_name = "SPEC",
+        value_parser = parse_design_spec,
+        help = "Like --design, but `*` and `?` in the file name select every matching file: \"dir/*.json --module NAME\""
+    )]
+    pub design_globs: Vec<DesignSpec>,
+
     /// Enable parallel execution (applies to all designs)
     #[arg(short = 'p', long, default_value_t = false)]
     pub parallel: bool,
 
+    /// Load a base `Config` profile from a TOML or JSON file
+    /// (precedence: defaults < file < command-line flags)
+    #[arg(long = "config", value_name = "PATH")]
+    pub config: Option<std::path::PathBuf>,
+
+    /// Number of worker threads for parallel search and report rendering
+    /// (defaults to rayon's automatic choice; `1` disables parallelism)
+    #[arg(long = "threads", value_name = "N")]
+    pub threads: Option<std::num::NonZeroUsize>,
+
     /// Query types to run (can be specified multiple times).
     #[arg(short = 'q', long = "query", value_enum)]
     pub queries: Vec<QueryArg>,
@@ -143,6 +248,26 @@ pub struct Args {
     #[arg(long = "list-queries")]
     pub list_queries: bool,
 
+    /// Print the execution plan of each selected query and exit
+    #[arg(long = "explain")]
+    pub explain: bool,
+
+    /// Load each design and the needles of each selected query, report cell
+    /// counts and load errors, then exit without searching
+    #[arg(long = "validate")]
+    pub validate: bool,
+
+    /// Group the needle files in this directory into structurally identical
+    /// classes, print the classes, then exit without searching
+    #[arg(long = "dedup-patterns", value_name = "DIR")]
+    pub dedup_patterns: Option<std::path::PathBuf>,
+
+    /// Check each netlist match for functional equivalence with its needle
+    /// and mark it proved, disproved or unknown in the results (needs `z3`
+    /// on the PATH)
+    #[arg(long = "verify")]
+    pub verify: bool,
+
     /// Enable profiling output (timing and memory usage)
     #[arg(long = "profile")]
     pub profile: bool,
@@ -151,6 +276,22 @@ pub struct Args {
     #[arg(long = "print-results")]
     pub print_results: bool,
 
+    /// Format used by `--print-results`
+    #[arg(long = "format", value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+
+    /// Only print results whose deepest recursive tree has at least this depth
+    #[arg(long = "min-depth", value_name = "DEPTH")]
+    pub min_depth: Option<u32>,
+
+    /// Only print results for the given CWE (can be specified multiple times)
+    #[arg(long = "only-cwe", value_name = "CWE")]
+    pub only_cwe: Vec<String>,
+
+    /// Write `--print-results` output to this file instead of stdout
+    #[arg(long = "output", value_name = "PATH")]
+    pub output: Option<std::path::PathBuf>,
+
     /// Output CSV file path for results
     #[arg(short = 'o', long = "output-csv")]
     pub output_csv: Option<String>,
@@ -160,12 +301,92 @@ pub struct Args {
     pub output_latex: Option<String>,
 }
 
+impl Args {
+    /// All designs to scan: explicit `--design` specs followed by the
+    /// expansion of each `--design-glob`, in sorted path order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a glob's directory cannot be read.
+    pub fn all_designs(&self) -> std::io::Result<Vec<DesignSpec>> {
+        let mut designs = self.designs.clone();
+        for spec in &self.design_globs {
+            designs.extend(expand_design_glob(spec)?);
+        }
+        Ok(designs)
+    }
+
+    /// Whether the command line forces parallel execution on or off.
+    ///
+    /// `--threads 1` overrides `--parallel`; any larger thread count implies
+    /// it. Returns `None` when neither flag is given, leaving the choice to
+    /// the base config.
+    pub fn parallel_override(&self) -> Option<bool> {
+        match self.threads {
+            Some(n) => Some(n.get() > 1),
+            None => self.parallel.then_some(true),
+        }
+    }
+
+    /// Builds the config every design starts from.
+    ///
+    /// Precedence is defaults < `--config` file < per-design and global
+    /// flags. Without a file the CLI defaults are used, which differ from
+    /// `Config::default()` in using subset matching. Files ending in `.json`
+    /// are read as JSON, anything else as TOML. `--verify` turns on
+    /// functional verification on top of either.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config file cannot be read or parsed.
+    pub fn base_config(&self) -> Result<Config, Box<dyn core::error::Error>> {
+        let config = match &self.config {
+            Some(path) if path.extension().is_some_and(|ext| ext == "json") => {
+                Config::from_json_file(path)?
+            }
+            Some(path) => Config::from_toml_file(path)?,
+            None => Config::builder()
+                .match_length(MatchLength::NeedleSubsetHaystack)
+                .build(),
+        };
+        if self.verify {
+            Ok(config.into_builder().verify_functional(true).build())
+        } else {
+            Ok(config)
+        }
+    }
+
+    /// Builds the report filter selected by `--min-depth` and `--only-cwe`.
+    pub fn report_filter(&self) -> ReportFilter {
+        ReportFilter {
+            min_tree_depth: self.min_depth,
+            cwe_ids: self.only_cwe.iter().cloned().collect(),
+        }
+    }
+}
+
+/// Output formats for detailed match results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable report trees
+    #[default]
+    Text,
+    /// A JSON array of per-match report trees
+    Json,
+    /// A SARIF 2.1.0 log with one result per match
+    Sarif,
+    /// One Graphviz digraph of matched cells per match
+    Dot,
+}
+
 /// Enumeration of supported match length strategies.
 #[derive(Debug, Clone, ValueEnum)]
 pub enum MatchLengthArg {
     First,
     NeedleSubsetHaystack,
     Exact,
+    SupersetNeedle,
+    Induced,
 }
 
 impl From<MatchLengthArg> for MatchLength {
@@ -174,6 +395,20 @@ impl From<MatchLengthArg> for MatchLength {
             MatchLengthArg::First => Self::First,
             MatchLengthArg::NeedleSubsetHaystack => Self::NeedleSubsetHaystack,
             MatchLengthArg::Exact => Self::Exact,
+            MatchLengthArg::SupersetNeedle => Self::SupersetNeedle,
+            MatchLengthArg::Induced => Self::Induced,
+        }
+    }
+}
+
+impl From<MatchLength> for MatchLengthArg {
+    fn from(length: MatchLength) -> Self {
+        match length {
+            MatchLength::First => Self::First,
+            MatchLength::NeedleSubsetHaystack => Self::NeedleSubsetHaystack,
+            MatchLength::Exact => Self::Exact,
+            MatchLength::SupersetNeedle => Self::SupersetNeedle,
+            MatchLength::Induced => Self::Induced,
         }
     }
 }
@@ -184,6 +419,8 @@ impl fmt::Display for MatchLengthArg {
             Self::First => write!(f, "first"),
             Self::NeedleSubsetHaystack => write!(f, "subset"),
             Self::Exact => write!(f, "exact"),
+            Self::SupersetNeedle => write!(f, "partial"),
+            Self::Induced => write!(f, "induced"),
         }
     }
 }
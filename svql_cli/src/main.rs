@@ -12,9 +12,15 @@ static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 mod args;
 mod query_registry;
 
-use args::{Args, QueryArg};
+use args::{Args, MatchLengthArg, OutputFormat, QueryArg};
 use clap::Parser;
 use query_registry::{export_csv, print_metrics_table};
+use std::fmt::Write as _;
+use std::path::Path;
+use rayon::prelude::*;
+use svql_query::report::{ReportFilter, dot, sarif};
+use svql_query::session::{AnyTable, Store};
+use svql_query::traits::display::ReportNode;
 use tracing::info;
 
 use crate::query_registry::export_latex;
@@ -28,11 +34,21 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let args = Args::parse();
 
+    // Searches and report rendering run on this pool; `0` threads leaves the
+    // size to rayon. The jemalloc allocator keeps per-thread arenas, so a
+    // lower thread count also bounds allocator memory overhead.
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(args.threads.map_or(0, std::num::NonZeroUsize::get))
+        .build()?;
+
     // Handle list-queries flag
     if args.list_queries {
         println!("Available queries:");
         for q in QueryArg::all() {
-            println!("  - {}", q.name());
+            match q.cwe_id() {
+                Some(cwe) => println!("  - {} [{}]: {}", q.name(), cwe, q.description()),
+                None => println!("  - {}: {}", q.name(), q.description()),
+            }
         }
         println!("\nUse -q <QUERY> to run specific queries, or omit -q to run all.");
         println!(
@@ -41,15 +57,39 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         return Ok(());
     }
 
-    if args.designs.is_empty() {
-        eprintln!("Error: No designs specified. Use -d to specify at least one design.");
+    // Handle explain flag
+    if args.explain {
+        let queries = if args.queries.is_empty() {
+            QueryArg::all()
+        } else {
+            args.queries.clone()
+        };
+        for q in &queries {
+            println!("=== Plan for {} ===", q.name());
+            print!("{}", q.explain());
+        }
+        return Ok(());
+    }
+
+    // Handle dedup-patterns flag
+    if let Some(dir) = &args.dedup_patterns {
+        let driver = svql_driver::Driver::new_workspace()?;
+        return dedup_patterns(&driver, dir);
+    }
+
+    let designs = args.all_designs()?;
+    if designs.is_empty() {
+        eprintln!(
+            "Error: No designs specified. Use -d or --design-glob to specify at least one design."
+        );
         std::process::exit(1);
     }
 
+    let base_config = args.base_config()?;
     info!(
         "Running {} design(s) with global parallel={}",
-        args.designs.len(),
-        args.parallel
+        designs.len(),
+        args.parallel_override().unwrap_or(base_config.parallel)
     );
 
     // Initialize driver
@@ -66,17 +106,59 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         args.queries.clone()
     };
 
+    // Handle validate flag: load everything, search nothing
+    if args.validate {
+        let mut ok = true;
+        for design_input in &designs {
+            let key = design_input.key();
+            let design_config = design_input.to_config(&base_config, args.parallel_override());
+            match driver.get_design(&key, &design_config.haystack_options) {
+                Ok(container) => println!(
+                    "[ok]    {} ({}): {} cells",
+                    design_input.path,
+                    design_input.module,
+                    container.index().num_cells()
+                ),
+                Err(e) => {
+                    println!(
+                        "[error] {} ({}): {}",
+                        design_input.path, design_input.module, e
+                    );
+                    ok = false;
+                    continue;
+                }
+            }
+            for query in &queries_to_run {
+                match query.validate(&driver, &key, &design_config) {
+                    Ok(()) => println!("[ok]      {}", query.name()),
+                    Err(e) => {
+                        println!("[error]   {}: {}", query.name(), e);
+                        ok = false;
+                    }
+                }
+            }
+        }
+        if !ok {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     // Execute queries against all designs with their individual configs
     let mut all_metrics = Vec::new();
+    let mut design_matches = Vec::with_capacity(designs.len());
     let mut had_errors = false;
 
-    for design_input in &args.designs {
+    for design_input in &designs {
+        let first_metric = all_metrics.len();
         let key = design_input.key();
-        let design_config = design_input.to_config(args.parallel);
+        let design_config = design_input.to_config(&base_config, args.parallel_override());
+        let use_raw = design_config.haystack_options.load_raw;
+        let match_length = MatchLengthArg::from(design_config.match_length);
 
         info!(
             "Loading design: {} (module: {}, raw: {}, match: {:?})",
-            design_input.path, design_input.module, design_input.use_raw, design_input.match_length
+            design_input.path, design_input.module, use_raw, match_length
         );
 
         let container = match driver.get_design(&key, &design_config.haystack_options) {
@@ -94,16 +176,21 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         for query in &queries_to_run {
             info!("Starting query: {} on {}", query.name(), key.module_name());
 
-            match query.run(
-                &driver,
-                &key,
-                &design_config, // Use design-specific config
-                args.profile,
-                args.print_results,
-                design_input.use_raw,
-                &design_input.match_length,
-                gates,
-            ) {
+            let result = pool.install(|| {
+                query
+                    .run(
+                        &driver,
+                        &key,
+                        &design_config, // Use design-specific config
+                        args.profile,
+                        args.print_results,
+                        use_raw,
+                        &match_length,
+                        gates,
+                    )
+                    .map_err(|e| e.to_string())
+            });
+            match result {
                 Ok(metrics) => {
                     all_metrics.push(metrics);
                 }
@@ -118,6 +205,24 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
             }
         }
+
+        let matches: usize = all_metrics[first_metric..].iter().map(|m| m.matches).sum();
+        design_matches.push((design_input, matches));
+    }
+
+    // Print per-design summary when scanning a batch. Status output goes to
+    // stderr so that stdout only carries the rendered results.
+    if designs.len() > 1 {
+        eprintln!("\n=== Design Summary ===");
+        for (design, matches) in &design_matches {
+            eprintln!("  {} ({}): {} matches", design.path, design.module, matches);
+        }
+        let total: usize = design_matches.iter().map(|(_, m)| m).sum();
+        eprintln!(
+            "Total matches across {} loaded design(s): {}",
+            design_matches.len(),
+            total
+        );
     }
 
     // Print profiling summary table
@@ -130,35 +235,108 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             .filter_map(|m| m.perf.as_ref())
             .map(|p| p.duration)
             .sum();
-        println!(
+        eprintln!(
             "\nTotal matches across all queries and designs: {}",
             total_matches
         );
-        println!("Total execution time: {:.2?}", total_time);
+        eprintln!("Total execution time: {:.2?}", total_time);
     }
 
-    // Print detailed results if requested
-    if args.print_results && !all_metrics.is_empty() {
-        println!("\n=== Detailed Results ===");
+    // Render detailed results if requested
+    let mut output = String::new();
+    let report_filter = args.report_filter();
+    if args.print_results && args.format == OutputFormat::Dot {
+        for metric in &all_metrics {
+            let Some(ref store) = metric.store else {
+                continue;
+            };
+            let Some(table) = store.get_any(metric.root_type_id) else {
+                continue;
+            };
+            let key = svql_driver::DriverKey::new(&metric.design_path, &metric.design_module);
+            let module_config = svql_common::ModuleConfig {
+                load_raw: metric.use_raw,
+                ..Default::default()
+            };
+            let design = driver.get_design(&key, &module_config)?;
+            let rows: Vec<usize> = pool.install(|| {
+                (0..table.len())
+                    .into_par_iter()
+                    .filter(|&row| {
+                        report_filter.is_empty()
+                            || table
+                                .row_to_report_node(row, store, &driver, &key)
+                                .is_some_and(|node| report_filter.matches(&node))
+                    })
+                    .collect()
+            });
+            for row in rows {
+                let cells = dot::wire_cells(&table.row_wires(row, store), design.index());
+                let name = format!("{}_{}_{row}", metric.name, metric.design_module);
+                output.push_str(&dot::cells_to_dot(&name, &cells, design.index()));
+            }
+        }
+    } else if args.print_results && args.format != OutputFormat::Text {
+        let mut reports = Vec::new();
+        for metric in &all_metrics {
+            let Some(ref store) = metric.store else {
+                continue;
+            };
+            if let Some(table) = store.get_any(metric.root_type_id) {
+                let key = svql_driver::DriverKey::new(&metric.design_path, &metric.design_module);
+                reports.extend(render_rows(
+                    &pool,
+                    table,
+                    store,
+                    &driver,
+                    &key,
+                    &report_filter,
+                ));
+            }
+        }
+        let json = if args.format == OutputFormat::Sarif {
+            let truncated = all_metrics.iter().any(|metric| metric.truncated);
+            let run = sarif::Run::from_reports(&reports).with_truncated(truncated);
+            let log = sarif::Log::new(vec![run]);
+            serde_json::to_string_pretty(&log)?
+        } else {
+            serde_json::to_string_pretty(&reports)?
+        };
+        writeln!(output, "{json}")?;
+    } else if args.print_results && !all_metrics.is_empty() {
+        writeln!(output, "\n=== Detailed Results ===")?;
         for metric in &all_metrics {
             if let Some(ref store) = metric.store {
                 if metric.matches == 0 {
                     continue;
                 }
 
-                println!(
-                    "\n--- {} on {} ({} matches) ---",
+                let truncated = if metric.truncated { ", truncated" } else { "" };
+                writeln!(
+                    output,
+                    "\n--- {} on {} ({} matches{truncated}) ---",
                     metric.name, metric.design_module, metric.matches
-                );
+                )?;
 
                 if let Some(table) = store.get_any(metric.root_type_id) {
                     // Create a dummy key for display - in production you'd store the key in metrics
                     let key =
                         svql_driver::DriverKey::new(&metric.design_path, &metric.design_module);
-                    if let Some(node) = table.row_to_report_node(0, store, &driver, &key) {
-                        println!("{}", node.render());
-                        if table.len() > 1 {
-                            println!("... and {} more matches", table.len() - 1);
+                    // Without a filter only the first match is rendered
+                    let (first, more) = if report_filter.is_empty() {
+                        let first = (0..table.len())
+                            .find_map(|row| table.row_to_report_node(row, store, &driver, &key));
+                        (first, table.len().saturating_sub(1))
+                    } else {
+                        let nodes =
+                            render_rows(&pool, table, store, &driver, &key, &report_filter);
+                        let more = nodes.len().saturating_sub(1);
+                        (nodes.into_iter().next(), more)
+                    };
+                    if let Some(node) = first {
+                        writeln!(output, "{}", node.render())?;
+                        if more > 0 {
+                            writeln!(output, "... and {} more matches", more)?;
                         }
                     }
                 }
@@ -166,17 +344,24 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    if let Some(path) = &args.output {
+        info!("Writing results to: {}", path.display());
+        write_output_file(path, &output)?;
+    } else {
+        print!("{output}");
+    }
+
     // Export CSV if requested
     if let Some(csv_path) = args.output_csv {
         info!("Exporting results to CSV: {}", csv_path);
         export_csv(&all_metrics, &csv_path)?;
-        println!("Results exported to: {}", csv_path);
+        eprintln!("Results exported to: {}", csv_path);
     }
 
     if let Some(latex_path) = args.output_latex {
         info!("Exporting results to LaTeX: {}", latex_path);
         export_latex(&all_metrics, &latex_path)?;
-        println!("Results exported to: {}", latex_path);
+        eprintln!("Results exported to: {}", latex_path);
     }
 
     if had_errors {
@@ -186,3 +371,94 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+/// Renders the report nodes of the rows of `table` that pass `filter`.
+///
+/// Rows are rendered in parallel on `pool`; the nodes keep row order.
+fn render_rows(
+    pool: &rayon::ThreadPool,
+    table: &(dyn AnyTable + Send + Sync),
+    store: &Store,
+    driver: &svql_driver::Driver,
+    key: &svql_driver::DriverKey,
+    filter: &ReportFilter,
+) -> Vec<ReportNode> {
+    pool.install(|| {
+        (0..table.len())
+            .into_par_iter()
+            .filter_map(|row| table.row_to_report_node(row, store, driver, key))
+            .filter(|node| filter.matches(node))
+            .collect()
+    })
+}
+
+/// Writes `contents` to `path`, creating parent directories as needed.
+///
+/// The data is written to a sibling temporary file first and then renamed
+/// into place, so readers never observe a partially written file.
+fn write_output_file(path: &Path, contents: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = std::path::PathBuf::from(tmp);
+    std::fs::write(&tmp, contents)?;
+    std::fs::rename(&tmp, path)
+}
+
+/// Loads every design file in `dir` as a needle and prints the groups of
+/// structurally identical ones.
+///
+/// Each file is loaded with its stem as the module name, matching the layout
+/// of `examples/patterns`. Files that fail to load are reported and skipped.
+fn dedup_patterns(
+    driver: &svql_driver::Driver,
+    dir: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut paths: Vec<std::path::PathBuf> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file() && svql_common::DesignPath::new(path.clone()).is_ok())
+        .collect();
+    paths.sort();
+
+    let options = svql_common::ModuleConfig::default();
+    let mut classes: Vec<(
+        std::sync::Arc<svql_driver::design_container::DesignContainer>,
+        Vec<std::path::PathBuf>,
+    )> = Vec::new();
+    for path in paths {
+        let Some(module) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let key = svql_driver::DriverKey::new(&path, module);
+        let container = match driver.get_design(&key, &options) {
+            Ok(c) => c,
+            Err(e) => {
+                eprintln!("[error] {}: {}", path.display(), e);
+                continue;
+            }
+        };
+        match classes
+            .iter_mut()
+            .find(|(repr, _)| svql_query::patterns_equivalent(repr.design(), container.design()))
+        {
+            Some((_, members)) => members.push(path),
+            None => classes.push((container, vec![path])),
+        }
+    }
+
+    let total: usize = classes.iter().map(|(_, members)| members.len()).sum();
+    println!(
+        "{} pattern file(s) in {} equivalence class(es)",
+        total,
+        classes.len()
+    );
+    for (_, members) in classes.iter().filter(|(_, members)| members.len() > 1) {
+        println!("\n{} equivalent files:", members.len());
+        for member in members {
+            println!("  - {}", member.display());
+        }
+    }
+    Ok(())
+}
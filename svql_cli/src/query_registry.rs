@@ -35,6 +35,8 @@ pub struct QueryMetrics {
     pub match_length: String,
     /// Number of matches found (cached for convenience)
     pub matches: usize,
+    /// Whether the search stopped at a configured limit, so matches may be missing
+    pub truncated: bool,
     /// Optional performance metrics (None if profiling disabled)
     pub perf: Option<PerfMetrics>,
     /// The actual query results store containing match data
@@ -59,7 +61,7 @@ impl QueryMetrics {
 
 #[macro_export]
 macro_rules! register_queries {
-    ($enum_name:ident { $($variant:ident => $type:ty),* $(,)? }) => {
+    ($enum_name:ident { $($variant:ident => $type:ty : $desc:literal),* $(,)? }) => {
         #[derive(Debug, Clone, ::clap::ValueEnum)]
         pub enum $enum_name {
             $(
@@ -82,6 +84,46 @@ macro_rules! register_queries {
                 }
             }
 
+            /// Get a one-line description of what this query detects.
+            pub const fn description(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $desc,)*
+                }
+            }
+
+            /// Get the CWE identifier (e.g. `CWE-1234`) if the query is named after one.
+            pub fn cwe_id(&self) -> Option<String> {
+                let digits = self.name().strip_prefix("Cwe")?;
+                digits
+                    .chars()
+                    .all(|c| c.is_ascii_digit())
+                    .then(|| format!("CWE-{digits}"))
+            }
+
+            /// Render the execution plan of this query.
+            pub fn explain(&self) -> String {
+                match self {
+                    $(Self::$variant => {
+                        <$type as ::svql_query::traits::Pattern>::explain()
+                    },)*
+                }
+            }
+
+            /// Load every design the query needs (its needles and the haystack)
+            /// without running the search.
+            pub fn validate(
+                &self,
+                driver: &::svql_driver::Driver,
+                key: &::svql_driver::DriverKey,
+                config: &::svql_common::Config,
+            ) -> Result<(), Box<dyn std::error::Error>> {
+                match self {
+                    $(Self::$variant => {
+                        <$type as ::svql_query::traits::Pattern>::preload_driver(driver, key, config)
+                    },)*
+                }
+            }
+
             /// Run the query with optional profiling and return metrics with results.
             ///
             /// # Arguments
@@ -153,6 +195,11 @@ macro_rules! register_queries {
                         store.get::<$type>().map(|t| t.len()).unwrap_or(0)
                     },)*
                 };
+                let truncated = match self {
+                    $(Self::$variant => {
+                        store.get::<$type>().is_some_and(|t| t.is_truncated())
+                    },)*
+                };
 
                 // Build performance metrics if enabled
                 let perf = if enable_profiling {
@@ -165,18 +212,22 @@ macro_rules! register_queries {
                     None
                 };
 
-                // Output basic summary (always printed)
-                println!("\n=== Results for {} on {} ===", self.name(), key.module_name());
-                println!("Matches found: {}", matches);
+                // Output basic summary (always printed, to stderr so that
+                // stdout only carries the rendered results)
+                eprintln!("\n=== Results for {} on {} ===", self.name(), key.module_name());
+                eprintln!("Matches found: {}", matches);
+                if truncated {
+                    eprintln!("Search truncated by configured limits; results may be incomplete");
+                }
 
                 if let Some(ref p) = perf {
-                    println!("Execution time: {:.2?}", p.duration);
-                    println!("Memory delta: {:.2} MB", p.memory_delta_mb);
+                    eprintln!("Execution time: {:.2?}", p.duration);
+                    eprintln!("Memory delta: {:.2} MB", p.memory_delta_mb);
                 }
 
                 // Print hint about using --print-results if there are matches and we're not printing them now
                 if !print_results && matches > 0 {
-                    println!("({} matches found, use --print-results to display details)", matches);
+                    eprintln!("({} matches found, use --print-results to display details)", matches);
                 }
 
                 Ok($crate::query_registry::QueryMetrics {
@@ -186,6 +237,7 @@ macro_rules! register_queries {
                     use_raw,
                     match_length: format!("{:?}", match_length),
                     matches,
+                    truncated,
                     perf,
                     store: Some(store),
                     root_type_id: match self {
@@ -198,19 +250,19 @@ macro_rules! register_queries {
     };
 }
 
-/// Print a formatted summary table of query metrics.
+/// Print a formatted summary table of query metrics to stderr.
 pub fn print_metrics_table(metrics: &[QueryMetrics]) {
     if metrics.is_empty() {
         return;
     }
 
-    println!(
+    eprintln!(
         "\n╔══════════════════════════════════════════════════════════════════════════════════════╗"
     );
-    println!(
+    eprintln!(
         "║                           SVQL Query Performance Summary                             ║"
     );
-    println!(
+    eprintln!(
         "╠══════════════════════════════════════════════════════════════════════════════════════╣"
     );
 
@@ -218,17 +270,17 @@ pub fn print_metrics_table(metrics: &[QueryMetrics]) {
     let has_perf = metrics.iter().any(|m| m.perf.is_some());
 
     if has_perf {
-        println!(
+        eprintln!(
             "║ {:<12} {:<15} {:<8} {:>10} {:>12} {:>12} ║",
             "Query", "Design", "Config", "Matches", "Time(ms)", "Mem(MB)"
         );
     } else {
-        println!(
+        eprintln!(
             "║ {:<12} {:<15} {:<8} {:>10} {:>30} ║",
             "Query", "Design", "Config", "Matches", "Status"
         );
     }
-    println!(
+    eprintln!(
         "╠══════════════════════════════════════════════════════════════════════════════════════╣"
     );
 
@@ -246,25 +298,35 @@ pub fn print_metrics_table(metrics: &[QueryMetrics]) {
                 "First" => "F",
                 "NeedleSubsetHaystack" => "S",
                 "Exact" => "E",
+                "SupersetNeedle" => "P",
+                "Induced" => "I",
                 _ => "?",
             }
         );
 
+        // A trailing `+` marks a truncated search that may have missed matches
+        let matches = format!("{}{}", m.matches, if m.truncated { "+" } else { "" });
+
         if let Some(ref p) = m.perf {
             let time_ms = p.duration.as_millis();
-            println!(
+            eprintln!(
                 "║ {:<12} {:<15} {:<8} {:>10} {:>12} {:>12.2} ║",
-                m.name, design_short, config_short, m.matches, time_ms, p.memory_delta_mb
+                m.name, design_short, config_short, matches, time_ms, p.memory_delta_mb
             );
         } else {
-            println!(
+            let status = if m.truncated {
+                "Truncated"
+            } else {
+                "Completed"
+            };
+            eprintln!(
                 "║ {:<12} {:<15} {:<8} {:>10} {:>30} ║",
-                m.name, design_short, config_short, m.matches, "Completed"
+                m.name, design_short, config_short, matches, status
             );
         }
     }
 
-    println!(
+    eprintln!(
         "╚══════════════════════════════════════════════════════════════════════════════════════╝"
     );
 
@@ -275,11 +337,11 @@ pub fn print_metrics_table(metrics: &[QueryMetrics]) {
         let total_time: Duration = perf_metrics.iter().map(|p| p.duration).sum();
         let total_memory: f64 = perf_metrics.iter().map(|p| p.memory_delta_mb).sum();
 
-        println!("\nAggregate Statistics:");
-        println!("  Total matches: {}", total_matches);
-        println!("  Total execution time: {:.2?}", total_time);
-        println!("  Total memory delta: {:.2} MB", total_memory);
-        println!(
+        eprintln!("\nAggregate Statistics:");
+        eprintln!("  Total matches: {}", total_matches);
+        eprintln!("  Total execution time: {:.2?}", total_time);
+        eprintln!("  Total memory delta: {:.2} MB", total_memory);
+        eprintln!(
             "  Average time per query: {:.2?}",
             total_time / perf_metrics.len() as u32
         );
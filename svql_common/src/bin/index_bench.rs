@@ -0,0 +1,74 @@
+//! Benchmark comparing serial and parallel `GraphIndex` construction.
+
+use std::time::{Duration, Instant};
+use svql_common::{GraphIndex, ModuleConfig, YosysModule};
+
+/// Builds the index `iterations` times and returns the fastest and mean build time.
+fn time_builds(
+    design: &prjunnamed_netlist::Design,
+    parallel: bool,
+    iterations: usize,
+) -> (Duration, Duration) {
+    let mut times = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let index = GraphIndex::build_with(design, parallel);
+        times.push(start.elapsed());
+        std::hint::black_box(index);
+    }
+    let fastest = times.iter().min().copied().unwrap_or_default();
+    let mean = times.iter().sum::<Duration>() / iterations.max(1) as u32;
+    (fastest, mean)
+}
+
+// EXAMPLES:
+// cargo run --release --bin index_bench
+// cargo run --release --bin index_bench examples/fixtures/basic/and/verilog/small_and_tree.v small_and_tree -n 100
+
+fn main() -> Result<(), Box<dyn core::error::Error>> {
+    use argparse::{ArgumentParser, Store};
+
+    let mut input_file =
+        "examples/fixtures/larger_designs/json/opentitan/otbn_core.json".to_owned();
+    let mut module_name = "otbn_core".to_owned();
+    let mut iterations: usize = 5;
+
+    {
+        let mut ap = ArgumentParser::new();
+        ap.set_description("Compare serial and parallel GraphIndex construction");
+
+        ap.refer(&mut input_file).add_argument(
+            "input",
+            Store,
+            "Input design file (.v, .il, .json, .blif, or .edif)",
+        );
+
+        ap.refer(&mut module_name)
+            .add_argument("module", Store, "Top module name");
+
+        ap.refer(&mut iterations).add_option(
+            &["-n", "--iterations"],
+            Store,
+            "Number of builds per mode",
+        );
+
+        ap.parse_args_or_exit();
+    }
+
+    let yosys_module = YosysModule::new(&input_file, &module_name)?;
+    let design = yosys_module.import_design(&ModuleConfig::default())?;
+    let num_cells = GraphIndex::build(&design).num_cells();
+
+    let (serial_fastest, serial_mean) = time_builds(&design, false, iterations);
+    let (parallel_fastest, parallel_mean) = time_builds(&design, true, iterations);
+
+    println!("{module_name}: {num_cells} cells, {iterations} builds per mode");
+    println!("  serial:   fastest {serial_fastest:?}, mean {serial_mean:?}");
+    println!("  parallel: fastest {parallel_fastest:?}, mean {parallel_mean:?}");
+    println!(
+        "  speedup:  {:.2}x",
+        serial_fastest.as_secs_f64() / parallel_fastest.as_secs_f64().max(f64::EPSILON)
+    );
+
+    Ok(())
+}
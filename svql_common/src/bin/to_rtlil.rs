@@ -1,7 +1,7 @@
 //! CLI utility to convert hardware netlists (Verilog/JSON) to RTLIL format via Yosys.
 
 use std::path::Path;
-use svql_common::{ModuleConfig, YosysModule};
+use svql_common::{ModuleConfig, YosysModule, find_yosys_binary};
 
 /// Invokes Yosys to process a design and output the resulting netlist in RTLIL format.
 ///
@@ -13,7 +13,7 @@ fn write_yosys_to_rtlil(
     config: &ModuleConfig,
     rtlil_out: Option<&Path>,
 ) -> Result<(), Box<dyn core::error::Error>> {
-    let yosys = which::which("yosys")?;
+    let yosys = find_yosys_binary()?;
     rtlil_out.map_or_else(
         || yosys_module.write_rtlil_to_stdout(config, &yosys),
         |path| yosys_module.write_rtlil_to_path(config, path, &yosys),
@@ -39,7 +39,7 @@ fn main() -> Result<(), Box<dyn core::error::Error>> {
         ap.set_description("Convert Verilog/RTLIL/JSON to RTLIL using Yosys");
 
         ap.refer(&mut input_file)
-            .add_argument("input", Store, "Input design file (.v, .il, or .json)")
+            .add_argument("input", Store, "Input design file (.v, .il, .json, .blif, or .edif)")
             .required();
 
         ap.refer(&mut module_name)
@@ -0,0 +1,30 @@
+//! Discovery of the Yosys executable.
+
+use std::path::PathBuf;
+
+/// Environment variable that overrides the Yosys executable location.
+pub const YOSYS_BIN_ENV: &str = "SVQL_YOSYS_BIN";
+
+/// Locates the Yosys executable.
+///
+/// Consults `SVQL_YOSYS_BIN` first and falls back to searching the system
+/// PATH only when the variable is unset or empty.
+///
+/// # Errors
+///
+/// Returns an error if `SVQL_YOSYS_BIN` points at a path that does not exist,
+/// or if the variable is unset and no `yosys` binary is found in PATH.
+pub fn find_yosys_binary() -> Result<PathBuf, String> {
+    if let Some(path) = std::env::var_os(YOSYS_BIN_ENV).filter(|path| !path.is_empty()) {
+        let path = PathBuf::from(path);
+        if !path.exists() {
+            return Err(format!(
+                "{YOSYS_BIN_ENV} is set to '{}', but that path does not exist",
+                path.display()
+            ));
+        }
+        return Ok(path);
+    }
+
+    which::which("yosys").map_err(|e| format!("yosys binary not found in PATH: {e}"))
+}
@@ -3,9 +3,12 @@
 use crate::*;
 use contracts::*;
 use core::hash::{Hash, Hasher};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 /// Configuration options for processing a Yosys module.
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct ModuleConfig {
     /// Whether to flatten the module.
@@ -17,6 +20,7 @@ pub struct ModuleConfig {
     /// Parameters to set on the module.
     pub params: HashMap<String, String>,
     /// Parameters to be set on the module compatable with const functions
+    #[serde(skip)]
     pub const_params: &'static [(&'static str, &'static str)],
     /// Additional Yosys commands to run.
     pub other_steps: Vec<String>,
@@ -24,6 +28,9 @@ pub struct ModuleConfig {
     pub verific: bool,
     /// Whether to load the raw unprocessed design, must be in json format.
     pub load_raw: bool,
+    /// Maximum wall-clock time a Yosys invocation may take before it is killed.
+    /// `None` waits indefinitely.
+    pub timeout: Option<Duration>,
 }
 
 impl ModuleConfig {
@@ -88,6 +95,14 @@ impl ModuleConfig {
         self.load_raw = load_raw;
         self
     }
+
+    /// Sets the maximum time a Yosys invocation may run before it is killed.
+    #[must_use]
+    #[debug_ensures(ret.timeout == Some(timeout))]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
 }
 
 impl Hash for ModuleConfig {
@@ -95,10 +110,10 @@ impl Hash for ModuleConfig {
         self.flatten.hash(state);
         self.opt_clean.hash(state);
         self.opt.hash(state);
-        for (k, v) in &self.params {
-            k.hash(state);
-            v.hash(state);
-        }
+        // Map iteration order is randomly seeded, so hash the params sorted
+        let mut params: Vec<_> = self.params.iter().collect();
+        params.sort();
+        params.hash(state);
         for step in &self.other_steps {
             step.hash(state);
         }
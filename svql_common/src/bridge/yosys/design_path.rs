@@ -12,6 +12,10 @@ pub enum DesignPath {
     Rtlil(PathBuf),
     /// A JSON file (.json).
     Json(PathBuf),
+    /// A BLIF netlist (.blif), e.g. as emitted by ABC.
+    Blif(PathBuf),
+    /// An EDIF netlist (.edif or .edf), e.g. as emitted by vendor tools.
+    Edif(PathBuf),
 }
 
 impl DesignPath {
@@ -20,13 +24,16 @@ impl DesignPath {
     /// # Errors
     ///
     /// Returns an error string if the file extension is missing or not
-    /// recognized as a supported design format (.v, .il, .json).
+    /// recognized as a supported design format (.v, .il, .json, .blif,
+    /// .edif, .edf).
     #[debug_ensures(ret.as_ref().map(|dp| dp.path() == path).unwrap_or(true))]
     pub fn new(path: PathBuf) -> Result<Self, String> {
         match path.extension().and_then(|s| s.to_str()) {
             Some("v") => Ok(Self::Verilog(path.clone())),
             Some("il") => Ok(Self::Rtlil(path.clone())),
             Some("json") => Ok(Self::Json(path.clone())),
+            Some("blif") => Ok(Self::Blif(path.clone())),
+            Some("edif" | "edf") => Ok(Self::Edif(path.clone())),
             _ => Err(format!(
                 "Unsupported design file extension: {:?}",
                 path.extension()
@@ -36,10 +43,12 @@ impl DesignPath {
 
     /// Returns a reference to the underlying path.
     #[must_use]
-    #[debug_ensures(ret == match self { Self::Verilog(p) | Self::Rtlil(p) | Self::Json(p) => p })]
+    #[debug_ensures(ret == match self {
+        Self::Verilog(p) | Self::Rtlil(p) | Self::Json(p) | Self::Blif(p) | Self::Edif(p) => p
+    })]
     pub fn path(&self) -> &Path {
         match self {
-            Self::Verilog(p) | Self::Rtlil(p) | Self::Json(p) => p,
+            Self::Verilog(p) | Self::Rtlil(p) | Self::Json(p) | Self::Blif(p) | Self::Edif(p) => p,
         }
     }
 
@@ -51,6 +60,8 @@ impl DesignPath {
             Self::Verilog(_) => "read_verilog -sv",
             Self::Rtlil(_) => "read_rtlil",
             Self::Json(_) => "read_json",
+            Self::Blif(_) => "read_blif",
+            Self::Edif(_) => "read_edif",
         }
     }
 }
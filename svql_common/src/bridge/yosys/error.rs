@@ -0,0 +1,177 @@
+//! Error types produced by Yosys invocations.
+
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    process::ExitStatus,
+    time::Duration,
+};
+
+/// Error returned when a Yosys invocation exceeds its configured timeout.
+///
+/// The child process is killed and reaped before this error is returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct YosysTimeout {
+    /// The timeout that was exceeded.
+    pub timeout: Duration,
+}
+
+impl fmt::Display for YosysTimeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Yosys execution timed out after {:?}", self.timeout)
+    }
+}
+
+impl core::error::Error for YosysTimeout {}
+
+/// A single located error reported by Yosys, e.g. `design.v:12: ERROR: ...`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YosysDiagnostic {
+    /// The source file the error refers to.
+    pub file: PathBuf,
+    /// The 1-based line number, if reported.
+    pub line: Option<u32>,
+    /// The 1-based column number, if reported.
+    pub column: Option<u32>,
+    /// The error message following the `ERROR:` marker.
+    pub message: String,
+}
+
+impl YosysDiagnostic {
+    /// Parses a single line of Yosys output.
+    ///
+    /// Accepts both the `file:line: ERROR: msg` form and the
+    /// `file:line.col-line.col: ERROR: msg` form emitted by newer frontends.
+    /// Returns `None` for lines that are not located errors.
+    #[must_use]
+    pub fn parse(line: &str) -> Option<Self> {
+        let (location, message) = line.trim().split_once(": ERROR: ")?;
+        let (file, position) = location.rsplit_once(':')?;
+
+        let start = position.split('-').next().unwrap_or(position);
+        let (line_no, column) = match start.split_once('.') {
+            Some((line_no, column)) => (line_no, column.parse().ok()),
+            None => (start, None),
+        };
+
+        Some(Self {
+            file: PathBuf::from(file),
+            line: line_no.parse().ok(),
+            column,
+            message: message.to_owned(),
+        })
+    }
+
+    /// Collects every located error from a block of Yosys output.
+    #[must_use]
+    pub fn parse_all(output: &str) -> Vec<Self> {
+        output.lines().filter_map(Self::parse).collect()
+    }
+}
+
+impl fmt::Display for YosysDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.file.display())?;
+        if let Some(line) = self.line {
+            write!(f, ":{line}")?;
+            if let Some(column) = self.column {
+                write!(f, ".{column}")?;
+            }
+        }
+        write!(f, ": ERROR: {}", self.message)
+    }
+}
+
+/// Error returned when a Yosys process exits unsuccessfully.
+#[derive(Debug, Clone)]
+pub struct YosysExecutionError {
+    /// Exit status of the Yosys process.
+    pub status: ExitStatus,
+    /// Captured standard output.
+    pub stdout: String,
+    /// Captured standard error.
+    pub stderr: String,
+    /// Located errors parsed from the captured output.
+    pub diagnostics: Vec<YosysDiagnostic>,
+}
+
+impl YosysExecutionError {
+    /// Creates an execution error, parsing diagnostics from the captured output.
+    #[must_use]
+    pub fn new(status: ExitStatus, stdout: String, stderr: String) -> Self {
+        let mut diagnostics = YosysDiagnostic::parse_all(&stdout);
+        diagnostics.extend(YosysDiagnostic::parse_all(&stderr));
+        Self {
+            status,
+            stdout,
+            stderr,
+            diagnostics,
+        }
+    }
+
+    /// Rewrites references to `from` in the captured output and diagnostics
+    /// so that they point at `to` instead.
+    pub(crate) fn rename_file(&mut self, from: &Path, to: &Path) {
+        let from_str = from.display().to_string();
+        let to_str = to.display().to_string();
+        self.stdout = self.stdout.replace(&from_str, &to_str);
+        self.stderr = self.stderr.replace(&from_str, &to_str);
+        for diagnostic in &mut self.diagnostics {
+            if diagnostic.file == from {
+                diagnostic.file = to.to_path_buf();
+            }
+        }
+    }
+}
+
+impl fmt::Display for YosysExecutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Yosys execution failed: {}\nSTDOUT: {}\nSTDERR: {}",
+            self.status, self.stdout, self.stderr
+        )
+    }
+}
+
+impl core::error::Error for YosysExecutionError {}
+
+#[cfg(test)]
+mod tests {
+    #![allow(missing_docs)]
+    #![allow(clippy::missing_docs_in_private_items)]
+
+    use super::*;
+
+    #[test]
+    fn test_parse_line_only() {
+        let diag = YosysDiagnostic::parse("design.v:12: ERROR: syntax error, unexpected ';'")
+            .expect("Located error should parse");
+        assert_eq!(diag.file, PathBuf::from("design.v"));
+        assert_eq!(diag.line, Some(12));
+        assert_eq!(diag.column, None);
+        assert_eq!(diag.message, "syntax error, unexpected ';'");
+    }
+
+    #[test]
+    fn test_parse_line_and_column_range() {
+        let diag = YosysDiagnostic::parse("rtl/top.sv:3.14-3.15: ERROR: syntax error")
+            .expect("Located error should parse");
+        assert_eq!(diag.file, PathBuf::from("rtl/top.sv"));
+        assert_eq!(diag.line, Some(3));
+        assert_eq!(diag.column, Some(14));
+    }
+
+    #[test]
+    fn test_parse_ignores_unlocated_lines() {
+        assert!(YosysDiagnostic::parse("ERROR: Module `foo' not found!").is_none());
+        assert!(YosysDiagnostic::parse("-- Running command `proc' --").is_none());
+    }
+
+    #[test]
+    fn test_diagnostic_display_round_trip() {
+        let line = "design.v:7.2: ERROR: unexpected token";
+        let diag = YosysDiagnostic::parse(line).unwrap();
+        assert_eq!(diag.to_string(), line);
+    }
+}
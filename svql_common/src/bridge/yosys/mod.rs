@@ -3,10 +3,16 @@
 //! This module provides types and utilities for interacting with Yosys, including
 //! configuration, design paths, and module handling.
 
+mod binary;
 mod config;
 mod design_path;
+mod error;
 mod module;
+mod retry;
 
+pub use binary::{YOSYS_BIN_ENV, find_yosys_binary};
 pub use config::ModuleConfig;
 pub use design_path::DesignPath;
-pub use module::YosysModule;
+pub use error::{YosysDiagnostic, YosysExecutionError, YosysTimeout};
+pub use module::{ModuleImport, YosysModule};
+pub use retry::RetryPolicy;
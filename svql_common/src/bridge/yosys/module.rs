@@ -2,11 +2,16 @@
 
 use std::{
     fs::File,
+    hash::{Hash, Hasher},
+    io::{Read, Write},
     path::{Path, PathBuf},
-    process::{Command, Stdio},
+    process::{Child, Command, Output, Stdio},
+    sync::mpsc,
+    time::{Duration, Instant},
 };
 
-use super::{DesignPath, ModuleConfig};
+use super::{DesignPath, ModuleConfig, YosysExecutionError, YosysTimeout, find_yosys_binary};
+use crate::util::StableHasher;
 use contracts::*;
 
 /// Represents a specific module within a design file to be processed by Yosys.
@@ -16,8 +21,22 @@ pub struct YosysModule {
     path: DesignPath,
     /// Name of the module to extract.
     module: String,
+    /// Whether the source is already synthesized JSON that must not be re-run through Yosys.
+    presynthesized: bool,
 }
 
+/// The outcome of importing one module as part of a batch, paired with its name.
+pub type ModuleImport = (
+    String,
+    Result<prjunnamed_netlist::Design, Box<dyn core::error::Error>>,
+);
+
+/// Name of the Yosys design slot holding the unprocessed design during batch runs.
+const BATCH_SAVE_SLOT: &str = "svql_batch_base";
+
+/// Synthetic file name reported in errors for designs imported from source text.
+const SOURCE_FILE_NAME: &str = "<stdin>";
+
 /// Internal format identifier for Yosys export commands.
 #[derive(Debug, Clone, Copy)]
 enum OutputFormat {
@@ -54,9 +73,49 @@ impl YosysModule {
         Ok(Self {
             path: design_path,
             module: module.as_ref().to_owned(),
+            presynthesized: false,
+        })
+    }
+
+    /// Creates a `YosysModule` for a pre-synthesized Yosys JSON netlist.
+    /// Resolves relative paths against the workspace root.
+    ///
+    /// Importing such a module parses the JSON directly and never invokes
+    /// Yosys, so the processing steps of the `ModuleConfig` (parameters,
+    /// flattening, optimization) are not applied. The JSON must already be in
+    /// the shape the caller wants to match against.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path does not have a `.json` extension or
+    /// contains invalid characters.
+    #[debug_requires(path.as_ref().to_str().is_some())]
+    #[debug_ensures(ret.is_ok() -> ret.as_ref().unwrap().is_presynthesized())]
+    pub fn from_json<P: AsRef<Path>, S: AsRef<str>>(
+        path: P,
+        module: S,
+    ) -> Result<Self, Box<dyn core::error::Error>> {
+        let module = Self::new(path, module)?;
+        if !matches!(module.design_path(), DesignPath::Json(_)) {
+            return Err(format!(
+                "Expected a pre-synthesized JSON netlist, got {}",
+                module.path().display()
+            )
+            .into());
+        }
+
+        Ok(Self {
+            presynthesized: true,
+            ..module
         })
     }
 
+    /// Returns true if this module refers to a pre-synthesized JSON netlist.
+    #[must_use]
+    pub const fn is_presynthesized(&self) -> bool {
+        self.presynthesized
+    }
+
     /// Returns the categorized design path.
     #[must_use]
     pub const fn design_path(&self) -> &DesignPath {
@@ -82,6 +141,18 @@ impl YosysModule {
         config: &ModuleConfig,
         output_format: OutputFormat,
     ) -> Vec<String> {
+        let mut args = self.generate_read_args(config);
+        args.extend(Self::generate_module_args(
+            self.module_name(),
+            output_path,
+            config,
+            output_format,
+        ));
+        args
+    }
+
+    /// Generates the Yosys commands that read the design source.
+    fn generate_read_args(&self, config: &ModuleConfig) -> Vec<String> {
         let mut args = Vec::new();
 
         args.push("-p".to_owned());
@@ -95,27 +166,30 @@ impl YosysModule {
             ));
         }
 
+        args
+    }
+
+    /// Generates the Yosys commands that elaborate, process and write out a
+    /// single module of an already read design.
+    fn generate_module_args(
+        module_name: &str,
+        output_path: &Path,
+        config: &ModuleConfig,
+        output_format: OutputFormat,
+    ) -> Vec<String> {
+        let mut args = Vec::new();
+
         args.push("-p".to_owned());
-        args.push(format!("hierarchy -top {}", self.module_name()));
+        args.push(format!("hierarchy -top {module_name}"));
 
         for (param, value) in &config.params {
             args.push("-p".to_owned());
-            args.push(format!(
-                "chparam -set {} {} {}",
-                param,
-                value,
-                self.module_name()
-            ));
+            args.push(format!("chparam -set {param} {value} {module_name}"));
         }
 
         for (param, value) in config.const_params.iter() {
             args.push("-p".to_owned());
-            args.push(format!(
-                "chparam -set {} {} {}",
-                param,
-                value,
-                self.module_name()
-            ));
+            args.push(format!("chparam -set {param} {value} {module_name}"));
         }
 
         args.push("-p".to_owned());
@@ -160,47 +234,135 @@ impl YosysModule {
         args
     }
 
+    /// Generates a single Yosys command sequence that processes and writes out
+    /// several modules of the same design source.
+    ///
+    /// The freshly read design is saved once and restored before each module,
+    /// since `hierarchy -top` discards every module outside the selected tree.
+    fn generate_batch_args(
+        &self,
+        modules: &[(&str, &Path)],
+        config: &ModuleConfig,
+        output_format: OutputFormat,
+    ) -> Vec<String> {
+        let mut args = self.generate_read_args(config);
+
+        args.push("-p".to_owned());
+        args.push(format!("design -save {BATCH_SAVE_SLOT}"));
+
+        for (module_name, output_path) in modules {
+            args.push("-p".to_owned());
+            args.push(format!("design -load {BATCH_SAVE_SLOT}"));
+            args.extend(Self::generate_module_args(
+                module_name,
+                output_path,
+                config,
+                output_format,
+            ));
+        }
+
+        args
+    }
+
     /// Executes the Yosys process with the provided arguments.
+    ///
+    /// When `timeout` is set, the process is killed if it has not finished
+    /// before the deadline and a [`YosysTimeout`] error is returned.
     fn execute_yosys(
         args: Vec<String>,
         yosys_binary: &Path,
+        timeout: Option<Duration>,
     ) -> Result<(), Box<dyn core::error::Error>> {
-        let output = Command::new(yosys_binary)
+        let mut command = Command::new(yosys_binary);
+        command
             .args(args)
             .stdin(Stdio::null())
             .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .output()?;
+            .stderr(Stdio::piped());
+
+        let output = match timeout {
+            Some(timeout) => Self::output_with_timeout(command.spawn()?, timeout)?,
+            None => command.output()?,
+        };
 
         if !output.status.success() {
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(format!(
-                "Yosys execution failed: {}\nSTDOUT: {}\nSTDERR: {}",
-                output.status, stdout, stderr
-            )
-            .into());
+            return Err(Box::new(YosysExecutionError::new(
+                output.status,
+                String::from_utf8_lossy(&output.stdout).into_owned(),
+                String::from_utf8_lossy(&output.stderr).into_owned(),
+            )));
         }
 
         Ok(())
     }
 
+    /// Collects the output of a spawned process, killing it if it does not
+    /// finish within `timeout`.
+    ///
+    /// Both pipes are drained on helper threads and the caller blocks on their
+    /// channels, so no polling is involved. Pipes reach end-of-file once the
+    /// process exits; a killed child is always waited on so it gets reaped.
+    fn output_with_timeout(
+        mut child: Child,
+        timeout: Duration,
+    ) -> Result<Output, Box<dyn core::error::Error>> {
+        let deadline = Instant::now() + timeout;
+        let stdout_rx = Self::drain_pipe(child.stdout.take());
+        let stderr_rx = Self::drain_pipe(child.stderr.take());
+
+        let stdout = stdout_rx.recv_timeout(deadline.saturating_duration_since(Instant::now()));
+        let stderr = stderr_rx.recv_timeout(deadline.saturating_duration_since(Instant::now()));
+
+        match (stdout, stderr) {
+            (Ok(stdout), Ok(stderr)) => Ok(Output {
+                status: child.wait()?,
+                stdout,
+                stderr,
+            }),
+            _ => {
+                // The process may exit on its own between the deadline and the
+                // kill, in which case `kill` fails harmlessly.
+                let _ = child.kill();
+                child.wait()?;
+                Err(Box::new(YosysTimeout { timeout }))
+            }
+        }
+    }
+
+    /// Reads a child pipe to completion on a background thread.
+    fn drain_pipe<R: Read + Send + 'static>(pipe: Option<R>) -> mpsc::Receiver<Vec<u8>> {
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            let mut buffer = Vec::new();
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_end(&mut buffer);
+            }
+            let _ = tx.send(buffer);
+        });
+        rx
+    }
+
     /// Imports the design into the internal netlist format by invoking Yosys.
-    /// Automatically locates the yosys binary in the system PATH.
+    ///
+    /// Pre-synthesized modules are parsed directly without looking for Yosys.
     ///
     /// # Errors
     ///
-    /// Returns an error if the Yosys binary is missing, execution fails,
-    /// or the resulting output cannot be parsed.
+    /// Returns an error if [`find_yosys_binary`] finds no Yosys binary,
+    /// execution fails, or the resulting output cannot be parsed.
     pub fn import_design(
         &self,
         module_config: &ModuleConfig,
     ) -> Result<prjunnamed_netlist::Design, Box<dyn core::error::Error>> {
-        let yosys = which::which("yosys").map_err(|_| "yosys binary not found in PATH")?;
+        if self.presynthesized {
+            return self.import_design_raw();
+        }
+        let yosys = find_yosys_binary()?;
         self.import_design_yosys(module_config, &yosys)
     }
 
     /// Imports the design using a specific Yosys binary path.
+    /// Pre-synthesized modules are parsed directly and the binary is unused.
     ///
     /// # Errors
     ///
@@ -211,17 +373,288 @@ impl YosysModule {
         module_config: &ModuleConfig,
         yosys_binary: &Path,
     ) -> Result<prjunnamed_netlist::Design, Box<dyn core::error::Error>> {
+        if self.presynthesized {
+            return self.import_design_raw();
+        }
         let json_temp = tempfile::Builder::new()
             .prefix("svql_")
             .suffix(".json")
             .tempfile()?;
 
         let args = self.generate_yosys_args(json_temp.path(), module_config, OutputFormat::Json);
-        Self::execute_yosys(args, yosys_binary)?;
+        Self::execute_yosys(args, yosys_binary, module_config.timeout)?;
 
         self.parse_json_output(json_temp.path())
     }
 
+    /// Imports the design through an on-disk cache of Yosys JSON output.
+    ///
+    /// When `cache_dir` is `None`, `$XDG_CACHE_HOME/svql` (or `~/.cache/svql`)
+    /// is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`find_yosys_binary`] finds no Yosys binary, no
+    /// cache directory is available, execution fails, or the resulting output
+    /// cannot be parsed.
+    pub fn import_design_cached(
+        &self,
+        module_config: &ModuleConfig,
+        cache_dir: Option<&Path>,
+    ) -> Result<prjunnamed_netlist::Design, Box<dyn core::error::Error>> {
+        if self.presynthesized {
+            return self.import_design_raw();
+        }
+        let yosys = find_yosys_binary()?;
+        self.import_design_cached_yosys(module_config, &yosys, cache_dir)
+    }
+
+    /// Imports the design through an on-disk cache using a specific Yosys
+    /// binary path.
+    ///
+    /// Entries are keyed by the design file contents, the module name, the
+    /// processing options and the Yosys binary path and modification time, so
+    /// rebuilding Yosys invalidates the cache. Files pulled in through
+    /// Verilog `include` directives are not part of the key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no cache directory is available, the cache cannot
+    /// be written, execution fails, or the resulting output cannot be parsed.
+    pub fn import_design_cached_yosys(
+        &self,
+        module_config: &ModuleConfig,
+        yosys_binary: &Path,
+        cache_dir: Option<&Path>,
+    ) -> Result<prjunnamed_netlist::Design, Box<dyn core::error::Error>> {
+        if self.presynthesized {
+            return self.import_design_raw();
+        }
+        let cache_dir = match cache_dir {
+            Some(dir) => dir.to_path_buf(),
+            None => Self::default_cache_dir()
+                .ok_or("no cache directory available, set XDG_CACHE_HOME or HOME")?,
+        };
+
+        let key = self.cache_key(module_config, yosys_binary)?;
+        let cached_json = cache_dir.join(format!("{key:016x}.json"));
+
+        if cached_json.is_file() {
+            match self.parse_json_output(&cached_json) {
+                Ok(design) => {
+                    tracing::debug!("Loaded {} from cache", self.module_name());
+                    return Ok(design);
+                }
+                Err(e) => tracing::debug!(
+                    "Discarding unreadable cache entry {}: {e}",
+                    cached_json.display()
+                ),
+            }
+        }
+
+        std::fs::create_dir_all(&cache_dir)?;
+        let json_temp = tempfile::Builder::new()
+            .prefix("svql_")
+            .suffix(".json")
+            .tempfile_in(&cache_dir)?;
+
+        let args = self.generate_yosys_args(json_temp.path(), module_config, OutputFormat::Json);
+        Self::execute_yosys(args, yosys_binary, module_config.timeout)?;
+
+        let design = self.parse_json_output(json_temp.path())?;
+        json_temp.persist(&cached_json)?;
+
+        Ok(design)
+    }
+
+    /// Returns the default directory for cached Yosys output.
+    pub(crate) fn default_cache_dir() -> Option<PathBuf> {
+        std::env::var_os("XDG_CACHE_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+            .map(|dir| dir.join("svql"))
+    }
+
+    /// Computes the cache key for this module under the given options.
+    ///
+    /// Parameters are sorted before hashing since map iteration order is not
+    /// stable between runs, and a [`StableHasher`] keeps the key itself stable.
+    pub(crate) fn cache_key(
+        &self,
+        module_config: &ModuleConfig,
+        yosys_binary: &Path,
+    ) -> Result<u64, Box<dyn core::error::Error>> {
+        let mut hasher = StableHasher::new();
+
+        std::fs::read(self.path())?.hash(&mut hasher);
+        self.design_path().read_command().hash(&mut hasher);
+        self.module_name().hash(&mut hasher);
+
+        let mut params: Vec<_> = module_config.params.iter().collect();
+        params.sort();
+        params.hash(&mut hasher);
+        module_config.const_params.hash(&mut hasher);
+        module_config.flatten.hash(&mut hasher);
+        module_config.opt.hash(&mut hasher);
+        module_config.opt_clean.hash(&mut hasher);
+        module_config.other_steps.hash(&mut hasher);
+        module_config.verific.hash(&mut hasher);
+        // `load_raw` is left out: it only decides whether the driver runs
+        // Yosys at all, and the cached import always does
+
+        yosys_binary.hash(&mut hasher);
+        std::fs::metadata(yosys_binary)?
+            .modified()?
+            .hash(&mut hasher);
+
+        Ok(hasher.finish())
+    }
+
+    /// Imports a module from Verilog source text rather than a file on disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`find_yosys_binary`] finds no Yosys binary, the
+    /// source fails to parse, or the module is not found. File references in Yosys
+    /// diagnostics are reported as `<stdin>`.
+    pub fn import_design_from_source(
+        source: &str,
+        module_name: &str,
+        module_config: &ModuleConfig,
+    ) -> Result<prjunnamed_netlist::Design, Box<dyn core::error::Error>> {
+        let yosys = find_yosys_binary()?;
+        Self::import_design_from_source_yosys(source, module_name, module_config, &yosys)
+    }
+
+    /// Imports a module from Verilog source text using a specific Yosys
+    /// binary path.
+    ///
+    /// The source is staged in a temporary file owned by this call, so the
+    /// caller never has to manage a file lifecycle.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the temporary file cannot be written, execution
+    /// fails, or the resulting output cannot be parsed.
+    pub fn import_design_from_source_yosys(
+        source: &str,
+        module_name: &str,
+        module_config: &ModuleConfig,
+        yosys_binary: &Path,
+    ) -> Result<prjunnamed_netlist::Design, Box<dyn core::error::Error>> {
+        let mut source_temp = tempfile::Builder::new()
+            .prefix("svql_")
+            .suffix(".v")
+            .tempfile()?;
+        source_temp.write_all(source.as_bytes())?;
+        source_temp.flush()?;
+
+        let module = Self::new(source_temp.path(), module_name)?;
+        module
+            .import_design_yosys(module_config, yosys_binary)
+            .map_err(|e| match e.downcast::<YosysExecutionError>() {
+                Ok(mut err) => {
+                    err.rename_file(source_temp.path(), Path::new(SOURCE_FILE_NAME));
+                    err as Box<dyn core::error::Error>
+                }
+                Err(e) => e,
+            })
+    }
+
+    /// Imports several modules of the same design file with a single Yosys
+    /// invocation.
+    ///
+    /// Each entry pairs a module name with its own import result, so a module
+    /// that is missing or fails to elaborate does not prevent the others from
+    /// being returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`find_yosys_binary`] finds no Yosys binary or the
+    /// design path cannot be categorized. Per-module failures are reported in the
+    /// returned entries instead.
+    pub fn import_designs<P: AsRef<Path>>(
+        path: P,
+        module_names: &[&str],
+        module_config: &ModuleConfig,
+    ) -> Result<Vec<ModuleImport>, Box<dyn core::error::Error>> {
+        let yosys = find_yosys_binary()?;
+        Self::import_designs_yosys(path, module_names, module_config, &yosys)
+    }
+
+    /// Imports several modules of the same design file using a specific Yosys
+    /// binary path.
+    ///
+    /// All modules are processed by one Yosys run. If that run fails (for
+    /// example because one of the modules does not exist), every module is
+    /// retried in isolation so that each entry carries its own error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the design path cannot be categorized or the
+    /// temporary output directory cannot be created.
+    pub fn import_designs_yosys<P: AsRef<Path>>(
+        path: P,
+        module_names: &[&str],
+        module_config: &ModuleConfig,
+        yosys_binary: &Path,
+    ) -> Result<Vec<ModuleImport>, Box<dyn core::error::Error>> {
+        let modules = module_names
+            .iter()
+            .map(|name| Self::new(path.as_ref(), name))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let Some(base) = modules.first() else {
+            return Ok(Vec::new());
+        };
+
+        let json_dir = tempfile::Builder::new().prefix("svql_").tempdir()?;
+        let outputs: Vec<PathBuf> = (0..modules.len())
+            .map(|i| json_dir.path().join(format!("module_{i}.json")))
+            .collect();
+
+        let batch: Vec<(&str, &Path)> = modules
+            .iter()
+            .zip(&outputs)
+            .map(|(module, output)| (module.module_name(), output.as_path()))
+            .collect();
+
+        let args = base.generate_batch_args(&batch, module_config, OutputFormat::Json);
+        if let Err(e) = Self::execute_yosys(args, yosys_binary, module_config.timeout) {
+            if let Some(timeout) = e.downcast_ref::<YosysTimeout>() {
+                let timeout = *timeout;
+                return Ok(modules
+                    .iter()
+                    .map(|module| -> ModuleImport {
+                        (module.module_name().to_owned(), Err(Box::new(timeout)))
+                    })
+                    .collect());
+            }
+            tracing::debug!("Batch Yosys run failed, retrying modules individually: {e}");
+            return Ok(modules
+                .iter()
+                .map(|module| {
+                    (
+                        module.module_name().to_owned(),
+                        module.import_design_yosys(module_config, yosys_binary),
+                    )
+                })
+                .collect());
+        }
+
+        Ok(modules
+            .iter()
+            .zip(&outputs)
+            .map(|(module, output)| {
+                (
+                    module.module_name().to_owned(),
+                    module.parse_json_output(output),
+                )
+            })
+            .collect())
+    }
+
     /// Imports the design without any preprocessing by Yosys.
     ///
     /// # Errors
@@ -234,6 +667,23 @@ impl YosysModule {
         self.parse_json_output(self.path())
     }
 
+    /// Imports every module of a Yosys JSON netlist without any preprocessing.
+    ///
+    /// Unlike [`Self::import_design_raw`], no module needs to be named up
+    /// front, so multi-module synthesized netlists can be loaded in one pass.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid Yosys
+    /// JSON netlist.
+    pub fn import_all_modules_raw<P: AsRef<Path>>(
+        path: P,
+    ) -> Result<crate::HashMap<String, prjunnamed_netlist::Design>, Box<dyn core::error::Error>>
+    {
+        let designs = prjunnamed_yosys_json::import(None, &mut File::open(path.as_ref())?)?;
+        Ok(designs.into_iter().collect())
+    }
+
     /// Internal helper to parse Yosys JSON output and extract the target module.
     fn parse_json_output(
         &self,
@@ -251,6 +701,21 @@ impl YosysModule {
         })
     }
 
+    /// Writes the processed design in Yosys JSON format to the specified path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if execution fails or the output cannot be written.
+    pub fn write_json_to_path(
+        &self,
+        config: &ModuleConfig,
+        yosys_binary: &Path,
+        json_out: &Path,
+    ) -> Result<(), Box<dyn core::error::Error>> {
+        let args = self.generate_yosys_args(json_out, config, OutputFormat::Json);
+        Self::execute_yosys(args, yosys_binary, config.timeout)
+    }
+
     /// Writes the processed design in RTLIL format to the specified path.
     ///
     /// # Errors
@@ -264,7 +729,7 @@ impl YosysModule {
         rtlil_out: &Path,
     ) -> Result<(), Box<dyn core::error::Error>> {
         let args = self.generate_yosys_args(rtlil_out, config, OutputFormat::Rtlil);
-        Self::execute_yosys(args, yosys_binary)
+        Self::execute_yosys(args, yosys_binary, config.timeout)
     }
 
     /// Writes the processed design in RTLIL format to standard output.
@@ -0,0 +1,138 @@
+//! Retry policy for transient Yosys failures.
+
+use std::time::Duration;
+
+use super::YosysTimeout;
+
+/// Exponential backoff policy for retrying transient Yosys failures.
+///
+/// Only timeouts and I/O errors (e.g. a failure to spawn the process or to
+/// read its output) are retried. Errors Yosys reports about the design, and
+/// output that cannot be parsed, fail immediately since retrying cannot help.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first.
+    pub max_attempts: u32,
+    /// Delay before the first retry.
+    pub base_delay: Duration,
+    /// Factor the delay is multiplied by after each retry.
+    pub multiplier: u32,
+}
+
+impl Default for RetryPolicy {
+    /// Returns a policy that makes a single attempt.
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a retry policy.
+    #[must_use]
+    pub const fn new(max_attempts: u32, base_delay: Duration, multiplier: u32) -> Self {
+        Self {
+            max_attempts,
+            base_delay,
+            multiplier,
+        }
+    }
+
+    /// Returns a policy that never retries.
+    #[must_use]
+    pub const fn none() -> Self {
+        Self::new(1, Duration::ZERO, 1)
+    }
+
+    /// Returns the delay before the given retry (0 for the first retry).
+    #[must_use]
+    pub const fn delay(&self, retry: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(self.multiplier.saturating_pow(retry))
+    }
+
+    /// Returns true if the error is transient and worth retrying.
+    #[must_use]
+    pub fn is_retryable(error: &(dyn core::error::Error + 'static)) -> bool {
+        error.is::<YosysTimeout>() || error.is::<std::io::Error>()
+    }
+
+    /// Runs `op`, retrying transient failures with exponential backoff.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first non-retryable error, or the last error once all
+    /// attempts are exhausted.
+    pub fn run<T>(
+        &self,
+        mut op: impl FnMut() -> Result<T, Box<dyn core::error::Error>>,
+    ) -> Result<T, Box<dyn core::error::Error>> {
+        let mut retry = 0;
+        loop {
+            match op() {
+                Err(e) if retry + 1 < self.max_attempts && Self::is_retryable(e.as_ref()) => {
+                    let delay = self.delay(retry);
+                    tracing::debug!("Transient Yosys failure, retrying in {delay:?}: {e}");
+                    std::thread::sleep(delay);
+                    retry += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(missing_docs)]
+    #![allow(clippy::missing_docs_in_private_items)]
+
+    use super::*;
+
+    #[test]
+    fn test_delay_grows_exponentially() {
+        let policy = RetryPolicy::new(4, Duration::from_millis(10), 2);
+        assert_eq!(policy.delay(0), Duration::from_millis(10));
+        assert_eq!(policy.delay(2), Duration::from_millis(40));
+    }
+
+    #[test]
+    fn test_run_retries_transient_errors() {
+        let policy = RetryPolicy::new(3, Duration::ZERO, 2);
+        let mut attempts = 0;
+        let result = policy.run(|| {
+            attempts += 1;
+            if attempts < 3 {
+                Err(Box::new(YosysTimeout {
+                    timeout: Duration::from_secs(1),
+                }) as Box<dyn core::error::Error>)
+            } else {
+                Ok(attempts)
+            }
+        });
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[test]
+    fn test_run_does_not_retry_permanent_errors() {
+        let policy = RetryPolicy::new(3, Duration::ZERO, 2);
+        let mut attempts = 0;
+        let result: Result<(), _> = policy.run(|| {
+            attempts += 1;
+            Err("syntax error".into())
+        });
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn test_run_gives_up_after_max_attempts() {
+        let policy = RetryPolicy::new(2, Duration::ZERO, 2);
+        let mut attempts = 0;
+        let result: Result<(), _> = policy.run(|| {
+            attempts += 1;
+            Err(Box::new(std::io::Error::other("busy")) as Box<dyn core::error::Error>)
+        });
+        assert!(result.is_err());
+        assert_eq!(attempts, 2);
+    }
+}
@@ -0,0 +1,142 @@
+//! Synthesis of Verilog fixtures into cached Yosys JSON netlists.
+//!
+//! Test suites that load the same fixtures over and over can synthesize each
+//! one once with [`ensure_synthesized`] and then load the resulting JSON
+//! through [`YosysModule::from_json`], keeping Yosys out of the hot path.
+
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use crate::{ModuleConfig, YosysModule, find_yosys_binary};
+
+/// Error returned when a fixture cannot be synthesized.
+///
+/// Wraps the underlying failure (usually a
+/// [`YosysExecutionError`](crate::YosysExecutionError)) with the fixture and
+/// module it was raised for. The wrapped error is available through
+/// [`core::error::Error::source`].
+#[derive(Debug)]
+pub struct SynthesisError {
+    /// The fixture that was being synthesized.
+    pub path: PathBuf,
+    /// The module that was being synthesized.
+    pub module: String,
+    /// The underlying failure.
+    pub error: Box<dyn core::error::Error>,
+}
+
+impl fmt::Display for SynthesisError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Failed to synthesize module '{}' from {}: {}",
+            self.module,
+            self.path.display(),
+            self.error
+        )
+    }
+}
+
+impl core::error::Error for SynthesisError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(self.error.as_ref())
+    }
+}
+
+/// Returns a cached JSON netlist for `module` of the design at `path`.
+///
+/// Yosys runs only if no up-to-date cached copy exists. The default module
+/// config is used and the JSON is cached in `$XDG_CACHE_HOME/svql` (or
+/// `~/.cache/svql`).
+///
+/// # Errors
+///
+/// Returns a [`SynthesisError`] if [`find_yosys_binary`] finds no Yosys
+/// binary, no cache directory is available, or synthesis fails.
+pub fn ensure_synthesized<P: AsRef<Path>, S: AsRef<str>>(
+    path: P,
+    module: S,
+) -> Result<PathBuf, SynthesisError> {
+    let wrap = |error: Box<dyn core::error::Error>| SynthesisError {
+        path: path.as_ref().to_path_buf(),
+        module: module.as_ref().to_owned(),
+        error,
+    };
+    let yosys = find_yosys_binary().map_err(|e| wrap(e.into()))?;
+    ensure_synthesized_yosys(&path, &module, &ModuleConfig::default(), &yosys, None)
+}
+
+/// Returns a JSON netlist for `module` of the design at `path` using a
+/// specific Yosys binary, module config and cache directory.
+///
+/// The cached file is named by a hash of the source contents, the module
+/// name, the processing options and the Yosys binary, so any of them
+/// changing yields a fresh file. A cached file older than the source is
+/// synthesized again. Files pulled in through Verilog `include` directives
+/// are not tracked.
+///
+/// # Errors
+///
+/// Returns a [`SynthesisError`] if the path cannot be categorized or read,
+/// no cache directory is available, the cache cannot be written, or Yosys
+/// fails.
+pub fn ensure_synthesized_yosys<P: AsRef<Path>, S: AsRef<str>>(
+    path: P,
+    module: S,
+    module_config: &ModuleConfig,
+    yosys_binary: &Path,
+    cache_dir: Option<&Path>,
+) -> Result<PathBuf, SynthesisError> {
+    let wrap = |error: Box<dyn core::error::Error>| SynthesisError {
+        path: path.as_ref().to_path_buf(),
+        module: module.as_ref().to_owned(),
+        error,
+    };
+
+    let yosys_module = YosysModule::new(&path, &module).map_err(wrap)?;
+    let cache_dir = match cache_dir {
+        Some(dir) => dir.to_path_buf(),
+        None => YosysModule::default_cache_dir().ok_or_else(|| {
+            wrap("no cache directory available, set XDG_CACHE_HOME or HOME".into())
+        })?,
+    };
+
+    let key = yosys_module
+        .cache_key(module_config, yosys_binary)
+        .map_err(wrap)?;
+    let cached_json = cache_dir.join(format!("{}-{key:016x}.json", module.as_ref()));
+
+    if is_newer_than(&cached_json, yosys_module.path()) {
+        tracing::debug!("Using synthesized {}", cached_json.display());
+        return Ok(cached_json);
+    }
+
+    std::fs::create_dir_all(&cache_dir).map_err(|e| wrap(e.into()))?;
+    let json_temp = tempfile::Builder::new()
+        .prefix("svql_")
+        .suffix(".json")
+        .tempfile_in(&cache_dir)
+        .map_err(|e| wrap(e.into()))?;
+
+    yosys_module
+        .write_json_to_path(module_config, yosys_binary, json_temp.path())
+        .map_err(wrap)?;
+    json_temp
+        .persist(&cached_json)
+        .map_err(|e| wrap(e.into()))?;
+
+    Ok(cached_json)
+}
+
+/// Returns true if `output` exists and was modified no earlier than `source`.
+fn is_newer_than(output: &Path, source: &Path) -> bool {
+    let modified =
+        |path: &Path| -> Option<SystemTime> { std::fs::metadata(path).ok()?.modified().ok() };
+    match (modified(output), modified(source)) {
+        (Some(output), Some(source)) => output >= source,
+        _ => false,
+    }
+}
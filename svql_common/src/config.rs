@@ -1,15 +1,24 @@
 //! Configuration for the subgraph isomorphism search.
 
 use core::{
+    fmt,
     hash::{Hash, Hasher},
     str::FromStr,
 };
+use std::collections::BTreeSet;
+use std::path::Path;
 
 use crate::ModuleConfig;
+use crate::cell::CellKind;
 use contracts::*;
+use serde::{Deserialize, Serialize};
 
 /// Configuration parameters for the subgraph matching engine.
-#[derive(Clone, Debug, PartialEq, Eq)]
+///
+/// Can be stored in TOML or JSON files; fields missing from a file take
+/// their default values.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Config {
     /// The length of the match to find.
     pub match_length: MatchLength,
@@ -21,6 +30,43 @@ pub struct Config {
     pub pattern_vars_match_design_consts: bool,
     /// Whether to run the search in parallel.
     pub parallel: bool,
+    /// Stop the search once this many distinct matches have been found.
+    pub max_matches: Option<usize>,
+    /// Whether `MatchLength::Exact` accepts operands of differing bit width.
+    pub width_tolerant: bool,
+    /// Whether pattern inout ports may bind to nets driven in either direction.
+    pub bidir_ports: bool,
+    /// Try at most this many haystack candidates for each needle gate.
+    pub max_candidates_per_node: Option<usize>,
+    /// Stop the search after visiting this many backtracking branches.
+    pub max_total_nodes: Option<usize>,
+    /// Cell kinds the matcher skips in both needle and haystack.
+    ///
+    /// Skipped cells are never matched; edges are followed through them, so a
+    /// gate driven through a skipped cell counts as driven by that cell's
+    /// drivers. Only `Buf` and `Not` are followed bit by bit when comparing
+    /// operands (an ignored `Not` loses its polarity); an operand driven by any
+    /// other skipped kind is unconstrained in the needle and cannot bind to a
+    /// needle driver in the haystack. `Input` and `Output` are never skipped.
+    pub ignore_cell_kinds: BTreeSet<CellKind>,
+    /// Whether buffers and inverters are treated as plain wires.
+    ///
+    /// Adds `Buf` and `Not` to the skipped kinds during matching, and lets
+    /// connections between matched cells pass through chains of them.
+    pub skip_transparent: bool,
+    /// Which duplicate embeddings and rows are collapsed.
+    pub dedupe: Dedupe,
+    /// Cell kind to anchor the search on, if the needle and haystack both have one.
+    ///
+    /// The search starts from a needle gate of this kind instead of the
+    /// default (the first gate in topological order) and grows outwards
+    /// from it. See [`ConfigBuilder::anchor_preference`] for choosing a kind.
+    pub anchor_preference: Option<CellKind>,
+    /// Whether leaf query results are shared through the driver's result cache.
+    pub cache_results: bool,
+    /// Whether netlist matches are checked for functional equivalence with
+    /// their needle by an SMT solver.
+    pub verify_functional: bool,
 }
 
 impl Default for Config {
@@ -32,12 +78,26 @@ impl Default for Config {
             haystack_options: ModuleConfig::default(),
             pattern_vars_match_design_consts: true,
             parallel: false,
+            max_matches: None,
+            width_tolerant: false,
+            bidir_ports: false,
+            max_candidates_per_node: None,
+            max_total_nodes: None,
+            ignore_cell_kinds: BTreeSet::new(),
+            skip_transparent: false,
+            dedupe: Dedupe::All,
+            anchor_preference: None,
+            cache_results: true,
+            verify_functional: false,
         }
     }
 }
 
 impl Config {
     /// Creates a new configuration instance.
+    ///
+    /// Settings not taken here start at their defaults; use
+    /// [`Config::builder`] or [`Config::into_builder`] to change them.
     #[must_use]
     pub const fn new(
         match_length: MatchLength,
@@ -52,14 +112,201 @@ impl Config {
             haystack_options,
             pattern_vars_match_design_consts,
             parallel,
+            max_matches: None,
+            width_tolerant: false,
+            bidir_ports: false,
+            max_candidates_per_node: None,
+            max_total_nodes: None,
+            ignore_cell_kinds: BTreeSet::new(),
+            skip_transparent: false,
+            dedupe: Dedupe::All,
+            anchor_preference: None,
+            cache_results: true,
+            verify_functional: false,
         }
     }
 
+    /// Returns the cell kinds the matcher skips.
+    ///
+    /// This is `ignore_cell_kinds`, plus [`CellKind::TRANSPARENT`] with
+    /// `skip_transparent`, minus the port kinds, which are never skipped.
+    #[must_use]
+    pub fn skipped_cell_kinds(&self) -> BTreeSet<CellKind> {
+        let transparent = CellKind::TRANSPARENT
+            .into_iter()
+            .filter(|_| self.skip_transparent);
+        self.ignore_cell_kinds
+            .iter()
+            .copied()
+            .chain(transparent)
+            .filter(|kind| !kind.is_input() && !kind.is_output())
+            .collect()
+    }
+
     /// Returns a builder for configuration.
     #[must_use]
     pub fn builder() -> ConfigBuilder {
         ConfigBuilder::default()
     }
+
+    /// Returns a builder pre-populated with this configuration, for deriving
+    /// variants of an existing (e.g. file-loaded) configuration.
+    #[must_use]
+    pub fn into_builder(self) -> ConfigBuilder {
+        ConfigBuilder {
+            match_length: self.match_length,
+            needle_options: self.needle_options,
+            haystack_options: self.haystack_options,
+            pattern_vars_match_design_consts: self.pattern_vars_match_design_consts,
+            max_recursion_depth: None,
+            parallel: self.parallel,
+            max_matches: self.max_matches,
+            width_tolerant: self.width_tolerant,
+            bidir_ports: self.bidir_ports,
+            max_candidates_per_node: self.max_candidates_per_node,
+            max_total_nodes: self.max_total_nodes,
+            ignore_cell_kinds: self.ignore_cell_kinds,
+            skip_transparent: self.skip_transparent,
+            dedupe: self.dedupe,
+            anchor_preference: self.anchor_preference,
+            cache_results: self.cache_results,
+            verify_functional: self.verify_functional,
+        }
+    }
+
+    /// Loads a configuration from a TOML file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid configuration.
+    pub fn from_toml_file(path: impl AsRef<Path>) -> Result<Self, Box<dyn core::error::Error>> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)?;
+        toml::from_str(&contents)
+            .map_err(|e| format!("Invalid config file {}: {e}", path.display()).into())
+    }
+
+    /// Loads a configuration from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid configuration.
+    pub fn from_json_file(path: impl AsRef<Path>) -> Result<Self, Box<dyn core::error::Error>> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)?;
+        serde_json::from_str(&contents)
+            .map_err(|e| format!("Invalid config file {}: {e}", path.display()).into())
+    }
+
+    /// Writes the configuration to a TOML file.
+    ///
+    /// Const-context module parameters (`const_params`) are not persisted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration cannot be serialized or the file
+    /// cannot be written.
+    pub fn to_toml_file(&self, path: impl AsRef<Path>) -> Result<(), Box<dyn core::error::Error>> {
+        let contents = toml::to_string_pretty(self)?;
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+
+    /// Lists every field that differs between `self` (old) and `other` (new).
+    ///
+    /// Scalar fields are reported as changed; list and map fields of the
+    /// module options are compared entry by entry, ignoring order.
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<ConfigDiff> {
+        let mut diffs = Vec::new();
+        diff_value(
+            &mut diffs,
+            "match_length",
+            &self.match_length,
+            &other.match_length,
+        );
+        diff_module_config(
+            &mut diffs,
+            "needle_options",
+            &self.needle_options,
+            &other.needle_options,
+        );
+        diff_module_config(
+            &mut diffs,
+            "haystack_options",
+            &self.haystack_options,
+            &other.haystack_options,
+        );
+        diff_value(
+            &mut diffs,
+            "pattern_vars_match_design_consts",
+            &self.pattern_vars_match_design_consts,
+            &other.pattern_vars_match_design_consts,
+        );
+        diff_value(&mut diffs, "parallel", &self.parallel, &other.parallel);
+        diff_value(
+            &mut diffs,
+            "max_matches",
+            &self.max_matches,
+            &other.max_matches,
+        );
+        diff_value(
+            &mut diffs,
+            "width_tolerant",
+            &self.width_tolerant,
+            &other.width_tolerant,
+        );
+        diff_value(
+            &mut diffs,
+            "bidir_ports",
+            &self.bidir_ports,
+            &other.bidir_ports,
+        );
+        diff_value(
+            &mut diffs,
+            "max_candidates_per_node",
+            &self.max_candidates_per_node,
+            &other.max_candidates_per_node,
+        );
+        diff_value(
+            &mut diffs,
+            "max_total_nodes",
+            &self.max_total_nodes,
+            &other.max_total_nodes,
+        );
+        diff_entries(
+            &mut diffs,
+            "ignore_cell_kinds",
+            &self.ignore_cell_kinds.iter().collect::<Vec<_>>(),
+            &other.ignore_cell_kinds.iter().collect::<Vec<_>>(),
+        );
+        diff_value(
+            &mut diffs,
+            "skip_transparent",
+            &self.skip_transparent,
+            &other.skip_transparent,
+        );
+        diff_value(&mut diffs, "dedupe", &self.dedupe, &other.dedupe);
+        diff_value(
+            &mut diffs,
+            "anchor_preference",
+            &self.anchor_preference,
+            &other.anchor_preference,
+        );
+        diff_value(
+            &mut diffs,
+            "cache_results",
+            &self.cache_results,
+            &other.cache_results,
+        );
+        diff_value(
+            &mut diffs,
+            "verify_functional",
+            &self.verify_functional,
+            &other.verify_functional,
+        );
+        diffs
+    }
 }
 
 impl Hash for Config {
@@ -67,9 +314,169 @@ impl Hash for Config {
         self.match_length.hash(state);
         self.needle_options.hash(state);
         self.pattern_vars_match_design_consts.hash(state);
+        self.max_matches.hash(state);
+        self.width_tolerant.hash(state);
+        self.bidir_ports.hash(state);
+        self.max_candidates_per_node.hash(state);
+        self.max_total_nodes.hash(state);
+        self.ignore_cell_kinds.hash(state);
+        self.skip_transparent.hash(state);
+        self.dedupe.hash(state);
+        self.anchor_preference.hash(state);
+        self.verify_functional.hash(state);
     }
 }
 
+/// A single difference between two configurations, as reported by [`Config::diff`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfigDiff {
+    /// A field holds a different value.
+    Changed {
+        /// Dotted path of the field, e.g. `needle_options.flatten`.
+        field: String,
+        /// Value in the old configuration.
+        old: String,
+        /// Value in the new configuration.
+        new: String,
+    },
+    /// An entry is present only in the new configuration.
+    Added {
+        /// Dotted path of the list or map field.
+        field: String,
+        /// The added entry.
+        value: String,
+    },
+    /// An entry is present only in the old configuration.
+    Removed {
+        /// Dotted path of the list or map field.
+        field: String,
+        /// The removed entry.
+        value: String,
+    },
+}
+
+impl fmt::Display for ConfigDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Changed { field, old, new } => write!(f, "~ {field}: {old} -> {new}"),
+            Self::Added { field, value } => write!(f, "+ {field}: {value}"),
+            Self::Removed { field, value } => write!(f, "- {field}: {value}"),
+        }
+    }
+}
+
+/// Records a [`ConfigDiff::Changed`] entry if the two values differ.
+fn diff_value<T: PartialEq + fmt::Debug>(
+    diffs: &mut Vec<ConfigDiff>,
+    field: &str,
+    old: &T,
+    new: &T,
+) {
+    if old != new {
+        diffs.push(ConfigDiff::Changed {
+            field: field.to_owned(),
+            old: format!("{old:?}"),
+            new: format!("{new:?}"),
+        });
+    }
+}
+
+/// Records added and removed entries between two lists, ignoring order.
+fn diff_entries<T: PartialEq + fmt::Debug>(
+    diffs: &mut Vec<ConfigDiff>,
+    field: &str,
+    old: &[T],
+    new: &[T],
+) {
+    for value in old.iter().filter(|value| !new.contains(value)) {
+        diffs.push(ConfigDiff::Removed {
+            field: field.to_owned(),
+            value: format!("{value:?}"),
+        });
+    }
+    for value in new.iter().filter(|value| !old.contains(value)) {
+        diffs.push(ConfigDiff::Added {
+            field: field.to_owned(),
+            value: format!("{value:?}"),
+        });
+    }
+}
+
+/// Records every difference between two module configurations under `prefix`.
+fn diff_module_config(
+    diffs: &mut Vec<ConfigDiff>,
+    prefix: &str,
+    old: &ModuleConfig,
+    new: &ModuleConfig,
+) {
+    diff_value(
+        diffs,
+        &format!("{prefix}.flatten"),
+        &old.flatten,
+        &new.flatten,
+    );
+    diff_value(
+        diffs,
+        &format!("{prefix}.opt_clean"),
+        &old.opt_clean,
+        &new.opt_clean,
+    );
+    diff_value(diffs, &format!("{prefix}.opt"), &old.opt, &new.opt);
+
+    // Sort so the report does not depend on hash map iteration order.
+    let mut old_params: Vec<_> = old.params.iter().collect();
+    old_params.sort();
+    let mut new_params: Vec<_> = new.params.iter().collect();
+    new_params.sort();
+    let changed_keys: Vec<&String> = old_params
+        .iter()
+        .filter(|(key, value)| new.params.get(*key).is_some_and(|other| other != *value))
+        .map(|(key, _)| *key)
+        .collect();
+    for key in &changed_keys {
+        diff_value(
+            diffs,
+            &format!("{prefix}.params[{key}]"),
+            &old.params[*key],
+            &new.params[*key],
+        );
+    }
+    old_params.retain(|(key, _)| !changed_keys.contains(key));
+    new_params.retain(|(key, _)| !changed_keys.contains(key));
+    diff_entries(diffs, &format!("{prefix}.params"), &old_params, &new_params);
+
+    diff_entries(
+        diffs,
+        &format!("{prefix}.const_params"),
+        old.const_params,
+        new.const_params,
+    );
+    diff_entries(
+        diffs,
+        &format!("{prefix}.other_steps"),
+        &old.other_steps,
+        &new.other_steps,
+    );
+    diff_value(
+        diffs,
+        &format!("{prefix}.verific"),
+        &old.verific,
+        &new.verific,
+    );
+    diff_value(
+        diffs,
+        &format!("{prefix}.load_raw"),
+        &old.load_raw,
+        &new.load_raw,
+    );
+    diff_value(
+        diffs,
+        &format!("{prefix}.timeout"),
+        &old.timeout,
+        &new.timeout,
+    );
+}
+
 /// A builder pattern implementation for the `Config` struct.
 #[derive(Clone, Debug)]
 pub struct ConfigBuilder {
@@ -85,6 +492,28 @@ pub struct ConfigBuilder {
     max_recursion_depth: Option<usize>,
     /// Enable multi-threaded search.
     parallel: bool,
+    /// Cap on the number of distinct matches to find.
+    max_matches: Option<usize>,
+    /// Compare operands of differing widths on their overlapping bits.
+    width_tolerant: bool,
+    /// Let pattern inout ports bind as either inputs or outputs.
+    bidir_ports: bool,
+    /// Cap on haystack candidates tried per needle gate.
+    max_candidates_per_node: Option<usize>,
+    /// Cap on backtracking branches visited in total.
+    max_total_nodes: Option<usize>,
+    /// Cell kinds skipped during matching.
+    ignore_cell_kinds: BTreeSet<CellKind>,
+    /// Treat buffers and inverters as plain wires.
+    skip_transparent: bool,
+    /// Which duplicates are collapsed.
+    dedupe: Dedupe,
+    /// Preferred cell kind for the search anchor.
+    anchor_preference: Option<CellKind>,
+    /// Share leaf query results through the driver's result cache.
+    cache_results: bool,
+    /// Check netlist matches for functional equivalence with their needle.
+    verify_functional: bool,
 }
 
 impl Default for ConfigBuilder {
@@ -96,6 +525,17 @@ impl Default for ConfigBuilder {
             pattern_vars_match_design_consts: true, // Match Config::default()
             max_recursion_depth: None,
             parallel: false,
+            max_matches: None,
+            width_tolerant: false,
+            bidir_ports: false,
+            max_candidates_per_node: None,
+            max_total_nodes: None,
+            ignore_cell_kinds: BTreeSet::new(),
+            skip_transparent: false,
+            dedupe: Dedupe::All,
+            anchor_preference: None,
+            cache_results: true,
+            verify_functional: false,
         }
     }
 }
@@ -192,6 +632,10 @@ impl ConfigBuilder {
     }
 
     /// Configures whether pattern variables can match design constants.
+    ///
+    /// When enabled, a needle input port whose driven pins are tied to a
+    /// constant (e.g. `1'b0`) in the haystack no longer rejects the match;
+    /// the port is left unbound if no haystack cell can drive it.
     #[must_use]
     #[debug_ensures(ret.pattern_vars_match_design_consts == allow)]
     pub const fn pattern_vars_match_design_consts(mut self, allow: bool) -> Self {
@@ -215,6 +659,148 @@ impl ConfigBuilder {
         self
     }
 
+    /// Limits the search to the first `limit` distinct matches.
+    ///
+    /// The search stops recursing as soon as the cap is reached. Results are
+    /// still deduplicated and sorted, but which matches make the cut depends
+    /// on discovery order and is not guaranteed to be stable.
+    #[must_use]
+    #[debug_ensures(ret.max_matches == limit)]
+    pub const fn max_matches(mut self, limit: Option<usize>) -> Self {
+        self.max_matches = limit;
+        self
+    }
+
+    /// Lets `MatchLength::Exact` match cells whose operands differ in width.
+    ///
+    /// When enabled, multi-bit operands (gate inputs, mux data, arithmetic
+    /// and comparison operands, flip-flop data) are compared bit by bit over
+    /// the narrower of the two values instead of being rejected outright.
+    /// Cells are still mapped whole, so port bindings are unaffected. Other
+    /// match length strategies already define their own width handling.
+    #[must_use]
+    #[debug_ensures(ret.width_tolerant == tolerant)]
+    pub const fn width_tolerant(mut self, tolerant: bool) -> Self {
+        self.width_tolerant = tolerant;
+        self
+    }
+
+    /// Lets pattern `inout` ports bind to haystack nets driven in either direction.
+    ///
+    /// Pattern inout pins are imported as `IoBuf` cells, which otherwise only
+    /// match haystack `IoBuf` cells. When enabled, an inout pin that the
+    /// pattern reads is matched like an input (any driver of the consuming
+    /// logic, including a haystack input), and one that the pattern only
+    /// drives is matched like an output.
+    #[must_use]
+    #[debug_ensures(ret.bidir_ports == enabled)]
+    pub const fn bidir_ports(mut self, enabled: bool) -> Self {
+        self.bidir_ports = enabled;
+        self
+    }
+
+    /// Tries at most `limit` haystack candidates for each needle gate.
+    ///
+    /// Candidates beyond the cap are skipped, so matches that depend on them
+    /// are lost; the search result is then flagged as truncated.
+    #[must_use]
+    #[debug_ensures(ret.max_candidates_per_node == limit)]
+    pub const fn max_candidates_per_node(mut self, limit: Option<usize>) -> Self {
+        self.max_candidates_per_node = limit;
+        self
+    }
+
+    /// Stops the search after visiting `limit` backtracking branches.
+    ///
+    /// Matches found before the cap is hit are kept and the search result is
+    /// flagged as truncated.
+    #[must_use]
+    #[debug_ensures(ret.max_total_nodes == limit)]
+    pub const fn max_total_nodes(mut self, limit: Option<usize>) -> Self {
+        self.max_total_nodes = limit;
+        self
+    }
+
+    /// Skips cells of the given kinds in both needle and haystack.
+    ///
+    /// Useful for normalizing away synthesis artifacts such as buffers that
+    /// appear on only one side. See [`Config::ignore_cell_kinds`] for how
+    /// connectivity through a skipped cell is treated.
+    #[must_use]
+    pub fn ignore_cell_kinds(mut self, kinds: impl IntoIterator<Item = CellKind>) -> Self {
+        self.ignore_cell_kinds = kinds.into_iter().collect();
+        self
+    }
+
+    /// Treats buffers and inverters as plain wires.
+    ///
+    /// Pre- and post-synthesis netlists often differ only by inserted
+    /// buffers, e.g. `a -> buf -> reg.en` instead of `a -> reg.en`. When
+    /// enabled, `Buf` and `Not` cells are skipped during matching and
+    /// connections between matched cells may pass through chains of them.
+    /// Inverter polarity is not tracked.
+    #[must_use]
+    #[debug_ensures(ret.skip_transparent == enabled)]
+    pub const fn skip_transparent(mut self, enabled: bool) -> Self {
+        self.skip_transparent = enabled;
+        self
+    }
+
+    /// Sets which duplicate embeddings and rows are collapsed.
+    ///
+    /// `Dedupe::None` is mainly a debugging aid: it shows every embedding the
+    /// matcher found, which helps explain why counts differ between queries.
+    #[must_use]
+    #[debug_ensures(ret.dedupe == mode)]
+    pub const fn dedupe(mut self, mode: Dedupe) -> Self {
+        self.dedupe = mode;
+        self
+    }
+
+    /// Anchors the search on a needle gate of `kind`.
+    ///
+    /// Every haystack cell of the anchor's kind is a root candidate, and the
+    /// remaining gates are matched outwards from it, so the search is only as
+    /// fast as the anchor kind is rare in the haystack. A good anchor is a
+    /// kind that occurs few times in the haystack but is part of the pattern,
+    /// such as a flip-flop, memory or wide arithmetic cell, rather than
+    /// plentiful logic like `And` or `Mux`. `GraphIndex::kind_histogram` of
+    /// the haystack shows the counts.
+    ///
+    /// The preference is ignored, and the default anchor used, when the
+    /// needle has no gate of `kind`, the kind is skipped, or the haystack has
+    /// no cell of it.
+    #[must_use]
+    #[debug_ensures(ret.anchor_preference.is_some() == kind.is_some())]
+    pub const fn anchor_preference(mut self, kind: Option<CellKind>) -> Self {
+        self.anchor_preference = kind;
+        self
+    }
+
+    /// Sets whether leaf query results are shared through the driver's
+    /// result cache.
+    ///
+    /// Enabled by default. Disable it to always re-run the search, e.g. when
+    /// measuring search performance.
+    #[must_use]
+    #[debug_ensures(ret.cache_results == enabled)]
+    pub const fn cache_results(mut self, enabled: bool) -> Self {
+        self.cache_results = enabled;
+        self
+    }
+
+    /// Sets whether netlist matches are checked for functional equivalence
+    /// with their needle.
+    ///
+    /// Disabled by default. Each match is proved or refuted with an SMT
+    /// solver (`z3` on the `PATH`), and the verdict is shown in its report.
+    #[must_use]
+    #[debug_ensures(ret.verify_functional == enabled)]
+    pub const fn verify_functional(mut self, enabled: bool) -> Self {
+        self.verify_functional = enabled;
+        self
+    }
+
     /// Finalizes the builder into a `Config` instance.
     #[must_use]
     pub fn build(self) -> Config {
@@ -224,6 +810,17 @@ impl ConfigBuilder {
             haystack_options: self.haystack_options,
             pattern_vars_match_design_consts: self.pattern_vars_match_design_consts,
             parallel: self.parallel,
+            max_matches: self.max_matches,
+            width_tolerant: self.width_tolerant,
+            bidir_ports: self.bidir_ports,
+            max_candidates_per_node: self.max_candidates_per_node,
+            max_total_nodes: self.max_total_nodes,
+            ignore_cell_kinds: self.ignore_cell_kinds,
+            skip_transparent: self.skip_transparent,
+            dedupe: self.dedupe,
+            anchor_preference: self.anchor_preference,
+            cache_results: self.cache_results,
+            verify_functional: self.verify_functional,
         }
     }
 }
@@ -231,8 +828,9 @@ impl ConfigBuilder {
 /// Defines matching strategies for pattern searches.
 ///
 /// Different strategies control how much of the pattern must match the target design.
-#[derive(Clone, Copy, Debug, Default, Hash)]
+#[derive(Clone, Copy, Debug, Default, Hash, Serialize, Deserialize)]
 #[derive_const(PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
 pub enum MatchLength {
     /// Stop after the first valid match is found.
     /// Fastest execution but only finds one result.
@@ -244,6 +842,20 @@ pub enum MatchLength {
     /// The pattern and target must have identical structure length.
     /// Requires exact matches with no additional nodes.
     Exact,
+    /// The matched region may be any non-empty subset of the pattern (needle).
+    ///
+    /// Needle gates without a haystack counterpart are left unassigned instead
+    /// of failing the branch, and ports whose neighbouring gates were all left
+    /// unassigned are skipped. Multi-bit values must have every haystack bit
+    /// driven like some needle bit. Useful for finding incomplete instances of
+    /// a pattern, e.g. half-built security mitigations.
+    SupersetNeedle,
+    /// Like `NeedleSubsetHaystack`, but the matched haystack gates may have no
+    /// edges among themselves beyond those of the pattern (induced subgraph).
+    ///
+    /// Distinguishes an isolated instance of a structure from the same
+    /// structure embedded in denser logic.
+    Induced,
 }
 
 impl MatchLength {
@@ -264,6 +876,18 @@ impl MatchLength {
     pub const fn exact(&self) -> bool {
         matches!(self, Self::Exact)
     }
+
+    /// Returns true if the strategy is `SupersetNeedle`.
+    #[must_use]
+    pub const fn superset_needle(&self) -> bool {
+        matches!(self, Self::SupersetNeedle)
+    }
+
+    /// Returns true if the strategy is `Induced`.
+    #[must_use]
+    pub const fn induced(&self) -> bool {
+        matches!(self, Self::Induced)
+    }
 }
 
 impl FromStr for MatchLength {
@@ -274,6 +898,76 @@ impl FromStr for MatchLength {
             "first" => Ok(Self::First),
             "needle_subset_haystack" => Ok(Self::NeedleSubsetHaystack),
             "exact" => Ok(Self::Exact),
+            "superset_needle" => Ok(Self::SupersetNeedle),
+            "induced" => Ok(Self::Induced),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Controls which duplicate results are collapsed.
+///
+/// The matcher emits embeddings (needle-to-haystack cell assignments), which
+/// query patterns turn into rows. Each mode collapses at least as much as the
+/// one before it.
+#[derive(Clone, Copy, Debug, Default, Hash, Serialize, Deserialize)]
+#[derive_const(PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Dedupe {
+    /// Keep every distinct embedding and every row.
+    ///
+    /// Embeddings that cover the same gates but bind the pattern's ports
+    /// differently (e.g. the two operand orders of a commutative gate) are
+    /// all reported.
+    None,
+    /// Collapse embeddings that map the pattern's gates onto the same set of
+    /// design cells, ignoring port bindings. Rows are kept as they are.
+    ByDesignCells,
+    /// Like `ByDesignCells`, and additionally drop rows with identical
+    /// signatures.
+    #[default]
+    All,
+    /// Like `All`, but keep only one result per set of design cells driving
+    /// the pattern's outputs.
+    ///
+    /// Embeddings are collapsed by the design cells mapped to the needle's
+    /// output drivers rather than by all of their gates, and rows by the
+    /// values of their output ports. Structurally different matches that
+    /// drive the same sink (e.g. several unlock paths into one protected
+    /// register) then yield a single finding, while `All` reports each
+    /// structure. Patterns without outputs fall back to `All`.
+    ByOutputDriver,
+}
+
+impl Dedupe {
+    /// Returns true if embeddings are collapsed by their design cells.
+    #[must_use]
+    pub const fn collapses_design_cells(&self) -> bool {
+        matches!(self, Self::ByDesignCells | Self::All | Self::ByOutputDriver)
+    }
+
+    /// Returns true if identical rows are dropped.
+    #[must_use]
+    pub const fn collapses_rows(&self) -> bool {
+        matches!(self, Self::All | Self::ByOutputDriver)
+    }
+
+    /// Returns true if results are collapsed by their output drivers.
+    #[must_use]
+    pub const fn collapses_output_drivers(&self) -> bool {
+        matches!(self, Self::ByOutputDriver)
+    }
+}
+
+impl FromStr for Dedupe {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self::None),
+            "by_design_cells" => Ok(Self::ByDesignCells),
+            "all" => Ok(Self::All),
+            "by_output_driver" => Ok(Self::ByOutputDriver),
             _ => Err(()),
         }
     }
@@ -339,6 +1033,8 @@ mod property_tests {
                 MatchLength::First,
                 MatchLength::NeedleSubsetHaystack,
                 MatchLength::Exact,
+                MatchLength::SupersetNeedle,
+                MatchLength::Induced,
             ];
             Self(*g.choose(&variants).unwrap())
         }
@@ -351,7 +1047,9 @@ mod property_tests {
              // If FromStr was robust, we'd check it here.
              // Instead, check boolean properties:
              (ml.0.first() == matches!(ml.0, MatchLength::First)) &&
-             (ml.0.exact() == matches!(ml.0, MatchLength::Exact))
+             (ml.0.exact() == matches!(ml.0, MatchLength::Exact)) &&
+             (ml.0.superset_needle() == matches!(ml.0, MatchLength::SupersetNeedle)) &&
+             (ml.0.induced() == matches!(ml.0, MatchLength::Induced))
          }
     }
 }
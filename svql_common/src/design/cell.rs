@@ -109,10 +109,92 @@ mod tests {
         assert!(CellKind::And.has_commutative_inputs());
         assert!(CellKind::Or.has_commutative_inputs());
         assert!(CellKind::Xor.has_commutative_inputs());
+        assert!(CellKind::Mul.has_commutative_inputs());
+        assert!(CellKind::Adc.has_commutative_inputs());
 
         assert!(!CellKind::Not.has_commutative_inputs());
         assert!(!CellKind::Mux.has_commutative_inputs());
     }
+
+    /// Expected `(sequential, combinational, arith, commutative)` flags.
+    ///
+    /// The match is exhaustive so a new `CellKind` variant fails to compile
+    /// here until it is classified.
+    const fn expected_classes(kind: CellKind) -> (bool, bool, bool, bool) {
+        use CellKind as K;
+        match kind {
+            K::Buf | K::Not => (false, true, false, false),
+            K::And | K::Or | K::Xor | K::Aig | K::Eq => (false, true, false, true),
+            K::Mux | K::ULt | K::SLt | K::Shl | K::UShr | K::SShr | K::XShr => {
+                (false, true, false, false)
+            }
+            K::Adc | K::Mul => (false, true, true, true),
+            K::UDiv | K::UMod | K::SDivTrunc | K::SDivFloor | K::SModTrunc | K::SModFloor => {
+                (false, true, true, false)
+            }
+            K::Match | K::Assign => (false, true, false, false),
+            K::DLatchSr | K::ADLatch | K::Dff | K::Memory => (true, false, false, false),
+            K::IoBuf | K::Target | K::Other | K::Input | K::Output | K::Name | K::Debug => {
+                (false, false, false, false)
+            }
+        }
+    }
+
+    #[test]
+    fn test_cell_kind_classification_table() {
+        use CellKind as K;
+        let all = [
+            K::Buf,
+            K::Not,
+            K::And,
+            K::Or,
+            K::Xor,
+            K::Mux,
+            K::Adc,
+            K::Aig,
+            K::Eq,
+            K::ULt,
+            K::SLt,
+            K::Shl,
+            K::UShr,
+            K::SShr,
+            K::XShr,
+            K::Mul,
+            K::UDiv,
+            K::UMod,
+            K::SDivTrunc,
+            K::SDivFloor,
+            K::SModTrunc,
+            K::SModFloor,
+            K::Match,
+            K::Assign,
+            K::DLatchSr,
+            K::ADLatch,
+            K::Dff,
+            K::Memory,
+            K::IoBuf,
+            K::Target,
+            K::Other,
+            K::Input,
+            K::Output,
+            K::Name,
+            K::Debug,
+        ];
+
+        for kind in all {
+            let actual = (
+                kind.is_sequential(),
+                kind.is_combinational(),
+                kind.is_arith(),
+                kind.is_commutative(),
+            );
+            assert_eq!(actual, expected_classes(kind), "Misclassified {kind}");
+            assert!(!(kind.is_sequential() && kind.is_combinational()));
+            if kind.has_commutative_inputs() {
+                assert!(kind.is_commutative());
+            }
+        }
+    }
 }
 
 #[cfg(test)]
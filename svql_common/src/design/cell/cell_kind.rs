@@ -3,9 +3,10 @@ use std::fmt::Formatter;
 use std::hash::Hash;
 
 use prjunnamed_netlist::Cell;
+use serde::{Deserialize, Serialize};
 
 /// Categorizes netlist primitives into known types for matching.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum CellKind {
     /// Buffer / Identity gate.
     Buf,
@@ -80,6 +81,12 @@ pub enum CellKind {
 }
 
 impl CellKind {
+    /// Kinds that pass each input bit straight to an output bit.
+    ///
+    /// Buffers and inverters are often inserted or removed by synthesis, so
+    /// connectivity checks can treat them as plain wires.
+    pub const TRANSPARENT: [Self; 2] = [Self::Buf, Self::Not];
+
     /// Returns true if the cell represents a combinational or sequential logic gate.
     #[must_use]
     pub const fn is_logic_gate(&self) -> bool {
@@ -126,9 +133,58 @@ impl CellKind {
     }
 
     /// Returns true if the inputs to this cell can be swapped without changing logic.
+    ///
+    /// For `Adc` only the two addends swap; the carry input keeps its place.
     #[must_use]
     pub const fn has_commutative_inputs(&self) -> bool {
-        matches!(self, Self::And | Self::Or | Self::Xor | Self::Aig)
+        matches!(
+            self,
+            Self::And | Self::Or | Self::Xor | Self::Aig | Self::Adc | Self::Mul
+        )
+    }
+
+    /// Returns true if the cell holds state across clock edges or enables.
+    #[must_use]
+    pub const fn is_sequential(&self) -> bool {
+        matches!(
+            self,
+            Self::Dff | Self::DLatchSr | Self::ADLatch | Self::Memory
+        )
+    }
+
+    /// Returns true if the cell's outputs depend only on its current inputs.
+    #[must_use]
+    pub const fn is_combinational(&self) -> bool {
+        (self.is_logic_gate() && !self.is_sequential())
+            || matches!(self, Self::Match | Self::Assign)
+    }
+
+    /// Returns true if the cell performs an arithmetic operation.
+    #[must_use]
+    pub const fn is_arith(&self) -> bool {
+        matches!(
+            self,
+            Self::Adc
+                | Self::Mul
+                | Self::UDiv
+                | Self::UMod
+                | Self::SDivTrunc
+                | Self::SDivFloor
+                | Self::SModTrunc
+                | Self::SModFloor
+        )
+    }
+
+    /// Returns true if the cell's operation is commutative in its two operands.
+    ///
+    /// This is a property of the operation; `has_commutative_inputs` is the
+    /// narrower set the subgraph matcher currently swaps during search.
+    #[must_use]
+    pub const fn is_commutative(&self) -> bool {
+        matches!(
+            self,
+            Self::And | Self::Or | Self::Xor | Self::Aig | Self::Adc | Self::Mul | Self::Eq
+        )
     }
 }
 
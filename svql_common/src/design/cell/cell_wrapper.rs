@@ -205,6 +205,22 @@ impl<'a> CellWrapper<'a> {
         self.inner_cell_ref.metadata()
     }
 
+    /// Returns the value of the Verilog attribute `name` attached to this cell.
+    ///
+    /// Attributes are read from the cell's metadata, so only those preserved
+    /// by the netlist import are visible. The value is returned in its
+    /// netlist text form.
+    #[must_use]
+    pub fn attr(&self, name: &str) -> Option<String> {
+        self.metadata().iter().find_map(|item| match item.get() {
+            MetaItem::Attr {
+                name: attr_name,
+                value,
+            } if &*attr_name.get() == name => Some(value.to_string()),
+            _ => None,
+        })
+    }
+
     /// Returns the name of the input port if this cell is an Input.
     #[must_use]
     pub fn input_name(&self) -> Option<String> {
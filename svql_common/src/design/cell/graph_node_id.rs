@@ -1,10 +1,12 @@
 use contracts::*;
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::hash::Hash;
 
 /// Local identifier within a specific GraphIndex array.
 /// Exclusively used inside the subgraph solver for performance.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct GraphNodeIdx {
     /// The underlying raw integer index.
     inner: u32,
@@ -1,3 +1,5 @@
+use std::fmt;
+
 /// A hierarchical path selector for navigating through netlist structures.
 ///
 /// Represents a path as a sequence of string segments, useful for
@@ -43,6 +45,79 @@ impl<'a> Selector<'a> {
     pub const fn path(&self) -> &[&'a str] {
         self.path
     }
+
+    /// Iterates over the path segments from root to leaf.
+    pub fn segments(&self) -> impl DoubleEndedIterator<Item = &'a str> + ExactSizeIterator {
+        self.path.iter().copied()
+    }
+
+    /// Returns the last segment of the path, if any.
+    pub const fn leaf(&self) -> Option<&'a str> {
+        self.path.last().copied()
+    }
+
+    /// Returns a selector with the last segment removed, or `None` if the
+    /// path is empty.
+    pub const fn parent(&self) -> Option<Self> {
+        match self.path {
+            [rest @ .., _] => Some(Self { path: rest }),
+            [] => None,
+        }
+    }
+
+    /// Returns true if `prefix` matches the leading segments of this path.
+    pub fn starts_with(&self, prefix: Self) -> bool {
+        self.path.len() >= prefix.len() && self.path.iter().zip(prefix.path).all(|(a, b)| a == b)
+    }
+
+    /// Returns the longest run of leading segments shared with `other`.
+    pub fn common_prefix(&self, other: Self) -> Self {
+        let shared = self
+            .path
+            .iter()
+            .zip(other.path)
+            .take_while(|(a, b)| a == b)
+            .count();
+        Self {
+            path: &self.path[..shared],
+        }
+    }
+
+    /// Returns the segments remaining after `prefix`, or `None` if `prefix`
+    /// is not a prefix of this path.
+    pub fn strip_prefix(&self, prefix: Self) -> Option<Self> {
+        self.starts_with(prefix).then(|| Self {
+            path: &self.path[prefix.len()..],
+        })
+    }
+
+    /// Splits a dotted path string (as rendered by `Display`) into segments.
+    ///
+    /// `Selector` borrows its segments, so the result must be kept alive and
+    /// passed to [`Selector::new`]. An empty string yields no segments.
+    pub fn split_path(path: &'a str) -> Vec<&'a str> {
+        if path.is_empty() {
+            Vec::new()
+        } else {
+            path.split('.').collect()
+        }
+    }
+}
+
+impl fmt::Display for Selector<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.path.join("."))
+    }
+}
+
+/// Serializes as the dotted path string rendered by `Display`.
+///
+/// There is no matching `Deserialize`: a `Selector` borrows its segments, so
+/// decode the string and rebuild it with [`Selector::split_path`].
+impl serde::Serialize for Selector<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
 }
 
 impl Selector<'static> {
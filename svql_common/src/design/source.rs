@@ -22,6 +22,19 @@ pub struct SourceLocation {
     pub lines: Vec<SourceLine>,
 }
 
+/// Serializes as `{"file": ..., "lines": [n, ...]}` with 1-indexed line numbers.
+impl serde::Serialize for SourceLocation {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let lines: Vec<usize> = self.lines.iter().map(|line| line.number).collect();
+        let mut state = serializer.serialize_struct("SourceLocation", 2)?;
+        state.serialize_field("file", &*self.file)?;
+        state.serialize_field("lines", &lines)?;
+        state.end()
+    }
+}
+
 impl SourceLocation {
     /// Formats the source location for pretty-printed reports.
     #[must_use]
@@ -32,6 +45,40 @@ impl SourceLocation {
             [first, .., last] => format!("{}:{}-{}", self.file, first.number, last.number),
         }
     }
+
+    /// Collapses the line numbers into sorted, inclusive `(start, end)` spans
+    /// of consecutive lines.
+    #[must_use]
+    pub fn line_spans(&self) -> Vec<(usize, usize)> {
+        let mut numbers: Vec<usize> = self.lines.iter().map(|line| line.number).collect();
+        numbers.sort_unstable();
+        numbers.dedup();
+
+        let mut spans: Vec<(usize, usize)> = Vec::new();
+        for number in numbers {
+            match spans.last_mut() {
+                Some((_, end)) if *end + 1 == number => *end = number,
+                _ => spans.push((number, number)),
+            }
+        }
+        spans
+    }
+
+    /// Formats the line numbers as collapsed ranges, e.g. `12-15, 20`.
+    #[must_use]
+    pub fn line_ranges(&self) -> String {
+        self.line_spans()
+            .into_iter()
+            .map(|(start, end)| {
+                if start == end {
+                    start.to_string()
+                } else {
+                    format!("{start}-{end}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
 }
 
 /// Represents a specific line and column range within a source file.
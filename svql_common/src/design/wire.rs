@@ -1,6 +1,6 @@
 use crate::HashSet;
 use crate::design::cell::PhysicalCellId;
-use prjunnamed_netlist::{Net, Trit, Value};
+use prjunnamed_netlist::{Cell, Design, Net, Trit, Value};
 use std::fmt;
 use std::ops::RangeBounds;
 
@@ -51,6 +51,31 @@ impl Wire {
         self.0.iter().any(|n| other_nets.contains(&n))
     }
 
+    /// Returns this wire extended with every net feeding it through
+    /// [`CellKind::TRANSPARENT`](crate::cell::CellKind::TRANSPARENT) cells.
+    ///
+    /// Each net is followed back through `design` until it reaches a net that
+    /// is not driven by a buffer or inverter. Used to treat those cells as
+    /// plain wires when checking connectivity.
+    #[must_use]
+    pub fn with_transparent_sources(&self, design: &Design) -> Self {
+        let mut seen: HashSet<Net> = HashSet::default();
+        let mut nets = Vec::new();
+        for mut net in self.0.iter() {
+            while seen.insert(net) {
+                nets.push(net);
+                let Ok((cell_ref, bit)) = design.find_cell(net) else {
+                    break;
+                };
+                match cell_ref.get().as_ref() {
+                    Cell::Buf(value) | Cell::Not(value) => net = value[bit],
+                    _ => break,
+                }
+            }
+        }
+        nets.into_iter().collect()
+    }
+
     /// Returns the number of bits in the wire.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -170,8 +195,40 @@ impl PortDecl {
             direction: PortDirection::Output,
         }
     }
+    /// Creates a new inout port declaration.
+    pub const fn inout(name: &'static str) -> Self {
+        Self {
+            name,
+            direction: PortDirection::Inout,
+        }
+    }
+}
+
+/// Error returned when port declarations name ports the design does not have.
+///
+/// A misspelt port never binds during matching, so without this check the
+/// query silently returns no results.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortValidationError {
+    /// The declaration list the offending names came from (e.g. `PORTS`).
+    pub field: &'static str,
+    /// Every declared name with no matching boundary port in the design.
+    pub unknown: Vec<String>,
+}
+
+impl fmt::Display for PortValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} references unknown port(s): {}",
+            self.field,
+            self.unknown.join(", ")
+        )
+    }
 }
 
+impl core::error::Error for PortValidationError {}
+
 impl fmt::Display for PortDirection {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = match self {
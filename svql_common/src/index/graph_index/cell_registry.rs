@@ -91,6 +91,15 @@ impl<'a> CellRegistry<'a> {
             .map_or(&[], std::vec::Vec::as_slice)
     }
 
+    /// Returns the number of cells of each type present in the registry.
+    #[must_use]
+    pub fn kind_histogram(&self) -> HashMap<CellKind, usize> {
+        self.cell_type_indices
+            .iter()
+            .map(|(kind, indices)| (*kind, indices.len()))
+            .collect()
+    }
+
     /// Returns the translation map from physical debug IDs to local graph indices.
     #[must_use]
     pub const fn cell_id_map(&self) -> &HashMap<usize, GraphNodeIdx> {
@@ -6,15 +6,24 @@
 use contracts::*;
 use std::sync::Arc;
 
+use super::NodeBitSet;
 use crate::cell::GraphNodeIdx;
 use crate::*;
 use dashmap::DashMap;
 use prjunnamed_netlist::{CellRef, Design};
+use rayon::prelude::*;
 
 /// Mapping from a cell to its input neighbors and their port indices.
 type FaninMap = HashMap<GraphNodeIdx, Vec<(GraphNodeIdx, usize)>>;
 /// Mapping from a cell to its output neighbors and their port indices.
 type FanoutMap = HashMap<GraphNodeIdx, Vec<(GraphNodeIdx, usize)>>;
+/// Lazily built bitsets of dense neighbour sets, keyed by cell.
+type BitsetCache = Arc<DashMap<GraphNodeIdx, Arc<NodeBitSet>>>;
+
+/// A neighbour set counts as dense once it holds one cell per this many cells.
+///
+/// At that density its bitset takes no more memory than the set's entries.
+const DENSE_RATIO: usize = 64;
 
 /// Stores adjacency information for the design graph, optimized for traversal.
 #[derive(Clone, Debug)]
@@ -33,6 +42,10 @@ pub struct ConnectivityGraph {
     fanin_indices: HashMap<GraphNodeIdx, Vec<GraphNodeIdx>>,
     /// Precomputed intersection of fanout of fanin
     intersect_fanout_of_fanin: Arc<DashMap<GraphNodeIdx, Arc<HashSet<GraphNodeIdx>>>>,
+    /// Bitsets of dense fan-out sets, built on first use
+    fanout_bitsets: BitsetCache,
+    /// Bitsets of dense fan-in sets, built on first use
+    fanin_bitsets: BitsetCache,
 }
 
 impl ConnectivityGraph {
@@ -61,15 +74,59 @@ impl ConnectivityGraph {
 #[debug_invariant(self.is_symmetric())]
 impl ConnectivityGraph {
     /// Builds the connectivity index using design cells and a translation map.
+    ///
+    /// With `parallel`, the adjacency is resolved on the rayon pool.
     #[must_use]
     pub fn build(
         design: &Design,
         cell_refs_topo: &[CellRef<'_>],
         cell_id_map: &HashMap<usize, GraphNodeIdx>,
+        parallel: bool,
     ) -> Self {
         let (fanin_map, fanout_map) =
-            Self::build_fanin_fanout_maps(design, cell_refs_topo, cell_id_map);
+            Self::build_fanin_fanout_maps(design, cell_refs_topo, cell_id_map, parallel);
+        Self::from_maps(fanin_map, fanout_map)
+    }
+
+    /// Extends a graph with cells that were prepended to the registry.
+    ///
+    /// `added_refs` are the new cells, which occupy indices `0..added_refs.len()`
+    /// in `cell_id_map`; every existing index is shifted up by that amount. Only
+    /// the input nets of the new cells are resolved, so the existing cells must
+    /// not read from any of them. Fan-out lists keep the order a full build
+    /// would produce, with edges into lower-indexed sinks first.
+    #[must_use]
+    pub fn with_added_cells(
+        &self,
+        design: &Design,
+        added_refs: &[CellRef<'_>],
+        cell_id_map: &HashMap<usize, GraphNodeIdx>,
+    ) -> Self {
+        let offset = added_refs.len();
+        let shift = |idx: GraphNodeIdx| GraphNodeIdx::new((idx.as_usize() + offset) as u32);
+        let shift_map = |map: &HashMap<GraphNodeIdx, Vec<(GraphNodeIdx, usize)>>| {
+            map.iter()
+                .map(|(idx, list)| {
+                    let list = list.iter().map(|&(cell, pin)| (shift(cell), pin)).collect();
+                    (shift(*idx), list)
+                })
+                .collect::<HashMap<_, _>>()
+        };
+
+        let (mut fanin_map, new_fanout_map) =
+            Self::build_fanin_fanout_maps(design, added_refs, cell_id_map, false);
+        fanin_map.extend(shift_map(&self.fanin_map));
+
+        let mut fanout_map = new_fanout_map;
+        for (idx, list) in shift_map(&self.fanout_map) {
+            fanout_map.entry(idx).or_default().extend(list);
+        }
 
+        Self::from_maps(fanin_map, fanout_map)
+    }
+
+    /// Wraps precomputed adjacency maps, deriving the set and index views.
+    fn from_maps(fanin_map: FaninMap, fanout_map: FanoutMap) -> Self {
         // Precompute fanout and fanin sets
         let fanout_sets = Self::precompute_sets(&fanout_map);
         let fanin_sets = Self::precompute_sets(&fanin_map);
@@ -84,6 +141,8 @@ impl ConnectivityGraph {
             fanout_indices,
             fanin_indices,
             intersect_fanout_of_fanin: Arc::new(DashMap::new()),
+            fanout_bitsets: Arc::new(DashMap::new()),
+            fanin_bitsets: Arc::new(DashMap::new()),
         };
 
         debug_assert_eq!(graph.fanin_map.len(), graph.fanout_map.len());
@@ -98,66 +157,122 @@ impl ConnectivityGraph {
             return HashSet::default();
         };
 
-        if fanin_list.is_empty() {
-            return HashSet::default();
-        }
+        let fanins: Vec<GraphNodeIdx> = fanin_list.iter().map(|(idx, _)| *idx).collect();
+        self.intersect_fanout_indices(&fanins)
+    }
 
-        let fanout_sets_of_fanins: Vec<&HashSet<GraphNodeIdx>> = fanin_list
+    /// Intersects the neighbour sets of `cells` returned by `neighbours`.
+    ///
+    /// Starts from the smallest set. Dense sets are probed through their
+    /// cached bitsets instead of hashing, and if every set is dense the
+    /// bitsets are ANDed word by word.
+    fn intersect_neighbours(
+        &self,
+        cells: &[GraphNodeIdx],
+        neighbours: fn(&Self, GraphNodeIdx) -> &HashSet<GraphNodeIdx>,
+        bitsets: &BitsetCache,
+    ) -> HashSet<GraphNodeIdx> {
+        let mut sets: Vec<(GraphNodeIdx, &HashSet<GraphNodeIdx>)> = cells
             .iter()
-            .filter_map(|(idx, _)| self.fanout_sets.get(idx))
+            .map(|&cell| (cell, neighbours(self, cell)))
             .collect();
-
-        if fanout_sets_of_fanins.is_empty() {
+        sets.sort_by_key(|(_, set)| set.len());
+        let Some((&(first_cell, first), rest)) = sets.split_first() else {
             return HashSet::default();
+        };
+        if rest.is_empty() {
+            return first.clone();
         }
 
-        let mut result = fanout_sets_of_fanins[0].clone();
-        for set in &fanout_sets_of_fanins[1..] {
-            result = &result & *set;
+        let universe = self.fanin_map.len();
+        let is_dense = |set: &HashSet<GraphNodeIdx>| set.len() * DENSE_RATIO >= universe;
+        let bitset = |cell: GraphNodeIdx, set: &HashSet<GraphNodeIdx>| {
+            bitsets
+                .entry(cell)
+                .or_insert_with(|| Arc::new(NodeBitSet::from_nodes(universe, set)))
+                .clone()
+        };
+
+        // Sets are sorted by size, so a dense first set means all are dense
+        if is_dense(first) {
+            let mut result = (*bitset(first_cell, first)).clone();
+            for &(cell, set) in rest {
+                result.intersect_with(&bitset(cell, set));
+            }
+            return result.to_hash_set();
         }
 
-        result
+        let (dense, sparse): (Vec<_>, Vec<_>) = rest.iter().partition(|(_, set)| is_dense(set));
+        let dense: Vec<Arc<NodeBitSet>> = dense
+            .into_iter()
+            .map(|&(cell, set)| bitset(cell, set))
+            .collect();
+        first
+            .iter()
+            .copied()
+            .filter(|node| {
+                dense.iter().all(|set| set.contains(*node))
+                    && sparse.iter().all(|(_, set)| set.contains(node))
+            })
+            .collect()
     }
 
     /// Iterates through the design and builds the bipartite adjacency maps.
+    ///
+    /// Resolving the input nets of each sink is independent of the others, so
+    /// with `parallel` it is sharded across the rayon pool. Fan-out lists are
+    /// always filled in sink order, so both modes produce identical maps.
     fn build_fanin_fanout_maps(
         design: &Design,
         cell_refs_topo: &[CellRef<'_>],
         cell_id_map: &HashMap<usize, GraphNodeIdx>,
+        parallel: bool,
     ) -> (FaninMap, FanoutMap) {
-        let mut fanout_map: FanoutMap = HashMap::default();
-        let mut fanin_map: FaninMap = HashMap::default();
-
-        // Initialize maps for all cells to ensure equal lengths and satisfy invariants
-        for &idx in cell_id_map.values() {
-            fanout_map.insert(idx, Vec::new());
-            fanin_map.insert(idx, Vec::new());
-        }
+        let resolve = |sink_ref: &CellRef<'_>| Self::resolve_fanin(design, sink_ref, cell_id_map);
+        let fanin_lists: Vec<(GraphNodeIdx, Vec<(GraphNodeIdx, usize)>)> = if parallel {
+            cell_refs_topo.par_iter().map(resolve).collect()
+        } else {
+            cell_refs_topo.iter().map(resolve).collect()
+        };
 
-        for sink_ref in cell_refs_topo.iter() {
-            let sink_idx = *cell_id_map
-                .get(&sink_ref.debug_index())
-                .expect("Sink cell should be in map");
-            sink_ref.visit(|net| {
-                if let Ok((source_ref, source_pin_idx)) = design.find_cell(net) {
-                    let source_ref_idx = *cell_id_map
-                        .get(&source_ref.debug_index())
-                        .expect("Source cell should be in map");
-
-                    fanout_map
-                        .entry(source_ref_idx)
-                        .or_default()
-                        .push((sink_idx, source_pin_idx));
-                    fanin_map
-                        .entry(sink_idx)
-                        .or_default()
-                        .push((source_ref_idx, source_pin_idx));
-                }
-            });
+        // Initialize maps for all sinks to ensure equal lengths and satisfy invariants
+        let mut fanout_map: FanoutMap = fanin_lists
+            .iter()
+            .map(|(sink_idx, _)| (*sink_idx, Vec::new()))
+            .collect();
+        for (sink_idx, fanin) in &fanin_lists {
+            for &(source_idx, source_pin_idx) in fanin {
+                fanout_map
+                    .entry(source_idx)
+                    .or_default()
+                    .push((*sink_idx, source_pin_idx));
+            }
         }
+        let fanin_map: FaninMap = fanin_lists.into_iter().collect();
         (fanin_map, fanout_map)
     }
 
+    /// Resolves the drivers of every input net of `sink_ref`.
+    fn resolve_fanin(
+        design: &Design,
+        sink_ref: &CellRef<'_>,
+        cell_id_map: &HashMap<usize, GraphNodeIdx>,
+    ) -> (GraphNodeIdx, Vec<(GraphNodeIdx, usize)>) {
+        let sink_idx = *cell_id_map
+            .get(&sink_ref.debug_index())
+            .expect("Sink cell should be in map");
+        let mut fanin = Vec::new();
+        sink_ref.visit(|net| {
+            if let Ok((source_ref, source_pin_idx)) = design.find_cell(net) {
+                let source_ref_idx = *cell_id_map
+                    .get(&source_ref.debug_index())
+                    .expect("Source cell should be in map");
+                fanin.push((source_ref_idx, source_pin_idx));
+            }
+        });
+        (sink_idx, fanin)
+    }
+
     /// Transforms adjacency lists into `HashSet` for fast existence checks.
     fn precompute_sets(
         map: &HashMap<GraphNodeIdx, Vec<(GraphNodeIdx, usize)>>,
@@ -216,6 +331,18 @@ impl ConnectivityGraph {
         })
     }
 
+    /// Returns the cells in the fan-out of every cell in `cells`.
+    #[must_use]
+    pub fn intersect_fanout_indices(&self, cells: &[GraphNodeIdx]) -> HashSet<GraphNodeIdx> {
+        self.intersect_neighbours(cells, Self::fanout_indices_set, &self.fanout_bitsets)
+    }
+
+    /// Returns the cells in the fan-in of every cell in `cells`.
+    #[must_use]
+    pub fn intersect_fanin_indices(&self, cells: &[GraphNodeIdx]) -> HashSet<GraphNodeIdx> {
+        self.intersect_neighbours(cells, Self::fanin_indices_set, &self.fanin_bitsets)
+    }
+
     /// Get intersection (computes on-demand, caches result)
     #[must_use]
     pub fn get_intersect_fanout_of_fanin_indices(
@@ -6,12 +6,15 @@
 mod cell_registry;
 mod connectivity_graph;
 mod io_mapping;
+mod node_bitset;
 
+use std::collections::BTreeSet;
 use std::sync::Arc;
 
 pub use cell_registry::CellRegistry;
 pub use connectivity_graph::ConnectivityGraph;
 pub use io_mapping::IoMapping;
+pub use node_bitset::NodeBitSet;
 use tracing::info;
 
 use crate::cell::{CellKind, CellWrapper};
@@ -29,16 +32,51 @@ pub struct GraphIndex<'a> {
     io_mapping: IoMapping,
 }
 
+/// Number of distinct cells on either side of a cell.
+///
+/// Used to prune match candidates: a haystack cell can only stand in for a
+/// needle cell whose neighbours all map to distinct cells of its own.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DegreeSignature {
+    /// Number of distinct cells in the fan-in.
+    pub fanin: usize,
+    /// Number of distinct cells in the fan-out.
+    pub fanout: usize,
+}
+
+impl DegreeSignature {
+    /// Returns true if this signature is at least `required` on both sides.
+    #[must_use]
+    pub const fn covers(&self, required: &Self) -> bool {
+        self.fanin >= required.fanin && self.fanout >= required.fanout
+    }
+}
+
 impl<'a> GraphIndex<'a> {
     /// Builds a new `GraphIndex` for the provided design.
     pub fn build(design: &'a Design) -> Self {
+        Self::build_with(design, false)
+    }
+
+    /// Builds a new `GraphIndex`, resolving connectivity on the rayon pool if `parallel` is set.
+    ///
+    /// The topological collection and registry stay sequential; only the
+    /// fan-in/fan-out resolution is sharded by cell, and the result is
+    /// identical to `build`. Calling this from inside rayon work is safe but
+    /// competes with it for the same pool, so the matcher entry points tie
+    /// the flag to `Config::parallel`.
+    pub fn build_with(design: &'a Design, parallel: bool) -> Self {
         info!("Building graph index for design");
         let start = std::time::Instant::now();
 
         let cell_refs_topo = Self::build_cell_refs_topo(design);
         let cell_registry = CellRegistry::build(&cell_refs_topo);
-        let connectivity =
-            ConnectivityGraph::build(design, &cell_refs_topo, cell_registry.cell_id_map());
+        let connectivity = ConnectivityGraph::build(
+            design,
+            &cell_refs_topo,
+            cell_registry.cell_id_map(),
+            parallel,
+        );
         let io_mapping = IoMapping::build(
             &cell_refs_topo,
             connectivity.fanin_map(),
@@ -58,6 +96,84 @@ impl<'a> GraphIndex<'a> {
         }
     }
 
+    /// Extends `base` with cells appended to its design, without rebuilding it from scratch.
+    ///
+    /// This skips the topological sort and reuses the adjacency of the
+    /// existing cells, resolving only the input nets of `new_cells`. It is
+    /// meant for cheap variants of a design, e.g. when fuzzing patterns by
+    /// adding a few cells at a time. The method assumes, without checking in
+    /// release builds, that:
+    ///
+    /// - `design` holds every cell of `base` under the same debug index and
+    ///   with the same inputs, e.g. a copy of the base design that was only
+    ///   mutated by adding cells;
+    /// - `new_cells` are exactly the cells of `design` missing from `base`,
+    ///   in topological order (drivers before the cells reading them), so
+    ///   creation order works;
+    /// - no cell of `base` reads from any of `new_cells`.
+    ///
+    /// The new cells are placed before the existing ones, which keeps the
+    /// index in reverse topological order but shifts every existing
+    /// `GraphNodeIdx` up by the number of added cells. Indices taken from
+    /// `base` must therefore not be reused with the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a cell of `base` is missing from `design`, or if a new cell
+    /// reads from a cell that is in neither.
+    pub fn with_added_cells(
+        base: &GraphIndex<'_>,
+        design: &'a Design,
+        new_cells: &[CellRef<'a>],
+    ) -> Self {
+        info!("Extending graph index with {} cells", new_cells.len());
+        let start = std::time::Instant::now();
+
+        let by_debug_index: HashMap<usize, CellRef<'a>> = design
+            .iter_cells()
+            .map(|cell_ref| (cell_ref.debug_index(), cell_ref))
+            .collect();
+        let base_refs = (0..base.num_cells()).map(|i| {
+            let debug_index = base
+                .get_cell_by_index(GraphNodeIdx::new(i as u32))
+                .debug_index()
+                .storage_key() as usize;
+            *by_debug_index
+                .get(&debug_index)
+                .expect("Base cell should be in design")
+        });
+
+        let added: Vec<CellRef<'a>> = new_cells
+            .iter()
+            .rev()
+            .copied()
+            .filter(|cell_ref| !matches!(CellKind::from(cell_ref.get().as_ref()), CellKind::Name))
+            .collect();
+        let cell_refs_topo: Vec<CellRef<'a>> = added.iter().copied().chain(base_refs).collect();
+
+        let cell_registry = CellRegistry::build(&cell_refs_topo);
+        let connectivity =
+            base.connectivity
+                .with_added_cells(design, &added, cell_registry.cell_id_map());
+        let io_mapping = IoMapping::build(
+            &cell_refs_topo,
+            connectivity.fanin_map(),
+            connectivity.fanout_map(),
+        );
+
+        tracing::info!(
+            "graph index extended in {:?} to {} cells",
+            start.elapsed(),
+            cell_registry.len()
+        );
+
+        GraphIndex {
+            cell_registry,
+            connectivity,
+            io_mapping,
+        }
+    }
+
     /// Returns the total number of cells in the index.
     #[must_use]
     pub const fn num_cells(&self) -> usize {
@@ -94,6 +210,141 @@ impl<'a> GraphIndex<'a> {
         self.connectivity.fanin_indices_set(cell_idx)
     }
 
+    /// Returns the fan-in and fan-out degree of the specified cell.
+    ///
+    /// Both counts come from the precomputed neighbour sets, so this is
+    /// constant time and equal to the sizes of `fanin_set` and `fanout_set`.
+    #[must_use]
+    pub fn degree_signature(&self, cell_idx: GraphNodeIdx) -> DegreeSignature {
+        DegreeSignature {
+            fanin: self.fanin_set(cell_idx).len(),
+            fanout: self.fanout_set(cell_idx).len(),
+        }
+    }
+
+    /// Returns the cells in the immediate fan-out of every cell in `cells`.
+    ///
+    /// Dense fan-out sets are intersected as bitsets, so this is cheaper than
+    /// intersecting the results of `fanout_set` by hand.
+    #[must_use]
+    pub fn intersect_fanout(&self, cells: &[GraphNodeIdx]) -> HashSet<GraphNodeIdx> {
+        self.connectivity.intersect_fanout_indices(cells)
+    }
+
+    /// Returns the cells in the immediate fan-in of every cell in `cells`.
+    #[must_use]
+    pub fn intersect_fanin(&self, cells: &[GraphNodeIdx]) -> HashSet<GraphNodeIdx> {
+        self.connectivity.intersect_fanin_indices(cells)
+    }
+
+    /// Returns the fan-out of the specified cell, looking through cells whose kind is in `skip`.
+    ///
+    /// A skipped cell is replaced by its own fan-out, recursively, so the
+    /// result never contains skipped cells. Unlike `fanout_set`, the set is
+    /// computed on each call.
+    #[must_use]
+    pub fn fanout_set_skipping(
+        &self,
+        cell_idx: GraphNodeIdx,
+        skip: &BTreeSet<CellKind>,
+    ) -> HashSet<GraphNodeIdx> {
+        self.neighbours_skipping(cell_idx, skip, |idx| self.fanout_set(idx))
+    }
+
+    /// Returns the fan-in of the specified cell, looking through cells whose kind is in `skip`.
+    ///
+    /// Same behavior and cost as `fanout_set_skipping`.
+    #[must_use]
+    pub fn fanin_set_skipping(
+        &self,
+        cell_idx: GraphNodeIdx,
+        skip: &BTreeSet<CellKind>,
+    ) -> HashSet<GraphNodeIdx> {
+        self.neighbours_skipping(cell_idx, skip, |idx| self.fanin_set(idx))
+    }
+
+    /// Collects the neighbours of `node` along `edges`, expanding skipped cells in place.
+    fn neighbours_skipping<'s, F>(
+        &'s self,
+        node: GraphNodeIdx,
+        skip: &BTreeSet<CellKind>,
+        edges: F,
+    ) -> HashSet<GraphNodeIdx>
+    where
+        F: Fn(GraphNodeIdx) -> &'s HashSet<GraphNodeIdx>,
+    {
+        let mut neighbours = HashSet::new();
+        let mut skipped = HashSet::new();
+        let mut stack = vec![node];
+        while let Some(idx) = stack.pop() {
+            for &next in edges(idx) {
+                if !skip.contains(&self.node_kind(next)) {
+                    neighbours.insert(next);
+                } else if skipped.insert(next) {
+                    stack.push(next);
+                }
+            }
+        }
+        neighbours
+    }
+
+    /// Returns every cell transitively reachable through fan-out edges.
+    ///
+    /// Performs a breadth-first search on each call; the result is not
+    /// cached. Cost is linear in the size of the reachable cone, which for a
+    /// cell near the inputs can be most of the design. The start cell is
+    /// only included if it lies on a cycle (e.g. a register feedback loop).
+    #[must_use]
+    pub fn reachable_fanout(&self, node: GraphNodeIdx) -> HashSet<GraphNodeIdx> {
+        self.reachable(node, |idx| self.fanout_set(idx))
+    }
+
+    /// Returns every cell transitively reachable through fan-in edges.
+    ///
+    /// Same cost and caching behavior as `reachable_fanout`.
+    #[must_use]
+    pub fn reachable_fanin(&self, node: GraphNodeIdx) -> HashSet<GraphNodeIdx> {
+        self.reachable(node, |idx| self.fanin_set(idx))
+    }
+
+    /// Checks whether `to` can be reached from `from` by following fan-out edges.
+    ///
+    /// Stops as soon as `to` is found, so it is cheaper than building the full
+    /// `reachable_fanout` set, but still a fresh search per call.
+    #[must_use]
+    pub fn is_reachable(&self, from: GraphNodeIdx, to: GraphNodeIdx) -> bool {
+        let mut visited = HashSet::new();
+        let mut queue = std::collections::VecDeque::from([from]);
+        while let Some(idx) = queue.pop_front() {
+            for &next in self.fanout_set(idx) {
+                if next == to {
+                    return true;
+                }
+                if visited.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        false
+    }
+
+    /// Breadth-first traversal from `node` using `edges` to expand each cell.
+    fn reachable<'s, F>(&'s self, node: GraphNodeIdx, edges: F) -> HashSet<GraphNodeIdx>
+    where
+        F: Fn(GraphNodeIdx) -> &'s HashSet<GraphNodeIdx>,
+    {
+        let mut visited = HashSet::new();
+        let mut queue = std::collections::VecDeque::from([node]);
+        while let Some(idx) = queue.pop_front() {
+            for &next in edges(idx) {
+                if visited.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        visited
+    }
+
     /// Returns the fan-out cell indices paired with their source pin indices.
     #[must_use]
     pub fn fanout_with_ports(&self, cell_idx: GraphNodeIdx) -> Option<&[(GraphNodeIdx, usize)]> {
@@ -138,6 +389,48 @@ impl<'a> GraphIndex<'a> {
         self.io_mapping.output_fanin_by_name_map()
     }
 
+    /// Checks that every declared port names a boundary cell of this design.
+    ///
+    /// Input declarations must match an input cell and output declarations an
+    /// output cell. Inout and undirected declarations may match either.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PortValidationError`] tagged with `field` that lists every
+    /// declaration without a matching port.
+    pub fn validate_port_decls(
+        &self,
+        field: &'static str,
+        decls: &[PortDecl],
+    ) -> Result<(), PortValidationError> {
+        let mut inputs: HashSet<String> = HashSet::new();
+        for &idx in self.cells_of_type_indices(CellKind::Input) {
+            inputs.extend(self.get_cell_by_index(idx).input_name());
+        }
+        let mut outputs: HashSet<String> = HashSet::new();
+        for &idx in self.cells_of_type_indices(CellKind::Output) {
+            outputs.extend(self.get_cell_by_index(idx).output_name());
+        }
+
+        let unknown: Vec<String> = decls
+            .iter()
+            .filter(|decl| match decl.direction {
+                PortDirection::Input => !inputs.contains(decl.name),
+                PortDirection::Output => !outputs.contains(decl.name),
+                PortDirection::Inout | PortDirection::None => {
+                    !inputs.contains(decl.name) && !outputs.contains(decl.name)
+                }
+            })
+            .map(|decl| decl.name.to_owned())
+            .collect();
+
+        if unknown.is_empty() {
+            Ok(())
+        } else {
+            Err(PortValidationError { field, unknown })
+        }
+    }
+
     // --- Job A: Fast Topology (For Solver) ---
 
     /// Returns the immediate fan-out node indices for the specified node.
@@ -196,6 +489,31 @@ impl<'a> GraphIndex<'a> {
         self.cell_registry.cells_of_type_indices(node_type)
     }
 
+    /// Returns how many cells of the given type the design contains.
+    #[must_use]
+    pub fn count_of_kind(&self, kind: CellKind) -> usize {
+        self.cell_registry.cells_of_type_indices(kind).len()
+    }
+
+    /// Returns the number of cells of each type in the design.
+    ///
+    /// Kinds with no cells are absent from the map.
+    #[must_use]
+    pub fn kind_histogram(&self) -> HashMap<CellKind, usize> {
+        self.cell_registry.kind_histogram()
+    }
+
+    /// Returns every cell carrying the attribute `name` with the given `value`.
+    ///
+    /// Scans all cells; see `CellWrapper::attr` for how values are compared.
+    #[must_use]
+    pub fn cells_with_attr(&self, name: &str, value: &str) -> Vec<GraphNodeIdx> {
+        (0..self.num_cells())
+            .map(|i| GraphNodeIdx::new(i as u32))
+            .filter(|&idx| self.get_cell_by_index(idx).attr(name).as_deref() == Some(value))
+            .collect()
+    }
+
     /// Retrieves a cell wrapper by its unique debug identifier.
     #[must_use]
     pub fn get_cell_by_id(&self, id: usize) -> Option<&CellWrapper<'a>> {
@@ -228,4 +546,78 @@ impl<'a> GraphIndex<'a> {
 
         false
     }
+
+    /// Like `is_connected`, but looking through cells whose kind is in `skip`.
+    ///
+    /// Pass `Config::skipped_cell_kinds` to honour `skip_transparent`, so a
+    /// cell driving a register enable through an inserted buffer still counts
+    /// as connected to the register.
+    #[must_use]
+    pub fn is_connected_skipping(
+        &self,
+        from_id: u64,
+        to_id: u64,
+        skip: &BTreeSet<CellKind>,
+    ) -> bool {
+        let (Some(from_idx), Some(to_idx)) = (
+            self.get_cell_index_by_debug_id(from_id as usize),
+            self.get_cell_index_by_debug_id(to_id as usize),
+        ) else {
+            return false;
+        };
+
+        self.fanout_set_skipping(from_idx, skip).contains(&to_idx)
+    }
+
+    /// Finds the shortest chain of cells from `from_id` to `to_id` along fan-out edges.
+    ///
+    /// Both ids are debug identifiers, as in `is_connected`. The returned path
+    /// starts at `from_id` and ends at `to_id`. Returns `None` if either cell
+    /// is unknown, no path exists, or the path would take more than
+    /// `max_depth` edges. Leaving `max_depth` unset searches the whole fan-out
+    /// cone, which on large designs can visit most cells.
+    #[must_use]
+    pub fn shortest_path(
+        &self,
+        from_id: u64,
+        to_id: u64,
+        max_depth: Option<usize>,
+    ) -> Option<Vec<GraphNodeIdx>> {
+        let from_idx = self.get_cell_index_by_debug_id(from_id as usize)?;
+        let to_idx = self.get_cell_index_by_debug_id(to_id as usize)?;
+
+        if from_idx == to_idx {
+            return Some(vec![from_idx]);
+        }
+
+        // Breadth-first search, remembering each cell's predecessor
+        let mut parents: HashMap<GraphNodeIdx, GraphNodeIdx> = HashMap::new();
+        let mut frontier = vec![from_idx];
+        let mut depth = 0;
+        while !frontier.is_empty() && max_depth.is_none_or(|max| depth < max) {
+            depth += 1;
+            let mut next_frontier = Vec::new();
+            for &idx in &frontier {
+                for &next in self.fanout_set(idx) {
+                    if next == from_idx || parents.contains_key(&next) {
+                        continue;
+                    }
+                    parents.insert(next, idx);
+                    if next == to_idx {
+                        let mut path = vec![to_idx];
+                        let mut current = to_idx;
+                        while let Some(&parent) = parents.get(&current) {
+                            path.push(parent);
+                            current = parent;
+                        }
+                        path.reverse();
+                        return Some(path);
+                    }
+                    next_frontier.push(next);
+                }
+            }
+            frontier = next_frontier;
+        }
+        None
+    }
 }
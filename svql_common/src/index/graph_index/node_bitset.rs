@@ -0,0 +1,105 @@
+//! Fixed-size bitsets over graph node indices.
+//!
+//! Cell indices in a `GraphIndex` are contiguous, so a set of cells can be
+//! stored as one bit per cell. Intersecting two such sets is a word-wise AND,
+//! which beats per-element hashing once the sets are dense.
+
+use crate::cell::GraphNodeIdx;
+use crate::*;
+
+/// Number of node bits stored per word.
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A set of graph nodes backed by a fixed-size bitset.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NodeBitSet {
+    /// One bit per node, least significant bit first.
+    words: Vec<u64>,
+}
+
+impl NodeBitSet {
+    /// Creates an empty set able to hold nodes `0..universe`.
+    #[must_use]
+    pub fn new(universe: usize) -> Self {
+        Self {
+            words: vec![0; universe.div_ceil(WORD_BITS)],
+        }
+    }
+
+    /// Creates a set holding `nodes`, all of which must be below `universe`.
+    #[must_use]
+    pub fn from_nodes<'n>(
+        universe: usize,
+        nodes: impl IntoIterator<Item = &'n GraphNodeIdx>,
+    ) -> Self {
+        let mut set = Self::new(universe);
+        for &node in nodes {
+            set.insert(node);
+        }
+        set
+    }
+
+    /// Adds `node` to the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` is outside the universe the set was created with.
+    pub fn insert(&mut self, node: GraphNodeIdx) {
+        let idx = node.as_usize();
+        self.words[idx / WORD_BITS] |= 1 << (idx % WORD_BITS);
+    }
+
+    /// Returns true if `node` is in the set.
+    #[must_use]
+    pub fn contains(&self, node: GraphNodeIdx) -> bool {
+        let idx = node.as_usize();
+        self.words
+            .get(idx / WORD_BITS)
+            .is_some_and(|word| word & (1 << (idx % WORD_BITS)) != 0)
+    }
+
+    /// Keeps only the nodes that are also in `other`.
+    pub fn intersect_with(&mut self, other: &Self) {
+        for (word, other_word) in self.words.iter_mut().zip(&other.words) {
+            *word &= other_word;
+        }
+        let common = other.words.len().min(self.words.len());
+        self.words[common..].fill(0);
+    }
+
+    /// Returns the number of nodes in the set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns true if the set holds no nodes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// Iterates over the nodes in the set in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = GraphNodeIdx> + '_ {
+        self.words.iter().enumerate().flat_map(|(word_idx, &word)| {
+            let mut remaining = word;
+            std::iter::from_fn(move || {
+                if remaining == 0 {
+                    return None;
+                }
+                let bit = remaining.trailing_zeros() as usize;
+                remaining &= remaining - 1;
+                Some(GraphNodeIdx::new((word_idx * WORD_BITS + bit) as u32))
+            })
+        })
+    }
+
+    /// Collects the set into a `HashSet`.
+    #[must_use]
+    pub fn to_hash_set(&self) -> HashSet<GraphNodeIdx> {
+        self.iter().collect()
+    }
+}
@@ -4,6 +4,7 @@
 //! - **Design**: Hardware cell types, wire references, and source location tracking
 //! - **Config**: Query configuration (match strategies, Yosys parameters, parallelism)
 //! - **Bridge**: Integration with Yosys for design processing and transformation
+//! - **Build support**: Synthesis of Verilog fixtures into cached JSON netlists
 //!
 //! The types defined here form the bridge between high-level pattern queries
 //! and low-level netlist data structures.
@@ -11,10 +12,11 @@
 #![feature(derive_const, const_cmp, const_trait_impl)]
 
 pub mod bridge;
+pub mod build_support;
 mod config;
 pub mod design;
 pub mod index;
-/// Utility functions for type name manipulation.
+/// Utility functions for type name manipulation and stable hashing.
 pub mod util;
 
 pub use crate::bridge::yosys::*;
@@ -1,3 +1,5 @@
+use core::hash::Hasher;
+
 /// Sanitizes a Rust type name by replacing special characters.
 ///
 /// Converts `::` to `_`, removes angle brackets and commas, normalizes underscores.
@@ -18,3 +20,42 @@ pub fn sanitize_type_name(type_name: &str) -> String {
 pub fn short_type_name(type_name: &str) -> &str {
     type_name.rsplit("::").next().unwrap_or(type_name)
 }
+
+/// A 64-bit FNV-1a hasher whose output does not change between runs.
+///
+/// Unlike `DefaultHasher`, the algorithm is fixed, so hashes can name files
+/// in on-disk caches and key results across process restarts.
+#[derive(Debug, Clone, Copy)]
+pub struct StableHasher(u64);
+
+impl StableHasher {
+    /// FNV-1a 64-bit offset basis.
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    /// FNV-1a 64-bit prime.
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    /// Creates a hasher in its initial state.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+}
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+#![allow(missing_docs)]
+#![allow(clippy::missing_docs_in_private_items)]
+
+use svql_common::build_support::ensure_synthesized_yosys;
+use svql_common::{ModuleConfig, YosysExecutionError, YosysModule, find_yosys_binary};
+
+mod common;
+
+const FIXTURE: &str = "examples/fixtures/basic/and/verilog/and_mux_tree.v";
+
+#[test]
+fn test_ensure_synthesized_reuses_cached_json() {
+    let cache_dir = tempfile::tempdir().unwrap();
+    let yosys = find_yosys_binary().unwrap();
+    let config = ModuleConfig::default();
+
+    let json =
+        ensure_synthesized_yosys(FIXTURE, "and_mux", &config, &yosys, Some(cache_dir.path()))
+            .expect("Fixture should synthesize");
+    let first_modified = std::fs::metadata(&json).unwrap().modified().unwrap();
+
+    let again =
+        ensure_synthesized_yosys(FIXTURE, "and_mux", &config, &yosys, Some(cache_dir.path()))
+            .expect("Cached fixture should be reused");
+    assert_eq!(again, json);
+    assert_eq!(
+        std::fs::metadata(&again).unwrap().modified().unwrap(),
+        first_modified
+    );
+
+    let module = YosysModule::from_json(&json, "and_mux").unwrap();
+    assert!(module.import_design(&config).is_ok());
+}
+
+#[test]
+fn test_ensure_synthesized_reports_yosys_errors() {
+    let cache_dir = tempfile::tempdir().unwrap();
+    let yosys = find_yosys_binary().unwrap();
+
+    let err = ensure_synthesized_yosys(
+        FIXTURE,
+        "does_not_exist",
+        &ModuleConfig::default(),
+        &yosys,
+        Some(cache_dir.path()),
+    )
+    .expect_err("A missing module should fail to synthesize");
+
+    assert!(err.to_string().contains("does_not_exist"));
+    assert!(err.error.downcast_ref::<YosysExecutionError>().is_some());
+}
@@ -0,0 +1,35 @@
+#![allow(missing_docs)]
+#![allow(clippy::missing_docs_in_private_items)]
+
+use svql_common::{Config, MatchLength};
+
+mod common;
+
+#[test]
+fn test_config_into_builder_round_trip() {
+    let config = Config::builder()
+        .match_length(MatchLength::Exact)
+        .needle_param("WIDTH", "8")
+        .haystack_opt(true)
+        .pattern_vars_match_design_consts(false)
+        .max_matches(Some(3))
+        .width_tolerant(true)
+        .parallel(true)
+        .verify_functional(true)
+        .build();
+
+    assert_eq!(config.clone().into_builder().build(), config);
+}
+
+#[test]
+fn test_config_into_builder_overrides_single_field() {
+    let base = Config::builder()
+        .match_length(MatchLength::NeedleSubsetHaystack)
+        .parallel(true)
+        .build();
+
+    let variant = base.clone().into_builder().parallel(false).build();
+
+    assert_eq!(variant.match_length, base.match_length);
+    assert!(!variant.parallel);
+}
@@ -0,0 +1,65 @@
+#![allow(missing_docs)]
+#![allow(clippy::missing_docs_in_private_items)]
+
+use svql_common::{Config, ConfigDiff, MatchLength};
+
+mod common;
+
+#[test]
+fn test_config_diff_identical_is_empty() {
+    let config = Config::builder().needle_param("WIDTH", "8").build();
+    assert!(config.diff(&config.clone()).is_empty());
+}
+
+#[test]
+fn test_config_diff_reports_scalar_changes() {
+    let old = Config::builder().match_length(MatchLength::First).build();
+    let new = old
+        .clone()
+        .into_builder()
+        .match_length(MatchLength::Exact)
+        .max_matches(Some(4))
+        .build();
+
+    let diffs = old.diff(&new);
+    assert_eq!(
+        diffs,
+        vec![
+            ConfigDiff::Changed {
+                field: "match_length".to_string(),
+                old: "First".to_string(),
+                new: "Exact".to_string(),
+            },
+            ConfigDiff::Changed {
+                field: "max_matches".to_string(),
+                old: "None".to_string(),
+                new: "Some(4)".to_string(),
+            },
+        ]
+    );
+    assert_eq!(diffs[0].to_string(), "~ match_length: First -> Exact");
+}
+
+#[test]
+fn test_config_diff_reports_list_and_map_entries() {
+    let old = Config::builder()
+        .needle_param("WIDTH", "8")
+        .needle_param("DEPTH", "2")
+        .haystack_cmd("opt_clean")
+        .build();
+    let new = Config::builder()
+        .needle_param("WIDTH", "16")
+        .haystack_cmd("opt_clean")
+        .haystack_cmd("proc")
+        .build();
+
+    let report: Vec<String> = old.diff(&new).iter().map(ToString::to_string).collect();
+    assert_eq!(
+        report,
+        vec![
+            r#"~ needle_options.params[WIDTH]: "8" -> "16""#,
+            r#"- needle_options.params: ("DEPTH", "2")"#,
+            r#"+ haystack_options.other_steps: "proc""#,
+        ]
+    );
+}
@@ -0,0 +1,82 @@
+#![allow(missing_docs)]
+#![allow(clippy::missing_docs_in_private_items)]
+
+use std::time::Duration;
+
+use svql_common::{CellKind, Config, Dedupe, MatchLength, ModuleConfig};
+
+mod common;
+
+#[test]
+fn test_config_toml_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("profile.toml");
+
+    let config = Config::builder()
+        .match_length(MatchLength::NeedleSubsetHaystack)
+        .haystack_options(
+            ModuleConfig::new()
+                .with_param("WIDTH", "32")
+                .with_timeout(Duration::from_secs(30)),
+        )
+        .max_matches(Some(10))
+        .parallel(true)
+        .build();
+
+    config.to_toml_file(&path).unwrap();
+    let loaded = Config::from_toml_file(&path).unwrap();
+
+    assert_eq!(loaded, config);
+}
+
+#[test]
+fn test_config_json_partial_uses_defaults() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("profile.json");
+    std::fs::write(&path, r#"{ "match_length": "exact", "parallel": true }"#).unwrap();
+
+    let loaded = Config::from_json_file(&path).unwrap();
+
+    assert_eq!(loaded.match_length, MatchLength::Exact);
+    assert!(loaded.parallel);
+    assert_eq!(loaded.max_matches, None);
+    assert_eq!(loaded.needle_options, ModuleConfig::default());
+}
+
+#[test]
+fn test_config_json_ignore_cell_kinds() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("profile.json");
+    std::fs::write(&path, r#"{ "ignore_cell_kinds": ["Buf", "Not"] }"#).unwrap();
+
+    let loaded = Config::from_json_file(&path).unwrap();
+
+    assert_eq!(
+        loaded,
+        Config::builder()
+            .ignore_cell_kinds([CellKind::Not, CellKind::Buf])
+            .build()
+    );
+}
+
+#[test]
+fn test_config_json_dedupe() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("debug.json");
+    std::fs::write(&path, r#"{ "dedupe": "by_design_cells" }"#).unwrap();
+
+    let loaded = Config::from_json_file(&path).unwrap();
+
+    assert_eq!(loaded.dedupe, Dedupe::ByDesignCells);
+}
+
+#[test]
+fn test_config_file_error_names_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("broken.toml");
+    std::fs::write(&path, "match_length = \"sideways\"").unwrap();
+
+    let err = Config::from_toml_file(&path).unwrap_err();
+
+    assert!(err.to_string().contains("broken.toml"));
+}
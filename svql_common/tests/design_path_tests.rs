@@ -31,6 +31,25 @@ fn test_design_path_json() {
     assert_eq!(dp.read_command(), "read_json");
 }
 
+#[test]
+fn test_design_path_blif() {
+    let path = PathBuf::from("mapped.blif");
+    let dp = DesignPath::new(path).unwrap();
+    assert!(matches!(dp, DesignPath::Blif(_)));
+    assert_eq!(dp.read_command(), "read_blif");
+}
+
+#[test]
+fn test_design_path_edif() {
+    for name in ["vendor.edif", "vendor.edf"] {
+        let path = PathBuf::from(name);
+        let dp = DesignPath::new(path.clone()).unwrap();
+        assert!(matches!(dp, DesignPath::Edif(_)));
+        assert_eq!(dp.read_command(), "read_edif");
+        assert_eq!(dp.path(), &path);
+    }
+}
+
 #[test]
 fn test_design_path_unsupported() {
     let path = PathBuf::from("design.txt");
@@ -64,7 +83,7 @@ mod property_tests {
 
     impl Arbitrary for ArbitraryDesignPath {
         fn arbitrary(g: &mut Gen) -> Self {
-            let extensions = vec!["v", "il", "json"];
+            let extensions = vec!["v", "il", "json", "blif", "edif", "edf"];
             let ext = g.choose(&extensions).unwrap();
             // get random stem. can use seperators
             let stem_len: usize = usize::arbitrary(g) % 10 + 1; // 1 to 10 segments
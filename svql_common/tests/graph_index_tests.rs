@@ -1,8 +1,11 @@
 #![allow(missing_docs)]
 #![allow(clippy::missing_docs_in_private_items)]
 
-use prjunnamed_netlist::Design;
-use svql_common::{GraphIndex, GraphNodeIdx, ModuleConfig, YosysModule};
+use prjunnamed_netlist::{Cell, ControlNet, Design, FlipFlop, MetaItem, ParamValue};
+use svql_common::{
+    CellKind, Config, GraphIndex, GraphNodeIdx, HashSet, ModuleConfig, NodeBitSet, PortDecl,
+    YosysModule,
+};
 
 mod common;
 
@@ -19,6 +22,21 @@ fn create_test_design() -> Design {
         .expect("Failed to import design")
 }
 
+/// A register whose enable is driven from input `a` through an inserted buffer.
+fn create_buffered_enable_design() -> Design {
+    let mut design = Design::new();
+    let clk = design.add_input("clk", 1);
+    let a = design.add_input("a", 1);
+    let d = design.add_input("d", 1);
+    let en = design.add_cell(Cell::Buf(a));
+    let q = design.add_cell(Cell::Dff(
+        FlipFlop::new(d, ControlNet::Pos(clk[0])).with_enable(ControlNet::Pos(en[0])),
+    ));
+    design.add_output("q", q);
+    design.apply();
+    design
+}
+
 #[test]
 fn test_graph_index_build() {
     let design = create_test_design();
@@ -82,3 +100,361 @@ fn test_graph_index_io_mapping() {
         assert!(!fanin.is_empty(), "Output {} has no fanin", name);
     }
 }
+
+#[test]
+fn test_graph_index_kind_histogram() {
+    let design = create_test_design();
+    let index = GraphIndex::build(&design);
+
+    let histogram = index.kind_histogram();
+    assert_eq!(index.count_of_kind(CellKind::And), 3);
+    assert_eq!(histogram.get(&CellKind::And), Some(&3));
+    assert_eq!(index.count_of_kind(CellKind::Mux), 0);
+    assert!(!histogram.contains_key(&CellKind::Mux));
+    assert_eq!(histogram.values().sum::<usize>(), index.num_cells());
+}
+
+#[test]
+fn test_graph_index_reachability() {
+    let design = create_test_design();
+    let index = GraphIndex::build(&design);
+
+    // `a` feeds a leaf AND gate, `y` is driven by the root AND gate
+    let leaf = index.get_input_fanout_by_name_indices()["a"][0].0;
+    let root = index.get_output_fanin_by_name_indices()["y"][0].0;
+
+    assert!(index.reachable_fanout(leaf).contains(&root));
+    assert!(index.reachable_fanin(root).contains(&leaf));
+    assert!(index.is_reachable(leaf, root));
+    assert!(!index.is_reachable(root, leaf));
+
+    // Transitive fanout and fanin are mirror images
+    for i in 0..index.num_cells() {
+        let node = GraphNodeIdx::new(i as u32);
+        for reached in index.reachable_fanout(node) {
+            assert!(index.reachable_fanin(reached).contains(&node));
+        }
+    }
+}
+
+#[test]
+fn test_graph_index_shortest_path() {
+    let design = create_test_design();
+    let index = GraphIndex::build(&design);
+
+    let leaf = index.get_input_fanout_by_name_indices()["a"][0].0;
+    let root = index.get_output_fanin_by_name_indices()["y"][0].0;
+    let debug_id = |idx| u64::from(index.resolve_physical(idx).storage_key());
+
+    let path = index
+        .shortest_path(debug_id(leaf), debug_id(root), None)
+        .expect("Leaf gate should reach the root gate");
+    assert_eq!(path, vec![leaf, root]);
+
+    assert!(
+        index
+            .shortest_path(debug_id(root), debug_id(leaf), None)
+            .is_none()
+    );
+    assert!(
+        index
+            .shortest_path(debug_id(leaf), debug_id(root), Some(0))
+            .is_none()
+    );
+}
+
+#[test]
+fn test_graph_index_cells_without_attr() {
+    let design = create_test_design();
+    let index = GraphIndex::build(&design);
+
+    // The fixture carries no `dont_touch` annotations
+    assert!(index.cells_with_attr("dont_touch", "1").is_empty());
+    for i in 0..index.num_cells() {
+        let cell = index.get_cell_by_index(GraphNodeIdx::new(i as u32));
+        assert!(cell.attr("dont_touch").is_none());
+    }
+}
+
+/// `y = a & b`, with the AND gate annotated `(* dont_touch = 1 *)`.
+fn create_annotated_design() -> Design {
+    let mut design = Design::new();
+    let a = design.add_input("a", 1);
+    let b = design.add_input("b", 1);
+    let name = design.add_metadata_string("dont_touch");
+    let attr = design.add_metadata_item(&MetaItem::Attr {
+        name,
+        value: ParamValue::Int(1),
+    });
+    let y = {
+        let _guard = design.use_metadata(attr);
+        design.add_and(a, b)
+    };
+    design.add_output("y", y);
+    design.apply();
+    design
+}
+
+#[test]
+fn test_graph_index_cells_with_attr() {
+    let design = create_annotated_design();
+    let index = GraphIndex::build(&design);
+
+    let and = index.cells_of_type_indices(CellKind::And)[0];
+    let cell = index.get_cell_by_index(and);
+    let value = cell
+        .attr("dont_touch")
+        .expect("attribute should be read from the cell metadata");
+    assert!(cell.attr("keep").is_none());
+    assert_eq!(index.cells_with_attr("dont_touch", &value), vec![and]);
+}
+
+#[test]
+fn test_validate_port_decls_accepts_known_ports() {
+    let design = create_test_design();
+    let index = GraphIndex::build(&design);
+
+    let decls = [
+        PortDecl::input("a"),
+        PortDecl::input("b"),
+        PortDecl::output("y"),
+    ];
+    assert!(index.validate_port_decls("PORTS", &decls).is_ok());
+}
+
+#[test]
+fn test_validate_port_decls_reports_unknown_ports() {
+    let design = create_test_design();
+    let index = GraphIndex::build(&design);
+
+    // "y" exists, but only as an output.
+    let decls = [
+        PortDecl::input("a"),
+        PortDecl::input("typo"),
+        PortDecl::input("y"),
+    ];
+    let err = index
+        .validate_port_decls("PORTS", &decls)
+        .expect_err("Unknown ports should be rejected");
+
+    assert_eq!(err.field, "PORTS");
+    assert_eq!(err.unknown, vec!["typo".to_string(), "y".to_string()]);
+    assert!(err.to_string().contains("typo"));
+}
+
+#[test]
+fn test_validate_port_decls_checks_inout_against_both_sets() {
+    let design = create_test_design();
+    let index = GraphIndex::build(&design);
+
+    let decls = [
+        PortDecl::inout("a"),
+        PortDecl::inout("y"),
+        PortDecl::inout("typo"),
+    ];
+    let err = index
+        .validate_port_decls("PORTS", &decls)
+        .expect_err("Unknown inout ports should be rejected");
+
+    assert_eq!(err.unknown, vec!["typo".to_string()]);
+}
+
+#[test]
+fn test_is_connected_skips_transparent_cells_only_when_enabled() {
+    let design = create_buffered_enable_design();
+    let index = GraphIndex::build(&design);
+
+    let input_a = index
+        .cells_of_type_indices(CellKind::Input)
+        .iter()
+        .copied()
+        .find(|&idx| index.get_cell_by_index(idx).input_name().as_deref() == Some("a"))
+        .expect("Fixture should have input `a`");
+    let reg = index.cells_of_type_indices(CellKind::Dff)[0];
+    let debug_id = |idx| u64::from(index.resolve_physical(idx).storage_key());
+
+    // The buffer sits between `a` and the register enable
+    assert!(!index.is_connected(debug_id(input_a), debug_id(reg)));
+
+    let default = Config::default();
+    assert!(!index.is_connected_skipping(
+        debug_id(input_a),
+        debug_id(reg),
+        &default.skipped_cell_kinds()
+    ));
+
+    let transparent = Config::builder().skip_transparent(true).build();
+    assert!(index.is_connected_skipping(
+        debug_id(input_a),
+        debug_id(reg),
+        &transparent.skipped_cell_kinds()
+    ));
+    assert!(
+        index
+            .fanout_set_skipping(input_a, &transparent.skipped_cell_kinds())
+            .contains(&reg)
+    );
+}
+
+/// `y = a & b`, returning the design and the AND gate output.
+fn create_and_design() -> (Design, prjunnamed_netlist::Value) {
+    let mut design = Design::new();
+    let a = design.add_input("a", 1);
+    let b = design.add_input("b", 1);
+    let y = design.add_and(a, b);
+    design.add_output("y", y.clone());
+    design.apply();
+    (design, y)
+}
+
+#[test]
+fn test_with_added_cells_matches_full_build() {
+    let (base_design, _) = create_and_design();
+    let base = GraphIndex::build(&base_design);
+
+    // Same design plus an inverter on the AND output
+    let (mut design, y) = create_and_design();
+    let not_y = design.add_cell(Cell::Not(y));
+    design.add_output("not_y", not_y);
+    design.apply();
+
+    let new_cells: Vec<_> = design
+        .iter_cells()
+        .filter(|cell_ref| {
+            base.get_cell_index_by_debug_id(cell_ref.debug_index())
+                .is_none()
+        })
+        .collect();
+    assert_eq!(new_cells.len(), 2);
+
+    let extended = GraphIndex::with_added_cells(&base, &design, &new_cells);
+    let full = GraphIndex::build(&design);
+
+    assert_eq!(extended.num_cells(), full.num_cells());
+    assert_eq!(extended.kind_histogram(), full.kind_histogram());
+    assert_eq!(
+        extended.get_output_fanin_by_name_indices().len(),
+        full.get_output_fanin_by_name_indices().len()
+    );
+
+    // Compare adjacency by debug id, since node indices may differ
+    let debug_ids = |index: &GraphIndex<'_>, cells: &HashSet<GraphNodeIdx>| {
+        let mut ids: Vec<_> = cells
+            .iter()
+            .map(|&idx| index.resolve_physical(idx).storage_key())
+            .collect();
+        ids.sort_unstable();
+        ids
+    };
+    for i in 0..full.num_cells() {
+        let full_idx = GraphNodeIdx::new(i as u32);
+        let physical = full.resolve_physical(full_idx);
+        let extended_idx = extended
+            .resolve_node(physical)
+            .expect("Every cell should be in the extended index");
+
+        assert_eq!(
+            debug_ids(&extended, extended.fanout_set(extended_idx)),
+            debug_ids(&full, full.fanout_set(full_idx))
+        );
+        assert_eq!(
+            debug_ids(&extended, extended.fanin_set(extended_idx)),
+            debug_ids(&full, full.fanin_set(full_idx))
+        );
+    }
+}
+
+#[test]
+fn test_parallel_build_matches_serial_build() {
+    let design = create_test_design();
+    let serial = GraphIndex::build(&design);
+    let parallel = GraphIndex::build_with(&design, true);
+
+    assert_eq!(parallel.num_cells(), serial.num_cells());
+    for i in 0..serial.num_cells() {
+        let node = GraphNodeIdx::new(i as u32);
+        assert_eq!(
+            parallel.fanout_with_ports(node),
+            serial.fanout_with_ports(node)
+        );
+        assert_eq!(
+            parallel.fanin_with_ports(node),
+            serial.fanin_with_ports(node)
+        );
+    }
+}
+
+/// `y_i = x_i & s` for many `i`, so `s` has a dense fan-out and each `x_i` a sparse one.
+fn create_shared_input_design() -> Design {
+    let mut design = Design::new();
+    let s = design.add_input("s", 1);
+    for i in 0..100 {
+        let x = design.add_input(format!("x{i}"), 1);
+        let y = design.add_and(x, s.clone());
+        design.add_output(format!("y{i}"), y);
+    }
+    design.apply();
+    design
+}
+
+/// Intersects neighbour sets one hash lookup at a time.
+fn naive_intersection<'s>(
+    sets: impl IntoIterator<Item = &'s HashSet<GraphNodeIdx>>,
+) -> Vec<GraphNodeIdx> {
+    let mut sets = sets.into_iter();
+    let Some(first) = sets.next() else {
+        return Vec::new();
+    };
+    let mut result = first.clone();
+    for set in sets {
+        result.retain(|node| set.contains(node));
+    }
+    let mut result: Vec<_> = result.into_iter().collect();
+    result.sort_unstable();
+    result
+}
+
+fn sorted(set: HashSet<GraphNodeIdx>) -> Vec<GraphNodeIdx> {
+    let mut nodes: Vec<_> = set.into_iter().collect();
+    nodes.sort_unstable();
+    nodes
+}
+
+#[test]
+fn test_intersect_fanout_matches_naive_intersection() {
+    for design in [create_test_design(), create_shared_input_design()] {
+        let index = GraphIndex::build(&design);
+        let nodes: Vec<_> = (0..index.num_cells())
+            .map(|i| GraphNodeIdx::new(i as u32))
+            .collect();
+
+        for &a in &nodes {
+            for &b in &nodes {
+                assert_eq!(
+                    sorted(index.intersect_fanout(&[a, b])),
+                    naive_intersection([index.fanout_set(a), index.fanout_set(b)])
+                );
+                assert_eq!(
+                    sorted(index.intersect_fanin(&[a, b])),
+                    naive_intersection([index.fanin_set(a), index.fanin_set(b)])
+                );
+            }
+        }
+        assert!(index.intersect_fanout(&[]).is_empty());
+    }
+}
+
+#[test]
+fn test_node_bitset_intersection() {
+    let nodes =
+        |ids: &[u32]| -> Vec<GraphNodeIdx> { ids.iter().copied().map(GraphNodeIdx::new).collect() };
+    let mut a = NodeBitSet::from_nodes(130, &nodes(&[0, 3, 64, 65, 129]));
+    let b = NodeBitSet::from_nodes(130, &nodes(&[3, 65, 100, 129]));
+
+    a.intersect_with(&b);
+
+    assert_eq!(a.len(), 3);
+    assert!(a.contains(GraphNodeIdx::new(65)));
+    assert!(!a.contains(GraphNodeIdx::new(0)));
+    assert_eq!(a.iter().collect::<Vec<_>>(), nodes(&[3, 65, 129]));
+}
@@ -41,6 +41,26 @@ fn test_module_config_hash_consistency() {
     assert_eq!(hasher1.finish(), hasher2.finish());
 }
 
+#[test]
+fn test_module_config_hash_ignores_param_order() {
+    let names: Vec<String> = (0..16).map(|i| format!("P{i}")).collect();
+    let forward = names
+        .iter()
+        .fold(ModuleConfig::new(), |config, name| config.with_param(name, "1"));
+    let backward = names
+        .iter()
+        .rev()
+        .fold(ModuleConfig::new(), |config, name| config.with_param(name, "1"));
+    assert_eq!(forward, backward);
+
+    let mut hasher1 = DefaultHasher::new();
+    let mut hasher2 = DefaultHasher::new();
+    forward.hash(&mut hasher1);
+    backward.hash(&mut hasher2);
+
+    assert_eq!(hasher1.finish(), hasher2.finish());
+}
+
 #[test]
 fn test_module_config_hash_difference() {
     let config1 = ModuleConfig::new().with_flatten(true);
@@ -77,3 +97,11 @@ fn test_module_config_duplicate_params() {
     assert_eq!(config.params.get("N"), Some(&"8".to_string()));
     assert_eq!(config.params.len(), 1);
 }
+
+#[test]
+fn test_module_config_timeout() {
+    assert!(ModuleConfig::default().timeout.is_none());
+
+    let config = ModuleConfig::new().with_timeout(std::time::Duration::from_secs(30));
+    assert_eq!(config.timeout, Some(std::time::Duration::from_secs(30)));
+}
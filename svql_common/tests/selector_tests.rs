@@ -0,0 +1,100 @@
+#![allow(missing_docs)]
+#![allow(clippy::missing_docs_in_private_items)]
+
+use svql_common::Selector;
+
+mod common;
+
+#[test]
+fn test_selector_common_prefix() {
+    let a = Selector::static_path(&["top", "cpu", "alu", "y"]);
+    let b = Selector::static_path(&["top", "cpu", "regfile", "q"]);
+
+    assert_eq!(a.common_prefix(b).path(), &["top", "cpu"]);
+    assert_eq!(b.common_prefix(a).path(), &["top", "cpu"]);
+    assert_eq!(a.common_prefix(a), a);
+}
+
+#[test]
+fn test_selector_common_prefix_disjoint() {
+    let a = Selector::static_path(&["top", "alu"]);
+    let b = Selector::static_path(&["other", "alu"]);
+
+    assert!(a.common_prefix(b).is_empty());
+    assert!(a.common_prefix(Selector::static_path(&[])).is_empty());
+}
+
+#[test]
+fn test_selector_strip_prefix() {
+    let path = Selector::static_path(&["top", "cpu", "alu"]);
+
+    let rest = path
+        .strip_prefix(Selector::static_path(&["top"]))
+        .expect("top is a prefix");
+    assert_eq!(rest.path(), &["cpu", "alu"]);
+
+    assert_eq!(path.strip_prefix(Selector::static_path(&[])), Some(path));
+    assert!(path.strip_prefix(path).unwrap().is_empty());
+}
+
+#[test]
+fn test_selector_strip_prefix_rejects_non_prefix() {
+    let path = Selector::static_path(&["top", "cpu"]);
+
+    assert!(path.strip_prefix(Selector::static_path(&["cpu"])).is_none());
+    assert!(
+        path.strip_prefix(Selector::static_path(&["top", "cpu", "alu"]))
+            .is_none()
+    );
+    assert!(!path.starts_with(Selector::static_path(&["top", "gpu"])));
+}
+
+#[test]
+fn test_selector_display_dotted() {
+    let path = Selector::static_path(&["top", "cpu", "alu"]);
+    assert_eq!(path.to_string(), "top.cpu.alu");
+    assert_eq!(Selector::static_path(&[]).to_string(), "");
+}
+
+#[test]
+fn test_selector_split_path_round_trip() {
+    let cases: [&[&str]; 4] = [&[], &["y"], &["and1", "y"], &["top", "cpu", "alu", "q"]];
+
+    for case in cases {
+        let original = Selector::new(case);
+        let rendered = original.to_string();
+        let segments = Selector::split_path(&rendered);
+        assert_eq!(Selector::new(&segments), original);
+    }
+}
+
+#[test]
+fn test_selector_serializes_as_dotted_string() {
+    let path = Selector::static_path(&["top", "cpu", "alu"]);
+    let json = serde_json::to_string(&path).unwrap();
+    assert_eq!(json, "\"top.cpu.alu\"");
+
+    let decoded: String = serde_json::from_str(&json).unwrap();
+    let segments = Selector::split_path(&decoded);
+    assert_eq!(Selector::new(&segments), path);
+}
+
+#[test]
+fn test_selector_segments_leaf_parent() {
+    let path = Selector::static_path(&["top", "cpu", "alu"]);
+
+    assert_eq!(
+        path.segments().collect::<Vec<_>>(),
+        vec!["top", "cpu", "alu"]
+    );
+    assert_eq!(path.leaf(), Some("alu"));
+
+    let parent = path.parent().unwrap();
+    assert_eq!(parent.path(), &["top", "cpu"]);
+    assert_eq!(parent.parent().unwrap().parent().unwrap().leaf(), None);
+
+    let empty = Selector::static_path(&[]);
+    assert_eq!(empty.segments().count(), 0);
+    assert!(empty.leaf().is_none());
+    assert!(empty.parent().is_none());
+}
@@ -0,0 +1,34 @@
+#![allow(missing_docs)]
+#![allow(clippy::missing_docs_in_private_items)]
+
+use svql_common::{SourceLine, SourceLocation};
+
+mod common;
+
+fn location(numbers: &[usize]) -> SourceLocation {
+    SourceLocation {
+        file: "top.v".into(),
+        lines: numbers
+            .iter()
+            .map(|&number| SourceLine {
+                number,
+                start_column: 1,
+                end_column: 0,
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn test_line_spans_collapse_consecutive_lines() {
+    let loc = location(&[20, 13, 12, 15, 14, 13]);
+    assert_eq!(loc.line_spans(), vec![(12, 15), (20, 20)]);
+    assert_eq!(loc.line_ranges(), "12-15, 20");
+}
+
+#[test]
+fn test_line_ranges_empty_and_single() {
+    assert_eq!(location(&[]).line_ranges(), "");
+    assert_eq!(location(&[7]).line_ranges(), "7");
+    assert_eq!(location(&[7, 9]).line_ranges(), "7, 9");
+}
@@ -0,0 +1,23 @@
+#![allow(missing_docs)]
+#![allow(clippy::missing_docs_in_private_items)]
+
+use svql_common::{YOSYS_BIN_ENV, find_yosys_binary};
+
+mod common;
+
+// Kept as the only test in this binary since it mutates the process environment.
+#[test]
+fn test_find_yosys_binary_env_override() {
+    // SAFETY: no other test in this binary reads or writes the environment.
+    unsafe { std::env::set_var(YOSYS_BIN_ENV, "/nonexistent/svql/yosys") };
+    let err = find_yosys_binary().expect_err("Missing override path should be rejected");
+    assert!(err.contains(YOSYS_BIN_ENV));
+
+    let current = std::env::current_exe().unwrap();
+    // SAFETY: see above.
+    unsafe { std::env::set_var(YOSYS_BIN_ENV, &current) };
+    assert_eq!(find_yosys_binary().unwrap(), current);
+
+    // SAFETY: see above.
+    unsafe { std::env::remove_var(YOSYS_BIN_ENV) };
+}
@@ -0,0 +1,143 @@
+#![allow(missing_docs)]
+#![allow(clippy::missing_docs_in_private_items)]
+
+use std::time::Duration;
+use svql_common::{ModuleConfig, YosysExecutionError, YosysModule, YosysTimeout};
+
+mod common;
+
+const MULTI_MODULE_FIXTURE: &str = "examples/fixtures/basic/and/verilog/and_mux_tree.v";
+
+#[test]
+fn test_import_designs_batch() {
+    let results = YosysModule::import_designs(
+        MULTI_MODULE_FIXTURE,
+        &["and_mux_tree", "and_mux"],
+        &ModuleConfig::default(),
+    )
+    .expect("Batch import should run");
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].0, "and_mux_tree");
+    assert_eq!(results[1].0, "and_mux");
+    assert!(results.iter().all(|(_, design)| design.is_ok()));
+}
+
+#[test]
+fn test_import_designs_isolates_missing_module() {
+    let results = YosysModule::import_designs(
+        MULTI_MODULE_FIXTURE,
+        &["and_mux", "does_not_exist"],
+        &ModuleConfig::default(),
+    )
+    .expect("Batch import should run");
+
+    assert_eq!(results.len(), 2);
+    assert!(results[0].1.is_ok());
+    assert!(results[1].1.is_err());
+}
+
+#[test]
+fn test_import_designs_empty() {
+    let results = YosysModule::import_designs(MULTI_MODULE_FIXTURE, &[], &ModuleConfig::default())
+        .expect("Empty batch should succeed");
+    assert!(results.is_empty());
+}
+
+#[test]
+fn test_import_design_timeout() {
+    let module = YosysModule::new(MULTI_MODULE_FIXTURE, "and_mux_tree").unwrap();
+    let config = ModuleConfig::default().with_timeout(Duration::from_nanos(1));
+
+    let err = module
+        .import_design(&config)
+        .expect_err("Import should time out");
+    assert!(err.downcast_ref::<YosysTimeout>().is_some());
+    assert!(err.to_string().contains("1ns"), "got: {err}");
+}
+
+#[test]
+fn test_import_design_from_source() {
+    let source = "module src_and(input a, input b, output y); assign y = a & b; endmodule\n";
+    let design =
+        YosysModule::import_design_from_source(source, "src_and", &ModuleConfig::default());
+    assert!(design.is_ok());
+}
+
+#[test]
+fn test_import_design_from_source_syntax_error() {
+    let source = "module broken(input a, output y); assign y = ; endmodule\n";
+    let err = YosysModule::import_design_from_source(source, "broken", &ModuleConfig::default())
+        .expect_err("Source with a syntax error should fail");
+    assert!(err.to_string().contains("<stdin>"));
+}
+
+#[test]
+fn test_import_design_cached() {
+    let cache_dir = tempfile::tempdir().unwrap();
+    let module = YosysModule::new(MULTI_MODULE_FIXTURE, "and_mux").unwrap();
+    let config = ModuleConfig::default();
+
+    let first = module.import_design_cached(&config, Some(cache_dir.path()));
+    assert!(first.is_ok());
+
+    let entries = std::fs::read_dir(cache_dir.path()).unwrap().count();
+    assert_eq!(entries, 1, "First import should populate the cache");
+
+    let second = module.import_design_cached(&config, Some(cache_dir.path()));
+    assert!(second.is_ok());
+    assert_eq!(std::fs::read_dir(cache_dir.path()).unwrap().count(), 1);
+}
+
+#[test]
+fn test_import_design_cached_ignores_load_raw() {
+    let cache_dir = tempfile::tempdir().unwrap();
+    let module = YosysModule::new(MULTI_MODULE_FIXTURE, "and_mux").unwrap();
+
+    let default = ModuleConfig::default();
+    let raw = ModuleConfig::default().with_load_raw(true);
+    let first = module.import_design_cached(&default, Some(cache_dir.path()));
+    assert!(first.is_ok());
+    let second = module.import_design_cached(&raw, Some(cache_dir.path()));
+    assert!(second.is_ok());
+
+    // Both imports run the same Yosys script, so they share one entry
+    assert_eq!(std::fs::read_dir(cache_dir.path()).unwrap().count(), 1);
+}
+
+#[test]
+fn test_import_design_syntax_error_diagnostics() {
+    let source = "module broken(input a, output y);\n  assign y = ;\nendmodule\n";
+    let err = YosysModule::import_design_from_source(source, "broken", &ModuleConfig::default())
+        .expect_err("Source with a syntax error should fail");
+
+    let err = err
+        .downcast_ref::<YosysExecutionError>()
+        .expect("Syntax errors should surface as execution errors");
+    let diag = err
+        .diagnostics
+        .first()
+        .expect("Yosys should report a location");
+    assert_eq!(diag.file, std::path::PathBuf::from("<stdin>"));
+    assert_eq!(diag.line, Some(2));
+}
+
+#[test]
+fn test_from_json_imports_without_yosys() {
+    let module =
+        YosysModule::from_json("examples/patterns/basic/and/json/and_gate.json", "and_gate")
+            .expect("JSON netlists should be accepted");
+    assert!(module.is_presynthesized());
+
+    // A missing binary proves that Yosys is never invoked
+    let design = module.import_design_yosys(
+        &ModuleConfig::default(),
+        std::path::Path::new("/nonexistent/yosys"),
+    );
+    assert!(design.is_ok());
+}
+
+#[test]
+fn test_from_json_rejects_verilog() {
+    assert!(YosysModule::from_json(MULTI_MODULE_FIXTURE, "and_mux").is_err());
+}
@@ -38,9 +38,16 @@ impl DesignContainer {
     /// The index is automatically constructed from the provided design.
     #[must_use]
     pub fn build(design: Design) -> Self {
+        Self::build_with(design, false)
+    }
+
+    /// Creates a new container, building the graph index on the rayon pool
+    /// if `parallel` is set. See [`GraphIndex::build_with`].
+    #[must_use]
+    pub fn build_with(design: Design, parallel: bool) -> Self {
         DesignContainerBuilder {
             design,
-            index_builder: |design: &Design| GraphIndex::build(design),
+            index_builder: |design: &Design| GraphIndex::build_with(design, parallel),
         }
         .build()
     }
@@ -7,14 +7,20 @@
 //! - Providing access to loaded designs through design containers
 
 use contracts::*;
+use rayon::prelude::*;
+use std::any::{Any, TypeId};
+use std::collections::VecDeque;
 use std::path::{Path, PathBuf};
-use std::sync::{Arc, RwLock};
-use tracing::{debug, info};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::SystemTime;
+use tracing::{debug, info, warn};
 
 use svql_common::*;
 use svql_common::{DesignPath, YosysModule};
 use thiserror::Error;
 
+use crate::Design;
 use crate::DriverKey;
 use crate::design_container::DesignContainer;
 
@@ -32,18 +38,241 @@ pub enum DriverError {
     DesignLoading(String),
 }
 
+/// Snapshot of the driver's design cache, as returned by [`Driver::cache_stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheStats {
+    /// Number of design requests served from the cache.
+    pub hits: u64,
+    /// Number of design requests that had to import the design.
+    pub misses: u64,
+    /// Number of designs currently cached.
+    pub entries: usize,
+    /// Total number of cells across all cached designs.
+    pub approx_cells: usize,
+}
+
+/// Default number of query results cached per design.
+pub const DEFAULT_RESULT_CAPACITY: usize = 64;
+
+/// Key of a cached query result: the producing pattern and a fingerprint.
+type ResultKey = (TypeId, u64);
+
+/// Type-erased query results computed against one cached design.
+#[derive(Default)]
+struct ResultCache(Mutex<ResultEntries>);
+
+impl std::fmt::Debug for ResultCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let len = self.0.lock().map_or(0, |entries| entries.results.len());
+        f.debug_struct("ResultCache").field("len", &len).finish()
+    }
+}
+
+/// Cached results and their insertion order.
+#[derive(Default)]
+struct ResultEntries {
+    /// Results by key.
+    results: HashMap<ResultKey, Arc<dyn Any + Send + Sync>>,
+    /// Keys of `results`, oldest first.
+    order: VecDeque<ResultKey>,
+}
+
+impl ResultEntries {
+    /// Caches a result, then drops the oldest ones beyond `capacity`.
+    fn insert(
+        &mut self,
+        key: ResultKey,
+        result: Arc<dyn Any + Send + Sync>,
+        capacity: Option<usize>,
+    ) {
+        if self.results.insert(key, result).is_none() {
+            self.order.push_back(key);
+        }
+        let Some(capacity) = capacity else {
+            return;
+        };
+        while self.results.len() > capacity {
+            let Some(oldest) = self.order.pop_front() else {
+                break;
+            };
+            self.results.remove(&oldest);
+        }
+    }
+
+    /// Drops every cached result.
+    fn clear(&mut self) {
+        self.results.clear();
+        self.order.clear();
+    }
+}
+
+/// A cached design and the registry clock value of its most recent use.
+#[derive(Debug)]
+struct RegistryEntry {
+    /// The loaded design and its graph index.
+    container: Arc<DesignContainer>,
+    /// Clock value at the most recent lookup, used for LRU eviction.
+    last_used: AtomicU64,
+    /// Modification time of the source file when it was imported.
+    modified: Option<SystemTime>,
+    /// Query results computed against this design, dropped with the entry.
+    results: ResultCache,
+}
+
+/// Design cache shared by every clone of a `Driver`.
+#[derive(Debug)]
+struct DesignRegistry {
+    /// Loaded designs by key.
+    entries: HashMap<DriverKey, RegistryEntry>,
+    /// Maximum number of cached designs; `None` means unbounded.
+    capacity: Option<usize>,
+    /// Maximum number of query results cached per design; `None` means unbounded.
+    result_capacity: Option<usize>,
+    /// Monotonic counter ordering lookups by recency.
+    clock: AtomicU64,
+    /// Number of `get_design` calls served from the cache.
+    hits: AtomicU64,
+    /// Number of `get_design` calls that imported the design.
+    misses: AtomicU64,
+}
+
+impl Default for DesignRegistry {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+            capacity: None,
+            result_capacity: Some(DEFAULT_RESULT_CAPACITY),
+            clock: AtomicU64::new(0),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+}
+
+impl DesignRegistry {
+    /// Advances the clock, returning the previous value.
+    fn tick(&self) -> u64 {
+        self.clock.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Looks up a design and marks it as most recently used.
+    ///
+    /// Returns `None` if the cached design was imported from a source file
+    /// with a different modification time than `modified`.
+    fn get(&self, key: &DriverKey, modified: Option<SystemTime>) -> Option<Arc<DesignContainer>> {
+        let entry = self.entries.get(key)?;
+        if entry.modified != modified {
+            return None;
+        }
+        entry.last_used.store(self.tick(), Ordering::Relaxed);
+        Some(entry.container.clone())
+    }
+
+    /// Caches a design, then evicts down to capacity.
+    ///
+    /// If another thread already cached the same revision of the source file,
+    /// its container is kept and returned so every caller shares one instance.
+    /// A stale entry is replaced.
+    fn insert(
+        &mut self,
+        key: DriverKey,
+        container: Arc<DesignContainer>,
+        modified: Option<SystemTime>,
+    ) -> Arc<DesignContainer> {
+        let last_used = AtomicU64::new(self.tick());
+        let container = match self.entries.get(&key) {
+            Some(entry) if entry.modified == modified => entry.container.clone(),
+            _ => {
+                let replaced = self.entries.insert(
+                    key,
+                    RegistryEntry {
+                        container: container.clone(),
+                        last_used,
+                        modified,
+                        results: ResultCache::default(),
+                    },
+                );
+                if replaced.is_some() {
+                    self.clear_results();
+                }
+                container
+            }
+        };
+        self.evict();
+        container
+    }
+
+    /// Drops a cached design, returning true if it was cached.
+    ///
+    /// The query results of every design are dropped with it; see
+    /// [`DesignRegistry::clear_results`].
+    fn remove(&mut self, key: &DriverKey) -> bool {
+        let removed = self.entries.remove(key).is_some();
+        if removed {
+            self.clear_results();
+        }
+        removed
+    }
+
+    /// Drops the cached query results of every design.
+    ///
+    /// A result cached on one design can depend on others, e.g. a haystack's
+    /// leaf tables on the needles matched against it. Since the needle is not
+    /// part of the result key, all results are dropped whenever a cached
+    /// design is replaced or removed, so none outlives a design it used.
+    fn clear_results(&self) {
+        for entry in self.entries.values() {
+            if let Ok(mut results) = entry.results.0.lock() {
+                results.clear();
+            }
+        }
+    }
+
+    /// Drops least-recently-used designs until the registry fits its capacity.
+    ///
+    /// Designs still referenced outside the registry (e.g. by a live query
+    /// context) are never evicted, so the registry may temporarily exceed its
+    /// capacity while they are in use.
+    fn evict(&mut self) {
+        let Some(capacity) = self.capacity else {
+            return;
+        };
+        while self.entries.len() > capacity {
+            let victim = self
+                .entries
+                .iter()
+                .filter(|(_, entry)| Arc::strong_count(&entry.container) == 1)
+                .min_by_key(|(_, entry)| entry.last_used.load(Ordering::Relaxed))
+                .map(|(key, _)| key.clone());
+            let Some(victim) = victim else {
+                warn!(
+                    "Design registry over capacity ({} > {}), but every design is in use",
+                    self.entries.len(),
+                    capacity
+                );
+                return;
+            };
+            debug!("Evicting least recently used design: {:?}", victim);
+            self.remove(&victim);
+        }
+    }
+}
+
 /// Central manager for loading, caching, and indexing hardware designs.
 ///
 /// The driver maintains a thread-safe registry to cache loaded designs
 /// and provides methods to load designs from files with optional Yosys processing.
+/// The registry is unbounded by default; see [`Driver::set_capacity`].
 #[derive(Debug, Clone)]
 pub struct Driver {
     /// Thread-safe registry mapping design keys to loaded design containers
-    registry: Arc<RwLock<HashMap<DriverKey, Arc<DesignContainer>>>>,
-    /// Path to the Yosys executable
-    yosys_path: PathBuf,
+    registry: Arc<RwLock<DesignRegistry>>,
+    /// Path to the Yosys executable, or `None` for an in-process driver
+    yosys_path: Option<PathBuf>,
     /// Root directory for resolving relative design paths
     root_path: PathBuf,
+    /// Policy for retrying transient Yosys failures
+    retry: RetryPolicy,
 }
 
 impl Driver {
@@ -55,15 +284,17 @@ impl Driver {
     ///
     /// # Errors
     ///
-    /// Returns `DriverError::YosysNotFound` if the yosys executable cannot be located in PATH.
+    /// Returns `DriverError::YosysNotFound` if the yosys executable cannot be located
+    /// via `SVQL_YOSYS_BIN` or in PATH.
     #[debug_requires(root.as_ref().exists())]
     pub fn new<P: AsRef<Path>>(root: P) -> Result<Self, DriverError> {
-        let yosys = which::which("yosys").map_err(|e| DriverError::YosysNotFound(e.to_string()))?;
+        let yosys = find_yosys_binary().map_err(DriverError::YosysNotFound)?;
 
         Ok(Self {
-            registry: Arc::new(RwLock::new(HashMap::new())),
-            yosys_path: yosys,
+            registry: Arc::new(RwLock::new(DesignRegistry::default())),
+            yosys_path: Some(yosys),
             root_path: std::fs::canonicalize(root.as_ref())?,
+            retry: RetryPolicy::none(),
         })
     }
 
@@ -75,7 +306,45 @@ impl Driver {
 
     /// Updates the path to the Yosys executable.
     pub fn set_yosys_path<P: AsRef<Path>>(&mut self, yosys: P) {
-        self.yosys_path = yosys.as_ref().to_path_buf();
+        self.yosys_path = Some(yosys.as_ref().to_path_buf());
+    }
+
+    /// Sets the policy for retrying transient Yosys failures (timeouts and
+    /// I/O errors) when importing designs.
+    #[must_use]
+    pub const fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Returns the path to the Yosys executable, if the driver has one.
+    #[must_use]
+    pub fn yosys_path(&self) -> Option<&Path> {
+        self.yosys_path.as_deref()
+    }
+
+    /// Creates an in-process driver that never invokes Yosys.
+    ///
+    /// Designs are imported directly by prjunnamed, so only Yosys JSON
+    /// netlists (and designs registered via [`Driver::insert_design`]) can be
+    /// loaded; this includes the needle designs of any pattern queried. Useful
+    /// for CI environments without a Yosys installation.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The root directory for resolving relative design paths
+    ///
+    /// # Errors
+    ///
+    /// Returns `DriverError::Io` if the root directory cannot be canonicalized.
+    #[debug_requires(root.as_ref().exists())]
+    pub fn new_prjunnamed<P: AsRef<Path>>(root: P) -> Result<Self, DriverError> {
+        Ok(Self {
+            registry: Arc::new(RwLock::new(DesignRegistry::default())),
+            yosys_path: None,
+            root_path: std::fs::canonicalize(root.as_ref())?,
+            retry: RetryPolicy::none(),
+        })
     }
 
     /// Creates a driver rooted at the current Cargo workspace.
@@ -89,6 +358,19 @@ impl Driver {
         Self::new(workspace)
     }
 
+    /// Creates a driver rooted at the current Cargo workspace that caches at
+    /// most `capacity` designs.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DriverError::YosysNotFound` if yosys is not in PATH or `DriverError::Io`
+    /// if the workspace directory cannot be canonicalized.
+    pub fn new_workspace_with_capacity(capacity: usize) -> Result<Self, DriverError> {
+        let driver = Self::new_workspace()?;
+        driver.set_capacity(Some(capacity));
+        Ok(driver)
+    }
+
     /// Creates a driver rooted at the workspace with an explicit Yosys path.
     ///
     /// # Arguments
@@ -128,9 +410,10 @@ impl Driver {
         }
 
         Ok(Self {
-            registry: Arc::new(RwLock::new(HashMap::new())),
-            yosys_path,
+            registry: Arc::new(RwLock::new(DesignRegistry::default())),
+            yosys_path: Some(yosys_path),
             root_path: std::fs::canonicalize(root.as_ref())?,
+            retry: RetryPolicy::none(),
         })
     }
 
@@ -144,9 +427,176 @@ impl Driver {
         }
     }
 
-    /// Checks the registry for an already-loaded design.
+    /// Checks the registry for an already-loaded design whose source file
+    /// has not changed since it was imported.
     fn check_registry(&self, key: &DriverKey) -> Option<Arc<DesignContainer>> {
-        self.registry.read().unwrap().get(key).cloned()
+        let modified = source_modified(&self.resolve_path(key.path()));
+        self.registry.read().unwrap().get(key, modified)
+    }
+
+    /// Drops a cached design so the next lookup imports it again.
+    ///
+    /// Cached query results of every design are dropped as well, since they
+    /// may have been computed with this design as a needle. Returns true if
+    /// the design was cached.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal design registry lock is poisoned.
+    pub fn invalidate(&self, key: &DriverKey) -> bool {
+        self.registry.write().unwrap().remove(key)
+    }
+
+    /// Re-imports a design from disk, replacing any cached copy.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DriverError` if the design cannot be loaded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal design registry lock is poisoned.
+    pub fn reload(
+        &self,
+        key: &DriverKey,
+        module_config: &svql_common::ModuleConfig,
+    ) -> Result<Arc<DesignContainer>, DriverError> {
+        self.invalidate(key);
+        self.get_design(key, module_config)
+    }
+
+    /// Returns a query result previously cached for the design `key`.
+    ///
+    /// Results are keyed by the producing pattern's `TypeId` and a
+    /// caller-chosen `fingerprint` (e.g. a hash of the search configuration).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal design registry lock is poisoned.
+    #[must_use]
+    pub fn cached_result(
+        &self,
+        key: &DriverKey,
+        type_id: TypeId,
+        fingerprint: u64,
+    ) -> Option<Arc<dyn Any + Send + Sync>> {
+        self.registry
+            .read()
+            .unwrap()
+            .entries
+            .get(key)?
+            .results
+            .0
+            .lock()
+            .unwrap()
+            .results
+            .get(&(type_id, fingerprint))
+            .cloned()
+    }
+
+    /// Caches a query result alongside the design `key`.
+    ///
+    /// The result lives as long as the cached design: evicting, invalidating,
+    /// reloading or clearing the design also drops its results. Since a
+    /// result may depend on other designs, replacing or dropping any cached
+    /// design drops the results of every design. Once the
+    /// design holds more results than the result capacity, the oldest are
+    /// dropped; see [`Driver::set_result_capacity`]. Returns false (and
+    /// caches nothing) if the design is not cached.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal design registry lock is poisoned.
+    pub fn cache_result(
+        &self,
+        key: &DriverKey,
+        type_id: TypeId,
+        fingerprint: u64,
+        result: Arc<dyn Any + Send + Sync>,
+    ) -> bool {
+        let registry = self.registry.read().unwrap();
+        let Some(entry) = registry.entries.get(key) else {
+            return false;
+        };
+        entry.results.0.lock().unwrap().insert(
+            (type_id, fingerprint),
+            result,
+            registry.result_capacity,
+        );
+        true
+    }
+
+    /// Limits each design to `capacity` cached query results, dropping the
+    /// oldest ones once it is exceeded. `None` removes the limit.
+    ///
+    /// The limit defaults to [`DEFAULT_RESULT_CAPACITY`] and applies to
+    /// results cached from now on.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal design registry lock is poisoned.
+    pub fn set_result_capacity(&self, capacity: Option<usize>) {
+        self.registry.write().unwrap().result_capacity = capacity;
+    }
+
+    /// Limits the registry to `capacity` designs, evicting the least recently
+    /// used ones once it is exceeded. `None` removes the limit.
+    ///
+    /// Evicting a design drops its `DesignContainer` and graph index. A design
+    /// whose container is still held elsewhere (e.g. by a live query context)
+    /// is skipped until it is released, so matches never outlive their design.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal design registry lock is poisoned.
+    pub fn set_capacity(&self, capacity: Option<usize>) {
+        let mut registry = self.registry.write().unwrap();
+        registry.capacity = capacity;
+        registry.evict();
+    }
+
+    /// Returns hit/miss counters and the current size of the design cache.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal design registry lock is poisoned.
+    #[must_use]
+    pub fn cache_stats(&self) -> CacheStats {
+        let registry = self.registry.read().unwrap();
+        CacheStats {
+            hits: registry.hits.load(Ordering::Relaxed),
+            misses: registry.misses.load(Ordering::Relaxed),
+            entries: registry.entries.len(),
+            approx_cells: registry
+                .entries
+                .values()
+                .map(|entry| entry.container.index().num_cells())
+                .sum(),
+        }
+    }
+
+    /// Drops every cached design and resets the hit/miss counters.
+    ///
+    /// The configured capacity is kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal design registry lock is poisoned.
+    pub fn clear_cache(&self) {
+        let mut registry = self.registry.write().unwrap();
+        registry.entries.clear();
+        registry.hits.store(0, Ordering::Relaxed);
+        registry.misses.store(0, Ordering::Relaxed);
+    }
+
+    /// Returns the configured registry capacity, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal design registry lock is poisoned.
+    #[must_use]
+    pub fn capacity(&self) -> Option<usize> {
+        self.registry.read().unwrap().capacity
     }
 
     /// Preloads a design into the registry without waiting for query execution.
@@ -168,10 +618,31 @@ impl Driver {
         Ok(())
     }
 
+    /// Preloads a design like [`Driver::preload_design`], building its graph
+    /// index on the rayon pool if `parallel` is set.
+    ///
+    /// Query entry points pass `Config::parallel` here.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DriverError` if the design cannot be loaded.
+    pub fn preload_design_with(
+        &self,
+        key: &DriverKey,
+        module_config: &svql_common::ModuleConfig,
+        parallel: bool,
+    ) -> Result<(), DriverError> {
+        Self::get_design_with(self, key, module_config, parallel)?;
+        Ok(())
+    }
+
     /// Loads or retrieves a cached design.
     ///
     /// If the design is not already in the registry, it is loaded from disk,
-    /// processed via Yosys (unless raw loading is enabled), and cached.
+    /// processed via Yosys (unless raw loading is enabled or the key refers to
+    /// pre-synthesized JSON), and cached. A
+    /// cached design is re-imported if its source file's modification time
+    /// has changed since it was loaded.
     ///
     /// # Arguments
     ///
@@ -186,34 +657,76 @@ impl Driver {
     /// # Panics
     ///
     /// Panics if the internal design registry lock is poisoned.
-    #[debug_ensures(ret.is_ok() -> self.check_registry(key).is_some())]
     pub fn get_design(
         &self,
         key: &DriverKey,
         module_config: &svql_common::ModuleConfig,
+    ) -> Result<Arc<DesignContainer>, DriverError> {
+        self.get_design_with(key, module_config, false)
+    }
+
+    /// Loads or retrieves a cached design like [`Driver::get_design`],
+    /// building the graph index of a newly imported design on the rayon pool
+    /// if `parallel` is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DriverError` if the design file is invalid, cannot be read,
+    /// or if Yosys processing fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal design registry lock is poisoned.
+    pub fn get_design_with(
+        &self,
+        key: &DriverKey,
+        module_config: &svql_common::ModuleConfig,
+        parallel: bool,
     ) -> Result<Arc<DesignContainer>, DriverError> {
         if let Some(design) = self.check_registry(key) {
             debug!("Design already loaded: {:?}", key);
+            self.registry
+                .read()
+                .unwrap()
+                .hits
+                .fetch_add(1, Ordering::Relaxed);
             return Ok(design);
         }
+        self.registry
+            .read()
+            .unwrap()
+            .misses
+            .fetch_add(1, Ordering::Relaxed);
 
         let absolute_path = self.resolve_path(key.path());
-        let yosys_module = YosysModule::new(&absolute_path, key.module_name())
-            .map_err(|e| DriverError::DesignLoading(e.to_string()))?;
+        // Record the modification time before importing so that an edit made
+        // while Yosys runs triggers another reload on the next lookup.
+        let modified = source_modified(&absolute_path);
+        let yosys_module = if key.is_presynthesized() {
+            YosysModule::from_json(&absolute_path, key.module_name())
+        } else {
+            YosysModule::new(&absolute_path, key.module_name())
+        }
+        .map_err(|e| DriverError::DesignLoading(e.to_string()))?;
 
-        let design = if module_config.load_raw {
-            if !matches!(yosys_module.design_path(), DesignPath::Json(_)) {
-                return Err(DriverError::DesignLoading(
-                    "Raw loading is only supported for JSON netlists.".to_string(),
-                ));
+        let design = match &self.yosys_path {
+            Some(yosys_path) if !module_config.load_raw && !key.is_presynthesized() => self
+                .retry
+                .run(|| yosys_module.import_design_yosys(module_config, yosys_path))
+                .map_err(|e| DriverError::DesignLoading(e.to_string()))?,
+            _ => {
+                if !matches!(yosys_module.design_path(), DesignPath::Json(_)) {
+                    let reason = if module_config.load_raw {
+                        "Raw loading is only supported for JSON netlists."
+                    } else {
+                        "Drivers without Yosys can only load JSON netlists."
+                    };
+                    return Err(DriverError::DesignLoading(reason.to_string()));
+                }
+                yosys_module
+                    .import_design_raw()
+                    .map_err(|e| DriverError::DesignLoading(e.to_string()))?
             }
-            yosys_module
-                .import_design_raw()
-                .map_err(|e| DriverError::DesignLoading(e.to_string()))?
-        } else {
-            yosys_module
-                .import_design(module_config)
-                .map_err(|e| DriverError::DesignLoading(e.to_string()))?
         };
 
         info!(
@@ -221,14 +734,142 @@ impl Driver {
             key.path(),
             key.module_name()
         );
-        let design_container = Arc::new(DesignContainer::build(design));
+        let design_container = Arc::new(DesignContainer::build_with(design, parallel));
+
+        // Another thread may have loaded the same key meanwhile; the registry
+        // keeps the first container so every caller shares one instance.
+        let design_container =
+            self.registry
+                .write()
+                .unwrap()
+                .insert(key.clone(), design_container, modified);
+
+        Ok(design_container)
+    }
 
+    /// Loads or retrieves several designs, importing uncached ones in parallel.
+    ///
+    /// Keys that are already cached or repeated within `requests` are only
+    /// imported once. Results are returned in the same order as `requests`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first `DriverError` encountered while loading a design.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal design registry lock is poisoned.
+    pub fn load_designs(
+        &self,
+        requests: &[(DriverKey, svql_common::ModuleConfig)],
+    ) -> Result<Vec<Arc<DesignContainer>>, DriverError> {
+        // Each request counts once: as a miss if it is imported here, as a
+        // hit if it is already cached or repeats an earlier request.
+        let mut pending: Vec<&(DriverKey, svql_common::ModuleConfig)> = Vec::new();
+        let mut hits = 0;
+        for request in requests {
+            let queued = pending.iter().any(|(key, _)| *key == request.0);
+            if queued || self.check_registry(&request.0).is_some() {
+                hits += 1;
+            } else {
+                pending.push(request);
+            }
+        }
         self.registry
-            .write()
+            .read()
             .unwrap()
-            .insert(key.clone(), design_container.clone());
+            .hits
+            .fetch_add(hits, Ordering::Relaxed);
 
-        Ok(design_container)
+        debug!("Loading {} uncached designs in parallel", pending.len());
+        pending
+            .par_iter()
+            .try_for_each(|(key, module_config)| self.get_design(key, module_config).map(|_| ()))?;
+
+        // A design evicted meanwhile is imported again through `get_design`.
+        requests
+            .iter()
+            .map(|(key, module_config)| {
+                self.check_registry(key)
+                    .map_or_else(|| self.get_design(key, module_config), Ok)
+            })
+            .collect()
+    }
+
+    /// Registers a pre-built design under `key`, building its graph index.
+    ///
+    /// The design is then served by [`Driver::get_design`] like any file-backed
+    /// design, so queries can run against designs constructed in memory. The
+    /// key's path should not name an existing file, otherwise the design is
+    /// treated as stale and re-imported from disk. In-memory designs cannot be
+    /// reloaded once evicted, so keep them referenced when a capacity is set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal design registry lock is poisoned.
+    pub fn insert_design(&self, key: DriverKey, design: Design) -> DriverKey {
+        let modified = source_modified(&self.resolve_path(key.path()));
+        let design_container = Arc::new(DesignContainer::build(design));
+
+        info!("Registering in-memory design: {:?}", key);
+        let mut registry = self.registry.write().unwrap();
+        registry.remove(&key);
+        registry.insert(key.clone(), design_container, modified);
+        key
+    }
+
+    /// Imports every module of a Yosys JSON netlist and caches each under a
+    /// key made from `path` and the module name.
+    ///
+    /// Modules are imported raw (without Yosys processing). Returns the keys
+    /// sorted by module name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DriverError::DesignLoading` if the file is not a JSON netlist
+    /// or cannot be imported.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal design registry lock is poisoned.
+    pub fn load_all_modules<P: AsRef<Path>>(&self, path: P) -> Result<Vec<DriverKey>, DriverError> {
+        let path = path.as_ref();
+        let absolute_path = self.resolve_path(path);
+        if !matches!(
+            DesignPath::new(absolute_path.clone()),
+            Ok(DesignPath::Json(_))
+        ) {
+            return Err(DriverError::DesignLoading(
+                "Loading all modules is only supported for JSON netlists.".to_string(),
+            ));
+        }
+
+        let modified = source_modified(&absolute_path);
+        let designs = YosysModule::import_all_modules_raw(&absolute_path)
+            .map_err(|e| DriverError::DesignLoading(e.to_string()))?;
+
+        let containers: Vec<(DriverKey, Arc<DesignContainer>)> = designs
+            .into_iter()
+            .map(|(module_name, design)| {
+                (
+                    DriverKey::new(path, module_name),
+                    Arc::new(DesignContainer::build(design)),
+                )
+            })
+            .collect();
+
+        let mut keys = Vec::with_capacity(containers.len());
+        let mut registry = self.registry.write().unwrap();
+        for (key, container) in containers {
+            registry.entries.remove(&key);
+            registry.insert(key.clone(), container, modified);
+            keys.push(key);
+        }
+        drop(registry);
+
+        info!("Loaded {} modules from {:?}", keys.len(), path);
+        keys.sort_by(|a, b| a.module_name().cmp(b.module_name()));
+        Ok(keys)
     }
 
     /// Returns a snapshot of all currently loaded designs.
@@ -238,7 +879,13 @@ impl Driver {
     /// Panics if the internal design registry lock is poisoned.
     #[must_use]
     pub fn get_all_designs(&self) -> HashMap<DriverKey, Arc<DesignContainer>> {
-        self.registry.read().unwrap().clone()
+        self.registry
+            .read()
+            .unwrap()
+            .entries
+            .iter()
+            .map(|(key, entry)| (key.clone(), entry.container.clone()))
+            .collect()
     }
 
     /// Retrieves source location logic using the stable PhysicalCellId.
@@ -249,7 +896,7 @@ impl Driver {
     ) -> Option<SourceLocation> {
         let container = {
             let registry = self.registry.read().ok()?;
-            registry.get(key)?.clone()
+            registry.entries.get(key)?.container.clone()
         };
 
         // Translate physical back to local node to reach the cell wrapper
@@ -257,3 +904,10 @@ impl Driver {
         container.index().get_cell_by_index(node).get_source()
     }
 }
+
+/// Returns the modification time of a design source file, if available.
+fn source_modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
@@ -12,10 +12,12 @@ use std::path::{Path, PathBuf};
 /// Two designs are considered the same if they have the same path and module name.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DriverKey {
-    /// Filesystem path to the design file (Verilog, RTLIL, or JSON)
+    /// Filesystem path to the design file (Verilog, RTLIL, JSON, BLIF, or EDIF)
     pub path: PathBuf,
     /// Name of the top-level module in the design
     pub module_name: String,
+    /// Whether `path` is pre-synthesized JSON to import without running Yosys
+    pub presynthesized: bool,
 }
 
 impl DriverKey {
@@ -34,6 +36,28 @@ impl DriverKey {
         Self {
             path: path.into(),
             module_name: module_name.into(),
+            presynthesized: false,
+        }
+    }
+
+    /// Creates a key for a pre-synthesized Yosys JSON netlist.
+    ///
+    /// The driver imports such designs directly, skipping Yosys and the
+    /// processing steps of the module config.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - File path to the JSON netlist
+    /// * `module_name` - Name of the top-level module
+    #[debug_requires(!module_name.as_ref().is_empty())]
+    pub fn from_json<P, S>(path: P, module_name: S) -> Self
+    where
+        P: Into<PathBuf>,
+        S: AsRef<str> + Into<String>,
+    {
+        Self {
+            presynthesized: true,
+            ..Self::new(path, module_name)
         }
     }
 
@@ -48,4 +72,10 @@ impl DriverKey {
     pub fn module_name(&self) -> &str {
         &self.module_name
     }
+
+    /// Returns true if the key refers to a pre-synthesized JSON netlist.
+    #[must_use]
+    pub const fn is_presynthesized(&self) -> bool {
+        self.presynthesized
+    }
 }
@@ -12,7 +12,7 @@ pub mod design_container;
 pub mod driver;
 pub mod key;
 
-pub use driver::{Driver, DriverError};
+pub use driver::{CacheStats, DEFAULT_RESULT_CAPACITY, Driver, DriverError};
 pub use key::DriverKey;
 
 pub use prjunnamed_netlist::Design;
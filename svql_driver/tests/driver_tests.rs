@@ -3,8 +3,9 @@
 
 use std::fs;
 use std::path::PathBuf;
+use std::sync::Arc;
 use svql_common::ModuleConfig;
-use svql_driver::{Driver, DriverKey};
+use svql_driver::{CacheStats, Design, Driver, DriverKey};
 use tempfile::tempdir;
 
 #[test]
@@ -36,3 +37,247 @@ fn test_driver_preload_invalid_path() {
     let result = driver.preload_design(&key, &config);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_driver_load_designs_deduplicates_keys() {
+    let driver = Driver::new_workspace().unwrap();
+    let and_key = DriverKey::new("examples/patterns/basic/and/verilog/and_gate.v", "and_gate");
+    let tree_key = DriverKey::new(
+        "examples/fixtures/basic/and/verilog/small_and_tree.v",
+        "small_and_tree",
+    );
+    let config = ModuleConfig::default();
+
+    let designs = driver
+        .load_designs(&[
+            (and_key.clone(), config.clone()),
+            (tree_key, config.clone()),
+            (and_key, config),
+        ])
+        .expect("Failed to load designs");
+
+    assert_eq!(designs.len(), 3);
+    assert!(std::sync::Arc::ptr_eq(&designs[0], &designs[2]));
+    assert!(!std::sync::Arc::ptr_eq(&designs[0], &designs[1]));
+    assert_eq!(driver.get_all_designs().len(), 2);
+
+    let stats = driver.cache_stats();
+    assert_eq!(stats.misses, 2);
+    assert_eq!(stats.hits, 1);
+}
+
+#[test]
+fn test_driver_capacity_evicts_least_recently_used() {
+    let driver = Driver::new_workspace_with_capacity(1).unwrap();
+    let and_key = DriverKey::new("examples/patterns/basic/and/verilog/and_gate.v", "and_gate");
+    let tree_key = DriverKey::new(
+        "examples/fixtures/basic/and/verilog/small_and_tree.v",
+        "small_and_tree",
+    );
+    let config = ModuleConfig::default();
+
+    driver.preload_design(&and_key, &config).unwrap();
+    driver.preload_design(&tree_key, &config).unwrap();
+
+    let designs = driver.get_all_designs();
+    assert_eq!(designs.len(), 1);
+    assert!(designs.contains_key(&tree_key));
+}
+
+#[test]
+fn test_driver_capacity_keeps_designs_in_use() {
+    let driver = Driver::new_workspace_with_capacity(1).unwrap();
+    let and_key = DriverKey::new("examples/patterns/basic/and/verilog/and_gate.v", "and_gate");
+    let tree_key = DriverKey::new(
+        "examples/fixtures/basic/and/verilog/small_and_tree.v",
+        "small_and_tree",
+    );
+    let config = ModuleConfig::default();
+
+    let _held = driver.get_design(&and_key, &config).unwrap();
+    driver.preload_design(&tree_key, &config).unwrap();
+
+    assert!(driver.get_all_designs().contains_key(&and_key));
+}
+
+#[test]
+fn test_driver_reloads_modified_design() {
+    let tmp = tempdir().unwrap();
+    let design_path = tmp.path().join("top.v");
+    fs::write(
+        &design_path,
+        "module top(input a, input b, output y); assign y = a & b; endmodule\n",
+    )
+    .unwrap();
+
+    let driver = Driver::new(tmp.path()).unwrap();
+    let key = DriverKey::new("top.v", "top");
+    let config = ModuleConfig::default();
+
+    let first = driver.get_design(&key, &config).unwrap();
+    let cached = driver.get_design(&key, &config).unwrap();
+    assert!(std::sync::Arc::ptr_eq(&first, &cached));
+
+    fs::write(
+        &design_path,
+        "module top(input a, input b, output y); assign y = a | b; endmodule\n",
+    )
+    .unwrap();
+    // Bump the timestamp explicitly for filesystems with coarse mtimes.
+    fs::File::options()
+        .write(true)
+        .open(&design_path)
+        .unwrap()
+        .set_modified(std::time::SystemTime::now() + std::time::Duration::from_secs(10))
+        .unwrap();
+
+    let reloaded = driver.get_design(&key, &config).unwrap();
+    assert!(!std::sync::Arc::ptr_eq(&first, &reloaded));
+    assert_eq!(driver.get_all_designs().len(), 1);
+
+    let explicit = driver.reload(&key, &config).unwrap();
+    assert!(!std::sync::Arc::ptr_eq(&reloaded, &explicit));
+}
+
+#[test]
+fn test_driver_cache_stats_count_hits_and_misses() {
+    let driver = Driver::new_workspace().unwrap();
+    let key = DriverKey::new("examples/patterns/basic/and/verilog/and_gate.v", "and_gate");
+    let config = ModuleConfig::default();
+
+    driver.get_design(&key, &config).unwrap();
+    let stats = driver.cache_stats();
+    assert_eq!((stats.hits, stats.misses, stats.entries), (0, 1, 1));
+    assert!(stats.approx_cells > 0);
+
+    driver.get_design(&key, &config).unwrap();
+    assert_eq!(driver.cache_stats().hits, 1);
+
+    driver.clear_cache();
+    assert_eq!(driver.cache_stats(), CacheStats::default());
+}
+
+#[test]
+fn test_driver_insert_in_memory_design() {
+    let tmp = tempdir().unwrap();
+    let driver = Driver::new(tmp.path()).unwrap();
+
+    let mut design = Design::new();
+    let a = design.add_input("a", 1);
+    let b = design.add_input("b", 1);
+    let y = design.add_and(a, b);
+    design.add_output("y", y);
+    design.apply();
+
+    let key = driver.insert_design(DriverKey::new("in_memory", "top"), design);
+    let container = driver
+        .get_design(&key, &ModuleConfig::default())
+        .expect("In-memory design should be served from the registry");
+
+    assert!(container.index().num_cells() > 0);
+    assert_eq!(driver.cache_stats().misses, 0);
+}
+
+#[test]
+fn test_driver_cached_results_follow_design_lifetime() {
+    let tmp = tempdir().unwrap();
+    let driver = Driver::new(tmp.path()).unwrap();
+    let type_id = std::any::TypeId::of::<u32>();
+
+    let orphan = DriverKey::new("not_loaded", "top");
+    assert!(!driver.cache_result(&orphan, type_id, 0, Arc::new(1_u32)));
+
+    let passthrough = || {
+        let mut design = Design::new();
+        let a = design.add_input("a", 1);
+        design.add_output("y", a);
+        design.apply();
+        design
+    };
+    let key = driver.insert_design(DriverKey::new("in_memory", "top"), passthrough());
+    let other = driver.insert_design(DriverKey::new("in_memory", "other"), passthrough());
+
+    assert!(driver.cache_result(&key, type_id, 7, Arc::new(42_u32)));
+    let cached = driver.cached_result(&key, type_id, 7).unwrap();
+    assert_eq!(cached.downcast_ref::<u32>(), Some(&42));
+    assert!(driver.cached_result(&key, type_id, 8).is_none());
+
+    // Results on other designs may have used this one as a needle
+    assert!(driver.cache_result(&other, type_id, 7, Arc::new(1_u32)));
+    driver.invalidate(&key);
+    assert!(driver.cached_result(&key, type_id, 7).is_none());
+    assert!(driver.cached_result(&other, type_id, 7).is_none());
+}
+
+#[test]
+fn test_driver_result_capacity_drops_oldest_results() {
+    let tmp = tempdir().unwrap();
+    let driver = Driver::new(tmp.path()).unwrap();
+    let type_id = std::any::TypeId::of::<u32>();
+
+    let mut design = Design::new();
+    let a = design.add_input("a", 1);
+    design.add_output("y", a);
+    design.apply();
+    let key = driver.insert_design(DriverKey::new("in_memory", "top"), design);
+
+    driver.set_result_capacity(Some(2));
+    for fingerprint in 0..3 {
+        assert!(driver.cache_result(&key, type_id, fingerprint, Arc::new(1_u32)));
+    }
+
+    assert!(driver.cached_result(&key, type_id, 0).is_none());
+    assert!(driver.cached_result(&key, type_id, 1).is_some());
+    assert!(driver.cached_result(&key, type_id, 2).is_some());
+}
+
+#[test]
+fn test_driver_load_all_modules_from_json() {
+    let driver = Driver::new_workspace().unwrap();
+    let path = "examples/fixtures/basic/and/json/and_multi_module.json";
+
+    let keys = driver
+        .load_all_modules(path)
+        .expect("Failed to load multi-module netlist");
+
+    let names: Vec<&str> = keys.iter().map(DriverKey::module_name).collect();
+    assert_eq!(names, vec!["and_gate", "and_tree"]);
+
+    let config = ModuleConfig {
+        load_raw: true,
+        ..ModuleConfig::default()
+    };
+    for key in &keys {
+        driver.get_design(key, &config).unwrap();
+    }
+    assert_eq!(driver.cache_stats().hits, 2);
+}
+
+#[test]
+fn test_prjunnamed_driver_loads_json_without_yosys() {
+    let workspace = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+    let driver = Driver::new_prjunnamed(&workspace).unwrap();
+    assert!(driver.yosys_path().is_none());
+
+    let config = ModuleConfig::default();
+    let json_key = DriverKey::new("examples/patterns/basic/and/json/and_gate.json", "and_gate");
+    assert!(driver.get_design(&json_key, &config).is_ok());
+
+    let verilog_key = DriverKey::new("examples/patterns/basic/and/verilog/and_gate.v", "and_gate");
+    assert!(driver.get_design(&verilog_key, &config).is_err());
+}
+
+#[test]
+fn test_driver_imports_presynthesized_json_directly() {
+    let mut driver = Driver::new_workspace().unwrap();
+    driver.set_yosys_path("/nonexistent/yosys");
+
+    let config = ModuleConfig::default();
+    let json_key =
+        DriverKey::from_json("examples/patterns/basic/and/json/and_gate.json", "and_gate");
+    assert!(json_key.is_presynthesized());
+    assert!(driver.get_design(&json_key, &config).is_ok());
+
+    let regular_key = DriverKey::new("examples/patterns/basic/and/json/and_gate.json", "and_gate");
+    assert!(driver.get_design(&regular_key, &config).is_err());
+}
@@ -25,6 +25,8 @@ struct Connection {
 enum ConnectionKind {
     /// Match if any element in a set satisfies the connection.
     AnyInSet,
+    /// Match if the source reaches the target through combinational logic.
+    FaninCone,
 }
 
 /// A group of connections where at least one must be satisfied.
@@ -34,7 +36,7 @@ struct OrGroup {
 }
 
 /// A custom filter expression for validation.
-struct Filter {
+pub(crate) struct Filter {
     /// The filter expression to evaluate.
     expr: syn::Expr,
 }
@@ -57,6 +59,18 @@ struct AliasField {
     target: PathSelector,
 }
 
+/// Represents an absent (negated) sub-pattern field in a composite pattern.
+struct AbsentField {
+    /// The field name.
+    name: syn::Ident,
+    /// The field type (`Absent<T>`).
+    ty: syn::Type,
+    /// The output port of the absent pattern.
+    port: String,
+    /// The protected path the absent pattern must not drive.
+    protects: PathSelector,
+}
+
 /// Implementation of the `Composite` derive macro.
 pub fn composite_impl(item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as DeriveInput);
@@ -76,6 +90,7 @@ pub fn composite_impl(item: TokenStream) -> TokenStream {
     let filters = parse_filters(&input);
     let submodules = parse_submodule_fields(fields);
     let aliases = parse_alias_fields(fields);
+    let absents = parse_absent_fields(fields);
 
     let name = &input.ident;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
@@ -102,6 +117,16 @@ pub fn composite_impl(item: TokenStream) -> TokenStream {
         })
         .collect();
 
+    let absent_entries: Vec<_> = absents
+        .iter()
+        .map(|a| {
+            let ty = &a.ty;
+            let port = &a.port;
+            let protects = a.protects.to_selector_tokens();
+            quote! { <#ty>::constraint(#port, #protects) }
+        })
+        .collect();
+
     let connection_groups: Vec<_> = or_groups
         .iter()
         .map(|group| {
@@ -115,6 +140,9 @@ pub fn composite_impl(item: TokenStream) -> TokenStream {
                         Some(ConnectionKind::AnyInSet) => quote! {
                             svql_query::traits::composite::Connection::any_in_set(#from, #to)
                         },
+                        Some(ConnectionKind::FaninCone) => quote! {
+                            svql_query::traits::composite::Connection::fanin_cone(#from, #to)
+                        },
                         None => quote! {
                             svql_query::traits::composite::Connection::new(#from, #to)
                         },
@@ -125,12 +153,33 @@ pub fn composite_impl(item: TokenStream) -> TokenStream {
         })
         .collect();
 
+    let connections_value = match parse_manual_connections(&input) {
+        Some(expr) => {
+            if !or_groups.is_empty() {
+                abort!(
+                    expr,
+                    "manual_connections cannot be combined with connection attributes"
+                );
+            }
+            quote! { #expr }
+        }
+        None => quote! {
+            svql_query::traits::composite::Connections {
+                connections: &[#(#connection_groups),*],
+            }
+        },
+    };
+
     let dep_entries: Vec<_> = submodules
         .iter()
         .map(|s| {
             let ty = &s.ty;
             quote! { <#ty as svql_query::traits::Pattern>::EXEC_INFO }
         })
+        .chain(absents.iter().map(|a| {
+            let ty = &a.ty;
+            quote! { <#ty>::EXEC_INFO }
+        }))
         .collect();
 
     let submodule_rehydrate: Vec<_> = submodules
@@ -163,6 +212,7 @@ pub fn composite_impl(item: TokenStream) -> TokenStream {
 
     let submodule_names: Vec<_> = submodules.iter().map(|s| &s.name).collect();
     let alias_names: Vec<_> = aliases.iter().map(|a| &a.name).collect();
+    let absent_names: Vec<_> = absents.iter().map(|a| &a.name).collect();
 
     let preload_calls: Vec<_> = submodules
         .iter()
@@ -172,6 +222,48 @@ pub fn composite_impl(item: TokenStream) -> TokenStream {
                 <#ty as svql_query::traits::Pattern>::preload_driver(driver, design_key, config)?;
             }
         })
+        .chain(absents.iter().map(|a| {
+            let ty = &a.ty;
+            quote! {
+                <#ty>::preload_driver(driver, design_key, config)?;
+            }
+        }))
+        .collect();
+
+    let submodule_report_nodes: Vec<_> = submodules
+        .iter()
+        .map(|s| {
+            let field_name = &s.name;
+            let field_str = field_name.to_string();
+            let ty = &s.ty;
+            quote! {
+                svql_query::traits::display::ReportNode {
+                    name: #field_str.to_string(),
+                    type_name: std::any::type_name::<#ty>().to_string(),
+                    details: Some(format!("{:?}", self.#field_name)),
+                    source_loc: None,
+                    children: vec![],
+                }
+            }
+        })
+        .collect();
+
+    let alias_report_nodes: Vec<_> = aliases
+        .iter()
+        .map(|a| {
+            let field_name = &a.name;
+            let field_str = field_name.to_string();
+            let direction = format!("{:?}", a.direction);
+            quote! {
+                svql_query::traits::display::ReportNode {
+                    name: #field_str.to_string(),
+                    type_name: #direction.to_string(),
+                    details: Some(self.#field_name.to_string()),
+                    source_loc: None,
+                    children: vec![],
+                }
+            }
+        })
         .collect();
 
     let validate_custom_impl = if !filters.is_empty() {
@@ -191,14 +283,16 @@ pub fn composite_impl(item: TokenStream) -> TokenStream {
             ];
 
             const CONNECTIONS: svql_query::traits::composite::Connections =
-                svql_query::traits::composite::Connections {
-                    connections: &[#(#connection_groups),*],
-                };
+                #connections_value;
 
             const DEPENDANCIES: &'static [&'static svql_query::session::ExecInfo] = &[
                 #(#dep_entries),*
             ];
 
+            const ABSENT: &'static [svql_query::traits::composite::Absence] = &[
+                #(#absent_entries),*
+            ];
+
             fn composite_schema() -> &'static svql_query::session::PatternSchema {
                 static SCHEMA: std::sync::OnceLock<svql_query::session::PatternSchema> =
                     std::sync::OnceLock::new();
@@ -224,7 +318,8 @@ pub fn composite_impl(item: TokenStream) -> TokenStream {
 
                 Some(Self {
                     #(#submodule_names,)*
-                    #(#alias_names),*
+                    #(#alias_names,)*
+                    #(#absent_names: ::core::default::Default::default(),)*
                 })
             }
 
@@ -244,6 +339,23 @@ pub fn composite_impl(item: TokenStream) -> TokenStream {
         impl #impl_generics svql_query::traits::Component for #name #ty_generics #where_clause {
             type Kind = svql_query::traits::kind::Composite;
         }
+
+        impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let type_name = std::any::type_name::<Self>();
+                let node = svql_query::traits::display::ReportNode {
+                    name: type_name.rsplit("::").next().unwrap_or(type_name).to_string(),
+                    type_name: type_name.to_string(),
+                    details: None,
+                    source_loc: None,
+                    children: vec![
+                        #(#submodule_report_nodes,)*
+                        #(#alias_report_nodes,)*
+                    ],
+                };
+                f.write_str(&node.render())
+            }
+        }
     };
 
     TokenStream::from(expanded)
@@ -279,6 +391,84 @@ fn parse_or_groups(input: &DeriveInput) -> Vec<OrGroup> {
         }
     }
 
+    groups.extend(parse_connect_groups(input));
+
+    groups
+}
+
+/// Parses the `#[manual_connections(expr)]` attribute, if present.
+///
+/// The expression must be a constant `Connections` value and replaces the
+/// connection set derived from the other connection attributes.
+fn parse_manual_connections(input: &DeriveInput) -> Option<syn::Expr> {
+    find_attr(&input.attrs, "manual_connections").map(|attr| {
+        attr.parse_args::<syn::Expr>().unwrap_or_else(|e| {
+            abort!(
+                attr,
+                "manual_connections expects a constant Connections expression: {}",
+                e
+            )
+        })
+    })
+}
+
+/// Parses `connect` attributes with dotted paths.
+///
+/// Ungrouped attributes each form their own group; attributes sharing a
+/// `group` id are collected into a single OR group, in order of first use.
+fn parse_connect_groups(input: &DeriveInput) -> Vec<OrGroup> {
+    let mut groups = Vec::new();
+    let mut named: Vec<(String, OrGroup)> = Vec::new();
+
+    for attr in find_all_attrs(&input.attrs, "connect") {
+        let mut from = None;
+        let mut to = None;
+        let mut group = None;
+
+        if let Err(e) = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("from") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                from = Some(PathSelector::from_dotted(&value)?);
+            } else if meta.path.is_ident("to") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                to = Some(PathSelector::from_dotted(&value)?);
+            } else if meta.path.is_ident("group") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                group = Some(value.value());
+            } else {
+                return Err(meta.error("Expected 'from', 'to', or 'group'"));
+            }
+            Ok(())
+        }) {
+            abort!(e.span(), "{}", e);
+        }
+
+        let (Some(from), Some(to)) = (from, to) else {
+            abort!(attr, "connect attribute requires both 'from' and 'to'");
+        };
+        let conn = Connection {
+            from,
+            to,
+            kind: None,
+        };
+
+        match group {
+            Some(id) => match named.iter_mut().find(|(name, _)| *name == id) {
+                Some((_, existing)) => existing.connections.push(conn),
+                None => named.push((
+                    id,
+                    OrGroup {
+                        connections: vec![conn],
+                    },
+                )),
+            },
+            None => groups.push(OrGroup {
+                connections: vec![conn],
+            }),
+        }
+    }
+
+    groups.extend(named.into_iter().map(|(_, group)| group));
     groups
 }
 
@@ -301,8 +491,14 @@ fn parse_single_connection(attr: &syn::Attribute) -> Option<Connection> {
                 let kind_str = lit_str.value();
                 if kind_str == "any" {
                     kind = Some(ConnectionKind::AnyInSet);
+                } else if kind_str == "cone" {
+                    kind = Some(ConnectionKind::FaninCone);
                 } else {
-                    abort!(attr, "connection kind must be 'any', got '{}'", kind_str);
+                    abort!(
+                        attr,
+                        "connection kind must be 'any' or 'cone', got '{}'",
+                        kind_str
+                    );
                 }
             }
         } else {
@@ -446,7 +642,7 @@ fn parse_or_group(attr: &syn::Attribute) -> Option<OrGroup> {
 }
 
 /// Parses all filter attributes from the derive input.
-fn parse_filters(input: &DeriveInput) -> Vec<Filter> {
+pub(crate) fn parse_filters(input: &DeriveInput) -> Vec<Filter> {
     find_all_attrs(&input.attrs, "filter")
         .into_iter()
         .filter_map(parse_single_filter)
@@ -545,8 +741,57 @@ fn parse_alias_fields(
     aliases
 }
 
+/// Extracts absent sub-pattern fields from the struct fields.
+fn parse_absent_fields(
+    fields: &syn::punctuated::Punctuated<syn::Field, syn::token::Comma>,
+) -> Vec<AbsentField> {
+    let mut absents = Vec::new();
+
+    for field in fields {
+        let Some(attr) = find_attr(&field.attrs, "absent") else {
+            continue;
+        };
+
+        let name = field
+            .ident
+            .clone()
+            .unwrap_or_else(|| abort!(field, "Absent fields must be named"));
+
+        let mut port = None;
+        let mut protects = None;
+
+        if let Err(e) = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("port") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                port = Some(value.value());
+            } else if meta.path.is_ident("protects") {
+                let value: ExprArray = meta.value()?.parse()?;
+                protects = Some(PathSelector::from_expr_array(&value)?);
+            } else {
+                return Err(meta.error("Expected 'port' or 'protects'"));
+            }
+            Ok(())
+        }) {
+            abort!(e.span(), "{}", e);
+        }
+
+        let port = port.unwrap_or_else(|| abort!(attr, "Absent must specify output 'port'"));
+        let protects =
+            protects.unwrap_or_else(|| abort!(attr, "Absent must specify 'protects' path"));
+
+        absents.push(AbsentField {
+            name,
+            ty: field.ty.clone(),
+            port,
+            protects,
+        });
+    }
+
+    absents
+}
+
 /// Generates the code for custom filter validation.
-fn generate_validate_custom(filters: &[Filter]) -> proc_macro2::TokenStream {
+pub(crate) fn generate_validate_custom(filters: &[Filter]) -> proc_macro2::TokenStream {
     let filter_calls: Vec<_> = filters
         .iter()
         .map(|f| {
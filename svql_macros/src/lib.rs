@@ -21,6 +21,12 @@ mod variant;
 /// - `#[netlist(file = \"path/to/file.v\", module = \"module_name\")]`: Specifies the Verilog file and module name
 /// - `#[port(input)]` / `#[port(output)]`: Marks a field as an input or output port
 /// - `#[port(input, rename = \"verilog_name\")]`: Renames the port when matching the Verilog file
+/// - `#[scan_filter(ScanFilter::Equal(\"a\", \"b\"))]`: Filters matches on their port wires
+///
+/// The `file` path is checked at expansion time, relative to the workspace root,
+/// and expansion emits a warning if the file is missing or does not declare
+/// `module`. The build still succeeds, since the runtime `Driver` may resolve
+/// the path against a different root.
 ///
 /// # Example
 ///
@@ -36,7 +42,24 @@ mod variant;
 ///     pub y: Wire,
 /// }
 /// ```
-#[proc_macro_derive(Netlist, attributes(netlist, port))]
+///
+/// Each port must map to a distinct wire once renames are applied:
+///
+/// ```compile_fail
+/// use svql_query::prelude::*;
+///
+/// #[derive(Debug, Clone, Netlist)]
+/// #[netlist(file = "gates.v", module = "and_gate")]
+/// pub struct Collides {
+///     #[port(input, rename = "a")]
+///     pub left: Wire,
+///     #[port(input, rename = "a")]
+///     pub right: Wire,
+///     #[port(output)]
+///     pub y: Wire,
+/// }
+/// ```
+#[proc_macro_derive(Netlist, attributes(netlist, port, scan_filter))]
 #[proc_macro_error]
 pub fn netlist(item: TokenStream) -> TokenStream {
     netlist::netlist_impl(item)
@@ -50,10 +73,21 @@ pub fn netlist(item: TokenStream) -> TokenStream {
 /// # Attributes
 ///
 /// - `#[submodule]`: Marks a field as a nested pattern component
-/// - `#[connection(from = [...], to = [...])]`: Specifies connections between component ports
+/// - `#[connection(from = [...], to = [...])]`: Specifies connections between component ports;
+///   `kind = "any"` matches any wire of a bundle and `kind = "cone"` any wire
+///   reaching the target through combinational logic
 /// - `#[or_to(from = [...], to = [[...], [...]])]`: One-to-many connection pattern
+/// - `#[connect(from = "a.y", to = "b.a", group = "...")]`: Dotted-path connection;
+///   repeated attributes sharing a `group` id form a single OR group
+/// - `#[manual_connections(CONST)]`: Uses a hand-written `Connections` constant instead
+///   of the connection attributes, keeping the rest of the impl derived
 /// - `#[alias(name, target = [...])]`: Creates an alias for a nested port
 /// - `#[filter(condition = \"...\" )]`: Adds filtering constraints to the pattern
+/// - `#[absent(port = "...", protects = [...])]`: Marks an `Absent<T>` field whose
+///   matches must not drive the protected port
+///
+/// A `Display` impl is also generated that renders the rehydrated match as a
+/// report tree of its submodules and alias wires.
 ///
 /// # Example
 ///
@@ -69,7 +103,18 @@ pub fn netlist(item: TokenStream) -> TokenStream {
 /// ```
 #[proc_macro_derive(
     Composite,
-    attributes(submodule, alias, connection, or_to, or_from, or_group, filter)
+    attributes(
+        submodule,
+        alias,
+        absent,
+        connection,
+        connect,
+        manual_connections,
+        or_to,
+        or_from,
+        or_group,
+        filter
+    )
 )]
 #[proc_macro_error]
 pub fn composite(item: TokenStream) -> TokenStream {
@@ -85,6 +130,11 @@ pub fn composite(item: TokenStream) -> TokenStream {
 ///
 /// - `#[variant_ports(...)]`: Declares the external ports of the variant pattern
 /// - `#[map(...)]`: Maps variant variant implementation ports to the external ports
+/// - `#[distinct]`: Merges matches from different arms that share the same external port wires
+/// - `#[filter(path_or_closure)]`: Rejects rows from any arm for which the filter returns `false`
+///
+/// Each common port `p` also gets an associated `p_cell(row, index)` function that
+/// resolves the design cell behind that port in a matched row.
 ///
 /// # Example
 ///
@@ -98,7 +148,7 @@ pub fn composite(item: TokenStream) -> TokenStream {
 ///     WithEnable(DffWithEnable),
 /// }
 /// ```
-#[proc_macro_derive(Variant, attributes(variant_ports, map))]
+#[proc_macro_derive(Variant, attributes(variant_ports, map, distinct, filter))]
 #[proc_macro_error]
 pub fn variant(item: TokenStream) -> TokenStream {
     variant::variant_impl(item)
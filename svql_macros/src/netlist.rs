@@ -1,11 +1,11 @@
 //! Procedural macro implementation for the `Netlist` derive.
 
 use proc_macro::TokenStream;
-use proc_macro_error::abort;
+use proc_macro_error::{abort, emit_warning};
 use quote::quote;
 use syn::{Data, DeriveInput, Fields, Meta, Token, parse_macro_input};
 
-use crate::parsing::{Direction, find_attr, get_string_value};
+use crate::parsing::{Direction, find_all_attrs, find_attr, get_string_value};
 
 /// Attributes parsed from the `#[netlist(...)]` derive attribute.
 struct NetlistAttr {
@@ -42,6 +42,7 @@ pub fn netlist_impl(item: TokenStream) -> TokenStream {
 
     let netlist_attr = parse_netlist_attr(&input);
     let ports = parse_port_fields(fields);
+    let scan_filters = parse_scan_filters(&input);
 
     let name = &input.ident;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
@@ -80,6 +81,10 @@ pub fn netlist_impl(item: TokenStream) -> TokenStream {
                 #(#port_entries),*
             ];
 
+            const FILTERS: &'static [svql_query::traits::netlist::ScanFilter] = &[
+                #(#scan_filters),*
+            ];
+
             fn netlist_schema() -> &'static svql_query::session::PatternSchema {
                 static SCHEMA: std::sync::OnceLock<svql_query::session::PatternSchema> =
                     std::sync::OnceLock::new();
@@ -183,10 +188,110 @@ fn parse_netlist_attr(input: &DeriveInput) -> NetlistAttr {
         }
     }
 
-    NetlistAttr {
+    let netlist_attr = NetlistAttr {
         file: file.unwrap_or_else(|| abort!(attr, "Missing 'file' in netlist attribute")),
         module: module.unwrap_or_else(|| abort!(attr, "Missing 'module' in netlist attribute")),
+    };
+    check_netlist_file(attr, &netlist_attr);
+    netlist_attr
+}
+
+/// Warns at expansion time if the netlist file cannot be found or does not
+/// mention the module.
+///
+/// Relative paths are looked up against the workspace root, taken to be the
+/// parent of the calling crate's manifest directory, and against the crate
+/// directory itself. The runtime `Driver` may use a different root, so a
+/// miss here is only a warning and never fails the build. The check is
+/// skipped when `CARGO_MANIFEST_DIR` is unavailable.
+fn check_netlist_file(attr: &syn::Attribute, netlist_attr: &NetlistAttr) {
+    let Ok(manifest_dir) = std::env::var("CARGO_MANIFEST_DIR") else {
+        return;
+    };
+    let manifest_dir = std::path::PathBuf::from(manifest_dir);
+    let file = std::path::Path::new(&netlist_attr.file);
+
+    let candidates = if file.is_absolute() {
+        vec![file.to_path_buf()]
+    } else {
+        vec![manifest_dir.join("..").join(file), manifest_dir.join(file)]
+    };
+
+    let Some(path) = candidates.iter().find(|p| p.is_file()) else {
+        emit_warning!(
+            attr,
+            "Netlist file '{}' not found (looked in {}); it must exist relative to the Driver root at runtime",
+            netlist_attr.file,
+            candidates
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        return;
+    };
+
+    if let Ok(contents) = std::fs::read_to_string(path)
+        && !declares_module(path, &contents, &netlist_attr.module)
+    {
+        emit_warning!(
+            attr,
+            "Module '{}' not found in netlist file '{}'",
+            netlist_attr.module,
+            netlist_attr.file
+        );
+    }
+}
+
+/// Whether the netlist text `contents` of `path` declares `module`.
+///
+/// Yosys JSON files must have a `"<module>"` key. Other formats must have
+/// the module name right after their declaration keyword (`.model` in BLIF,
+/// `(cell` in EDIF, `module` otherwise, with RTLIL's leading `\` ignored),
+/// ending on an identifier boundary, so comments or longer names that merely
+/// contain the name do not count.
+fn declares_module(path: &std::path::Path, contents: &str, module: &str) -> bool {
+    let extension = path.extension().and_then(|ext| ext.to_str());
+    let keyword = match extension {
+        Some("json") => return contents.contains(&format!("\"{module}\"")),
+        Some("blif") => ".model",
+        Some("edif" | "edf") => "(cell",
+        _ => "module",
+    };
+
+    let mut tokens = contents.split_whitespace();
+    while let Some(token) = tokens.next() {
+        if token != keyword {
+            continue;
+        }
+        let Some(name) = tokens.next() else {
+            break;
+        };
+        let name = name.trim_start_matches('\\');
+        let end = name
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '$'))
+            .unwrap_or(name.len());
+        if &name[..end] == module {
+            return true;
+        }
     }
+    false
+}
+
+/// Parses all `#[scan_filter(...)]` attributes into filter expressions.
+fn parse_scan_filters(input: &DeriveInput) -> Vec<syn::Expr> {
+    find_all_attrs(&input.attrs, "scan_filter")
+        .into_iter()
+        .map(|attr| {
+            attr.parse_args::<syn::Expr>().unwrap_or_else(|e| {
+                abort!(
+                    attr,
+                    "scan_filter attribute expects a ScanFilter expression: {}",
+                    e
+                )
+            })
+        })
+        .collect()
 }
 
 /// Extracts port fields from the struct fields.
@@ -221,9 +326,30 @@ fn parse_port_fields(
         );
     }
 
+    check_port_collisions(&ports);
+
     ports
 }
 
+/// Rejects ports whose effective wire names collide after applying renames.
+fn check_port_collisions(ports: &[PortField]) {
+    let mut seen: Vec<(String, &syn::Ident)> = Vec::new();
+
+    for port in ports {
+        let wire = port.rename.clone().unwrap_or_else(|| port.name.to_string());
+        if let Some((_, first)) = seen.iter().find(|(name, _)| *name == wire) {
+            abort!(
+                port.name,
+                "Fields '{}' and '{}' both map to port '{}'",
+                first,
+                port.name,
+                wire
+            );
+        }
+        seen.push((wire, &port.name));
+    }
+}
+
 /// Parses the `#[port(...)]` attribute on a field.
 fn parse_port_attr(attr: &syn::Attribute) -> (Direction, Option<String>) {
     let mut direction = None;
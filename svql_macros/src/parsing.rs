@@ -31,6 +31,19 @@ impl PathSelector {
         Ok(Self { segments })
     }
 
+    /// Parse from a dotted string literal like `"and1.y"`
+    pub fn from_dotted(lit: &syn::LitStr) -> Result<Self> {
+        let value = lit.value();
+        let segments: Vec<String> = value.split('.').map(str::to_owned).collect();
+        if segments.iter().any(String::is_empty) {
+            return Err(Error::new_spanned(
+                lit,
+                "Expected a dotted path like \"and1.y\"",
+            ));
+        }
+        Ok(Self { segments })
+    }
+
     /// Generate code for a static Selector.
     pub fn to_selector_tokens(&self) -> TokenStream {
         let segments: Vec<_> = self.segments.iter().map(|s| quote! { #s }).collect();
@@ -5,6 +5,7 @@ use proc_macro_error::abort;
 use quote::quote;
 use syn::{Data, DeriveInput, ExprArray, Fields, Ident, parse_macro_input};
 
+use crate::composite::{generate_validate_custom, parse_filters};
 use crate::parsing::{Direction, PathSelector, find_attr};
 
 /// Represents a common port shared across all variants.
@@ -43,6 +44,8 @@ pub fn variant_impl(item: TokenStream) -> TokenStream {
     };
 
     let common_ports = parse_variant_ports(&input);
+    let distinct = find_attr(&input.attrs, "distinct").is_some();
+    let filters = parse_filters(&input);
     let arms = parse_variant_arms(variants);
 
     let name = &input.ident;
@@ -124,6 +127,33 @@ pub fn variant_impl(item: TokenStream) -> TokenStream {
         })
         .collect();
 
+    let validate_custom_impl = if !filters.is_empty() {
+        generate_validate_custom(&filters)
+    } else {
+        quote! {}
+    };
+
+    let cell_accessors: Vec<_> = common_ports
+        .iter()
+        .map(|p| {
+            let port_name = &p.name;
+            let accessor = quote::format_ident!("{}_cell", port_name);
+            let doc = format!(
+                " Resolves the design cell behind the `{port_name}` port of a matched row."
+            );
+            quote! {
+                #[doc = #doc]
+                pub fn #accessor<'i, 'd>(
+                    row: &svql_query::session::Row<Self>,
+                    index: &'i svql_query::common::GraphIndex<'d>,
+                ) -> Option<&'i svql_query::common::CellWrapper<'d>> {
+                    let id = row.wire(#port_name)?.cell_id()?;
+                    index.get_cell_by_id(id.storage_key() as usize)
+                }
+            }
+        })
+        .collect();
+
     let expanded = quote! {
         impl #impl_generics svql_query::traits::variant::Variant for #name #ty_generics #where_clause {
             const NUM_VARIANTS: usize = #num_variants;
@@ -144,6 +174,10 @@ pub fn variant_impl(item: TokenStream) -> TokenStream {
                 #(#dep_entries),*
             ];
 
+            const DISTINCT: bool = #distinct;
+
+            #validate_custom_impl
+
             fn variant_schema() -> &'static svql_query::session::PatternSchema {
                 static SCHEMA: std::sync::OnceLock<svql_query::session::PatternSchema> =
                     std::sync::OnceLock::new();
@@ -192,6 +226,10 @@ pub fn variant_impl(item: TokenStream) -> TokenStream {
         impl #impl_generics svql_query::traits::Component for #name #ty_generics #where_clause {
             type Kind = svql_query::traits::kind::Variant;
         }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#cell_accessors)*
+        }
     };
 
     TokenStream::from(expanded)
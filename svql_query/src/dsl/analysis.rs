@@ -0,0 +1,93 @@
+//! Structural metrics over matched sub-circuits.
+//!
+//! Helpers here walk the haystack `GraphIndex` starting from wires of a match,
+//! so patterns can compute properties like logic depth in their filters
+//! without writing their own traversals.
+
+use crate::prelude::*;
+
+/// Returns true for cells that end a combinational fan-in walk.
+const fn is_depth_boundary(kind: CellKind) -> bool {
+    !kind.is_logic_gate() || kind.is_sequential()
+}
+
+/// Returns the haystack cell driving the first bit of `wire`, if any.
+#[must_use]
+pub fn driver_cell(wire: &Wire, index: &GraphIndex<'_>) -> Option<GraphNodeIdx> {
+    wire.cell_id()
+        .and_then(|id| index.get_cell_index_by_debug_id(id.storage_key() as usize))
+}
+
+/// Counts the combinational logic levels driving `wire`.
+///
+/// Walks the haystack fan-in from the cell driving `wire`, counting one level
+/// per logic cell on the longest path, and stops at registers, latches,
+/// primary inputs and any other non-logic cell. A wire driven directly by a
+/// register or input therefore has depth `0`, and so does a constant or a
+/// wire whose driver is not in `index`. Combinational loops are cut where
+/// they are first revisited.
+#[must_use]
+pub fn fanin_depth(wire: &Wire, index: &GraphIndex<'_>) -> usize {
+    let Some(start) = driver_cell(wire, index) else {
+        return 0;
+    };
+
+    let mut memo: HashMap<GraphNodeIdx, usize> = HashMap::new();
+    let mut on_path: HashSet<GraphNodeIdx> = HashSet::new();
+    node_depth(start, index, &mut memo, &mut on_path)
+}
+
+/// Collects the combinational fan-in cone of `wire`.
+///
+/// The cone holds the cell driving `wire` plus every cell reachable backwards
+/// through logic cells. Registers, latches, primary inputs and other non-logic
+/// cells are included when reached but not walked through, so a register whose
+/// output feeds back into `wire` appears in the set. Constants and drivers not
+/// in `index` yield an empty set.
+#[must_use]
+pub fn combinational_fanin(wire: &Wire, index: &GraphIndex<'_>) -> HashSet<GraphNodeIdx> {
+    let mut cone = HashSet::new();
+    let Some(start) = driver_cell(wire, index) else {
+        return cone;
+    };
+
+    cone.insert(start);
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        if is_depth_boundary(index.node_kind(node)) {
+            continue;
+        }
+        for &pred in index.fanin(node) {
+            if cone.insert(pred) {
+                stack.push(pred);
+            }
+        }
+    }
+    cone
+}
+
+/// Memoized depth of a single node, see [`fanin_depth`].
+fn node_depth(
+    node: GraphNodeIdx,
+    index: &GraphIndex<'_>,
+    memo: &mut HashMap<GraphNodeIdx, usize>,
+    on_path: &mut HashSet<GraphNodeIdx>,
+) -> usize {
+    if let Some(&depth) = memo.get(&node) {
+        return depth;
+    }
+    if is_depth_boundary(index.node_kind(node)) || !on_path.insert(node) {
+        return 0;
+    }
+
+    let depth = 1 + index
+        .fanin(node)
+        .iter()
+        .map(|&pred| node_depth(pred, index, memo, on_path))
+        .max()
+        .unwrap_or(0);
+
+    on_path.remove(&node);
+    memo.insert(node, depth);
+    depth
+}
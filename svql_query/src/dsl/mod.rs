@@ -2,6 +2,7 @@
 //!
 //! This module provides traits and utilities for defining query patterns in SVQL.
 
+pub mod analysis;
 pub mod traits;
 
 pub use traits::*;
@@ -14,6 +14,10 @@ pub enum ConnectionKind {
     Exact,
     /// Set membership: source wire must be present in target WireArray.
     AnyInSet,
+    /// Cone membership: the cell driving the source wire must be in the
+    /// combinational fan-in cone of the target wire (see
+    /// [`combinational_fanin`]).
+    FaninCone,
 }
 
 /// Connection constraint (keeping existing struct, just updating signature)
@@ -48,6 +52,17 @@ impl Connection {
             kind: ConnectionKind::AnyInSet,
         }
     }
+
+    /// Creates a fan-in cone connection constraint.
+    /// The source wire must reach the target wire through combinational logic.
+    #[must_use]
+    pub const fn fanin_cone(from: Selector<'static>, to: Selector<'static>) -> Self {
+        Self {
+            from: Endpoint { selector: from },
+            to: Endpoint { selector: to },
+            kind: ConnectionKind::FaninCone,
+        }
+    }
 }
 
 /// A target within a pattern hierarchy (a specific submodule port).
@@ -65,6 +80,79 @@ pub struct Connections {
     pub connections: &'static [&'static [Connection]],
 }
 
+/// Negative constraint: no match of another pattern may drive a protected wire.
+#[derive(Debug, Clone, Copy)]
+pub struct Absence {
+    /// Type fingerprint of the pattern that must be absent.
+    pub type_id: std::any::TypeId,
+    /// Output port of the absent pattern.
+    pub port: &'static str,
+    /// The composite wire that the absent pattern must not drive.
+    pub protects: Endpoint,
+}
+
+impl Absence {
+    /// Creates an absence constraint for pattern `T`.
+    #[must_use]
+    pub const fn of<T: 'static>(port: &'static str, protects: Selector<'static>) -> Self {
+        Self {
+            type_id: std::any::TypeId::of::<T>(),
+            port,
+            protects: Endpoint { selector: protects },
+        }
+    }
+}
+
+/// Field type for an `#[absent]` sub-pattern.
+///
+/// The absent pattern is searched as a dependency but never joined into the
+/// composite, so the field carries no match data.
+pub struct Absent<T>(std::marker::PhantomData<T>);
+
+impl<T: Pattern + 'static> Absent<T> {
+    /// Execution metadata of the absent pattern.
+    pub const EXEC_INFO: &'static ExecInfo = T::EXEC_INFO;
+
+    /// Creates the absence constraint for this pattern.
+    #[must_use]
+    pub const fn constraint(port: &'static str, protects: Selector<'static>) -> Absence {
+        Absence::of::<T>(port, protects)
+    }
+
+    /// Pre-fetch the designs required by the absent pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the absent pattern's designs cannot be loaded.
+    pub fn preload_driver(
+        driver: &Driver,
+        design_key: &DriverKey,
+        config: &svql_common::Config,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        T::preload_driver(driver, design_key, config)
+    }
+}
+
+impl<T> Default for Absent<T> {
+    fn default() -> Self {
+        Self(std::marker::PhantomData)
+    }
+}
+
+impl<T> Clone for Absent<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Absent<T> {}
+
+impl<T> std::fmt::Debug for Absent<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Absent<{}>", std::any::type_name::<T>())
+    }
+}
+
 /// Resolve a selector path to its root submodule index.
 fn resolve_submodule<T: Composite>(endpoint: &Endpoint) -> Option<usize> {
     let head = endpoint.selector.head()?;
@@ -257,6 +345,9 @@ pub trait Composite: Sized + Component<Kind = kind::Composite> + Send + Sync + '
     /// Dependencies (macro-generated)
     const DEPENDANCIES: &'static [&'static ExecInfo];
 
+    /// Absence constraints (macro-generated from `#[absent]` fields)
+    const ABSENT: &'static [Absence] = &[];
+
     /// Schema accessor (macro generates this with `OnceLock` pattern)
     fn composite_schema() -> &'static crate::session::PatternSchema {
         static SCHEMA: std::sync::OnceLock<crate::session::PatternSchema> =
@@ -269,6 +360,11 @@ pub trait Composite: Sized + Component<Kind = kind::Composite> + Send + Sync + '
     }
 
     /// Convert declarations to column definitions
+    ///
+    /// A composite row stores one reference per submodule plus its alias
+    /// wires; submodule columns are never copied into the parent. Rows stay
+    /// narrow however deep the hierarchy is, and connection selectors are
+    /// resolved through the referenced submodule tables on demand.
     #[must_use]
     fn composite_to_defs() -> Vec<ColumnDef> {
         let mut defs = Vec::with_capacity(Self::SUBMODULES.len() + Self::ALIASES.len());
@@ -404,7 +500,7 @@ pub trait Composite: Sized + Component<Kind = kind::Composite> + Send + Sync + '
 
         // Apply automatic deduplication
         let before_dedup = final_entries.len();
-        Self::apply_deduplication(&mut final_entries);
+        Self::apply_deduplication(&mut final_entries, ctx);
         if before_dedup != final_entries.len() {
             tracing::debug!(
                 "[COMPOSITE] Deduplication: {} -> {} entries ({} removed)",
@@ -422,8 +518,8 @@ pub trait Composite: Sized + Component<Kind = kind::Composite> + Send + Sync + '
     }
 
     /// Apply automatic deduplication.
-    fn apply_deduplication(entries: &mut Vec<EntryArray>) {
-        crate::traits::apply_deduplication(entries);
+    fn apply_deduplication(entries: &mut Vec<EntryArray>, ctx: &ExecutionContext) {
+        crate::traits::apply_deduplication(entries, &Self::composite_schema().outputs, ctx);
     }
 
     /// Custom validation hook for filters (override to add filtering logic)
@@ -489,7 +585,7 @@ pub trait Composite: Sized + Component<Kind = kind::Composite> + Send + Sync + '
                         let dst_wire = row.resolve(conn.to.selector, ctx);
 
                         match (src_wire, dst_wire) {
-                            (Some(s), Some(d)) if s.drives(&d) => {
+                            (Some(s), Some(d)) if ctx.wire_drives(&s, &d) => {
                                 tracing::trace!(
                                     "[{}] Connection {:?} → {:?} satisfied",
                                     std::any::type_name::<Self>(),
@@ -530,7 +626,7 @@ pub trait Composite: Sized + Component<Kind = kind::Composite> + Send + Sync + '
                         match (src_wire, dst_bundle) {
                             (Some(src), Some(bundle)) => {
                                 // Check if src drives any wire in the bundle
-                                let found = bundle.iter().any(|w| src.drives(w));
+                                let found = bundle.iter().any(|w| ctx.wire_drives(&src, w));
                                 if found {
                                     tracing::trace!(
                                         "[{}] ConnectAny {:?} → {:?} satisfied (found in bundle of {} wires)",
@@ -564,6 +660,29 @@ pub trait Composite: Sized + Component<Kind = kind::Composite> + Send + Sync + '
                             }
                         }
                     }
+                    ConnectionKind::FaninCone => {
+                        // Cone membership: source driver must feed the target combinationally
+                        let src_wire = row.resolve(conn.from.selector, ctx);
+                        let dst_wire = row.resolve(conn.to.selector, ctx);
+                        let index = ctx.haystack_design().index();
+
+                        let found = match (src_wire, dst_wire) {
+                            (Some(s), Some(d)) => driver_cell(&s, index)
+                                .is_some_and(|cell| combinational_fanin(&d, index).contains(&cell)),
+                            _ => false,
+                        };
+                        tracing::trace!(
+                            "[{}] FaninCone {:?} → {:?} satisfied: {}",
+                            std::any::type_name::<Self>(),
+                            conn.from.selector.path(),
+                            conn.to.selector.path(),
+                            found
+                        );
+                        if found {
+                            group_satisfied = true;
+                            break;
+                        }
+                    }
                 }
             }
 
@@ -617,15 +736,61 @@ pub trait Composite: Sized + Component<Kind = kind::Composite> + Send + Sync + '
         }
     }
 
-    /// Validate (calls both connection and custom validation)
+    /// Validate absence constraints
+    ///
+    /// Rejects the row if any match of an absent pattern drives a protected wire.
+    fn validate_absent(row: &Row<Self>, ctx: &ExecutionContext) -> bool {
+        for absence in Self::ABSENT {
+            if !Self::is_selector_resolvable(row, absence.protects.selector) {
+                // Protected submodule not joined yet - check in a later iteration
+                continue;
+            }
+
+            let Some(protected) = row.resolve(absence.protects.selector, ctx) else {
+                continue;
+            };
+
+            let Some(table) = ctx.get_any_table(absence.type_id) else {
+                tracing::warn!(
+                    "[{}] Absent pattern table for {:?} not found",
+                    std::any::type_name::<Self>(),
+                    absence.protects.selector.path()
+                );
+                continue;
+            };
+
+            let driven = (0..table.len()).any(|idx| {
+                table
+                    .get_wire(idx, absence.port)
+                    .is_some_and(|wire| ctx.wire_drives(&wire, &protected))
+            });
+
+            if driven {
+                tracing::trace!(
+                    "[{}] Absent {} drives {:?}, rejecting",
+                    std::any::type_name::<Self>(),
+                    table.type_name(),
+                    absence.protects.selector.path()
+                );
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Validate (calls connection, absence and custom validation)
     fn validate(row: &Row<Self>, ctx: &ExecutionContext) -> bool {
         // Connection validation
         let connections_ok = Self::validate_connections(row, ctx);
 
+        // Absence validation
+        let absent_ok = Self::validate_absent(row, ctx);
+
         // Custom filter validation
         let custom_ok = Self::validate_custom(row, ctx);
 
-        connections_ok && custom_ok
+        connections_ok && absent_ok && custom_ok
     }
 
     /// Rehydrate from row
@@ -1016,7 +1181,9 @@ where
             dep_tables.push(table);
         }
 
-        T::compose(ctx, &dep_tables)
+        // Missing sub-matches can only shrink the composite's results
+        let truncated = dep_tables.iter().any(|table| table.is_truncated());
+        Ok(T::compose(ctx, &dep_tables)?.with_truncated(truncated))
     }
 
     fn internal_rehydrate<'a>(
@@ -1050,8 +1217,8 @@ pub(crate) mod test {
     use crate::traits::{Netlist, Pattern};
 
     use super::{
-        Alias, Component, Composite, Connection, Connections, Driver, DriverKey, ExecInfo, Row,
-        Selector, Store, Submodule, kind,
+        Absent, Alias, Component, Composite, Connection, Connections, Driver, DriverKey, ExecInfo,
+        Row, Selector, Store, Submodule, kind,
     };
 
     use svql_common::Wire;
@@ -1091,6 +1258,162 @@ pub(crate) mod test {
         expect: 2  // Automatically deduplicated
     );
 
+    #[test]
+    fn test_and2gates_display() -> Result<(), Box<dyn std::error::Error>> {
+        let driver = Driver::new_workspace()?;
+        let config = svql_common::Config::builder().build();
+        let key = DriverKey::new(
+            "examples/fixtures/basic/and/verilog/small_and_tree.v",
+            "small_and_tree",
+        );
+
+        let store = svql_query::run_query::<And2Gates>(&driver, &key, &config)?;
+        let table = store.get::<And2Gates>().expect("Table should exist");
+        let (_, row) = table.rows().next().expect("Should have a match");
+        let matched =
+            And2Gates::rehydrate(&row, &store, &driver, &key, &config).expect("Should rehydrate");
+
+        let rendered = matched.to_string();
+        assert!(rendered.starts_with("And2Gates"));
+        assert!(rendered.contains("and1 ("));
+        assert!(rendered.contains("and2 ("));
+        assert!(rendered.contains("y (Output: "));
+        Ok(())
+    }
+
+    #[test]
+    fn test_and2gates_rehydrate_field() -> Result<(), Box<dyn std::error::Error>> {
+        let driver = Driver::new_workspace()?;
+        let config = svql_common::Config::builder().build();
+        let key = DriverKey::new(
+            "examples/fixtures/basic/and/verilog/small_and_tree.v",
+            "small_and_tree",
+        );
+
+        let store = svql_query::run_query::<And2Gates>(&driver, &key, &config)?;
+        let table = store.get::<And2Gates>().expect("Table should exist");
+        for (_, row) in table.rows() {
+            let matched = And2Gates::rehydrate(&row, &store, &driver, &key, &config)
+                .expect("Should rehydrate");
+            assert_eq!(
+                And2Gates::rehydrate_field(&row, &store, Selector::new(&["and1", "y"])),
+                Some(matched.and1.y.clone())
+            );
+            assert_eq!(
+                And2Gates::rehydrate_field(&row, &store, Selector::new(&["y"])),
+                Some(matched.y.clone())
+            );
+            assert!(
+                And2Gates::rehydrate_field(&row, &store, Selector::new(&["and1", "nope"]))
+                    .is_none()
+            );
+        }
+        Ok(())
+    }
+
+    #[derive(Debug, Clone, Composite)]
+    #[connect(from = "and1.y", to = "and2.a", group = "cascade")]
+    #[connect(from = "and1.y", to = "and2.b", group = "cascade")]
+    /// Two cascaded AND gates declared with dotted `connect` attributes.
+    pub struct And2GatesConnect {
+        /// The first gate.
+        #[submodule]
+        pub and1: AndGate,
+        /// The second gate.
+        #[submodule]
+        pub and2: AndGate,
+        /// Final output.
+        #[alias(output, target = ["and2", "y"])]
+        pub y: Wire,
+    }
+
+    query_test!(
+        name: test_and2gates_connect_small_and_tree,
+        query: And2GatesConnect,
+        haystack: ("examples/fixtures/basic/and/verilog/small_and_tree.v", "small_and_tree"),
+        expect: 2
+    );
+
+    #[derive(Debug, Clone, Composite)]
+    #[or_to(from = ["and1", "y"], to = [["and2", "a"], ["and2", "b"]])]
+    /// Cascaded AND gates whose first stage is not fed by another AND gate.
+    pub struct And2GatesLeafFirst {
+        /// The first gate.
+        #[submodule]
+        pub and1: AndGate,
+        /// The second gate.
+        #[submodule]
+        pub and2: AndGate,
+        /// No AND gate may drive the first gate's input.
+        #[absent(port = "y", protects = ["and1", "a"])]
+        pub feeder: Absent<AndGate>,
+    }
+
+    query_test!(
+        name: test_absent_unconnected_keeps_matches,
+        query: And2GatesLeafFirst,
+        haystack: ("examples/fixtures/basic/and/verilog/small_and_tree.v", "small_and_tree"),
+        expect: 2
+    );
+
+    #[derive(Debug, Clone, Composite)]
+    #[or_to(from = ["and1", "y"], to = [["and2", "a"], ["and2", "b"]])]
+    /// Cascaded AND gates whose second stage is not fed by another AND gate.
+    pub struct And2GatesLeafSecond {
+        /// The first gate.
+        #[submodule]
+        pub and1: AndGate,
+        /// The second gate.
+        #[submodule]
+        pub and2: AndGate,
+        /// No AND gate may drive the second gate's input.
+        #[absent(port = "y", protects = ["and2", "a"])]
+        pub feeder: Absent<AndGate>,
+    }
+
+    query_test!(
+        name: test_absent_connected_rejects_matches,
+        query: And2GatesLeafSecond,
+        haystack: ("examples/fixtures/basic/and/verilog/small_and_tree.v", "small_and_tree"),
+        expect: 0
+    );
+
+    /// Hand-written connection set for two cascaded AND gates.
+    const AND2_CASCADE: Connections = Connections {
+        connections: &[&[
+            Connection::new(
+                Selector::static_path(&["and1", "y"]),
+                Selector::static_path(&["and2", "a"]),
+            ),
+            Connection::new(
+                Selector::static_path(&["and1", "y"]),
+                Selector::static_path(&["and2", "b"]),
+            ),
+        ]],
+    };
+
+    #[derive(Debug, Clone, Composite)]
+    #[manual_connections(AND2_CASCADE)]
+    /// Cascaded AND gates with derived plumbing but hand-written connections.
+    pub struct And2GatesManualConnections {
+        /// The first gate.
+        #[submodule]
+        pub and1: AndGate,
+        /// The second gate.
+        #[submodule]
+        pub and2: AndGate,
+        /// Final output.
+        #[alias(output, target = ["and2", "y"])]
+        pub y: Wire,
+    }
+
+    query_test!(
+        name: test_and2gates_manual_connections_small_and_tree,
+        query: And2GatesManualConnections,
+        haystack: ("examples/fixtures/basic/and/verilog/small_and_tree.v", "small_and_tree"),
+        expect: 2
+    );
+
     #[derive(Debug, Clone)]
     /// Manual implementation of a composite pattern for testing.
     pub struct ManualAnd2Gates {
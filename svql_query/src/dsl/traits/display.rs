@@ -4,10 +4,11 @@
 
 use crate::prelude::*;
 
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 /// A node in a hierarchical match report
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct ReportNode {
     /// Display name for this node (field name or type name)
     pub name: String,
@@ -22,6 +23,101 @@ pub struct ReportNode {
 }
 
 impl ReportNode {
+    /// Serialize the report tree as JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `serde_json::Error` if serialization fails.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Group reports whose source lines overlap into single findings.
+    ///
+    /// The overlap of two reports is the number of shared `(file, line)` pairs
+    /// across their whole trees, divided by the size of the smaller set. Reports
+    /// whose overlap is at least `threshold` (in `0.0..=1.0`) end up in the same
+    /// group, transitively. Each group of two or more becomes a parent node,
+    /// named after its first member, with the grouped reports as children.
+    /// Reports without source lines are never merged.
+    #[must_use]
+    pub fn merge_overlapping(nodes: Vec<Self>, threshold: f64) -> Vec<Self> {
+        /// Finds the representative of `i`, compressing the path on the way.
+        fn find(parent: &mut [usize], mut i: usize) -> usize {
+            while parent[i] != i {
+                parent[i] = parent[parent[i]];
+                i = parent[i];
+            }
+            i
+        }
+
+        let line_sets: Vec<HashSet<(Arc<str>, usize)>> = nodes
+            .iter()
+            .map(|node| {
+                let mut lines = HashSet::new();
+                node.collect_source_lines(&mut lines);
+                lines
+            })
+            .collect();
+
+        // Union-find over report indices
+        let mut parent: Vec<usize> = (0..nodes.len()).collect();
+
+        for i in 0..nodes.len() {
+            for j in (i + 1)..nodes.len() {
+                let smaller = line_sets[i].len().min(line_sets[j].len());
+                if smaller == 0 {
+                    continue;
+                }
+                let shared = line_sets[i].intersection(&line_sets[j]).count();
+                if shared as f64 / smaller as f64 >= threshold {
+                    let (a, b) = (find(&mut parent, i), find(&mut parent, j));
+                    parent[b.max(a)] = a.min(b);
+                }
+            }
+        }
+
+        let mut groups: Vec<(usize, Vec<Self>)> = Vec::new();
+        for (i, node) in nodes.into_iter().enumerate() {
+            let root = find(&mut parent, i);
+            match groups.iter_mut().find(|(r, _)| *r == root) {
+                Some((_, members)) => members.push(node),
+                None => groups.push((root, vec![node])),
+            }
+        }
+
+        groups
+            .into_iter()
+            .map(|(_, mut members)| {
+                if members.len() == 1 {
+                    return members.remove(0);
+                }
+                Self {
+                    name: members[0].name.clone(),
+                    type_name: members[0].type_name.clone(),
+                    details: Some(format!("{} overlapping matches", members.len())),
+                    source_loc: None,
+                    children: members,
+                }
+            })
+            .collect()
+    }
+
+    /// Collect every `(file, line)` pair referenced in this subtree.
+    fn collect_source_lines(&self, out: &mut HashSet<(Arc<str>, usize)>) {
+        if let Some(source_loc) = &self.source_loc {
+            out.extend(
+                source_loc
+                    .lines
+                    .iter()
+                    .map(|line| (source_loc.file.clone(), line.number)),
+            );
+        }
+        for child in &self.children {
+            child.collect_source_lines(out);
+        }
+    }
+
     /// Render the report tree as a formatted string
     pub fn render(&self) -> String {
         let mut cache = HashMap::new();
@@ -31,7 +127,20 @@ impl ReportNode {
     /// Render with file content caching for efficiency
     pub fn render_with_cache(&self, cache: &mut HashMap<Arc<str>, Vec<String>>) -> String {
         let mut output = String::new();
-        self.render_recursive(&mut output, "", true, true, cache);
+        self.render_recursive(&mut output, "", true, true, None, cache);
+        output
+    }
+
+    /// Render the report tree with annotated source excerpts.
+    ///
+    /// Relative source paths are resolved against `root`. Each location is
+    /// shown with [`SOURCE_CONTEXT_LINES`] lines of context on either side and
+    /// matched lines are marked with `>` in the gutter. When a source file
+    /// cannot be read, the collapsed line ranges are printed instead.
+    pub fn render_with_source(&self, root: &Path) -> String {
+        let mut cache = HashMap::new();
+        let mut output = String::new();
+        self.render_recursive(&mut output, "", true, true, Some(root), &mut cache);
         output
     }
 
@@ -42,6 +151,7 @@ impl ReportNode {
         prefix: &str,
         is_last: bool,
         is_root: bool,
+        root: Option<&Path>,
         cache: &mut HashMap<Arc<str>, Vec<String>>,
     ) {
         let marker = if is_root {
@@ -60,10 +170,12 @@ impl ReportNode {
         let source_header = self.source_loc.as_ref().map_or_else(
             || "".to_string(),
             |source_loc| {
-                if !source_loc.lines.is_empty() {
-                    format!(": {}:", source_loc.file)
-                } else {
+                if source_loc.lines.is_empty() {
                     "".to_string()
+                } else if root.is_some() {
+                    format!(": {}:{}", source_loc.file, source_loc.line_ranges())
+                } else {
+                    format!(": {}:", source_loc.file)
                 }
             },
         );
@@ -84,27 +196,85 @@ impl ReportNode {
         // Print source lines if available
         if let Some(source_loc) = self.source_loc.as_ref().filter(|s| !s.lines.is_empty()) {
             let file_path = &source_loc.file;
-            let lines = cache
-                .entry(file_path.clone())
-                .or_insert_with(|| read_file_lines(file_path).unwrap_or_default());
-
-            for line_meta in &source_loc.lines {
-                let content = if line_meta.number > 0 && line_meta.number <= lines.len() {
-                    lines[line_meta.number - 1].trim_end()
-                } else {
-                    "<line not found in file>"
-                };
-
-                f.push_str(&format!(
-                    "{}    {:>4} | {}\n",
-                    new_prefix, line_meta.number, content
-                ));
+            let lines = cache.entry(file_path.clone()).or_insert_with(|| {
+                let path =
+                    root.map_or_else(|| PathBuf::from(&**file_path), |r| r.join(&**file_path));
+                read_file_lines(&path.to_string_lossy()).unwrap_or_default()
+            });
+
+            if root.is_some() {
+                push_source_excerpt(f, new_prefix, source_loc, lines);
+            } else {
+                for line_meta in &source_loc.lines {
+                    let content = if line_meta.number > 0 && line_meta.number <= lines.len() {
+                        lines[line_meta.number - 1].trim_end()
+                    } else {
+                        "<line not found in file>"
+                    };
+
+                    f.push_str(&format!(
+                        "{}    {:>4} | {}\n",
+                        new_prefix, line_meta.number, content
+                    ));
+                }
             }
         }
 
         for (i, child) in self.children.iter().enumerate() {
             let last_child = i == self.children.len() - 1;
-            child.render_recursive(f, new_prefix, last_child, false, cache);
+            child.render_recursive(f, new_prefix, last_child, false, root, cache);
+        }
+    }
+}
+
+/// Lines of context shown around each location by [`ReportNode::render_with_source`].
+pub const SOURCE_CONTEXT_LINES: usize = 2;
+
+/// Appends an excerpt of `lines` covering `source_loc`, with context and a gutter.
+///
+/// Falls back to the collapsed line ranges when none of the lines are available.
+fn push_source_excerpt(
+    f: &mut String,
+    prefix: &str,
+    source_loc: &SourceLocation,
+    lines: &[String],
+) {
+    let mut windows: Vec<(usize, usize)> = Vec::new();
+    for (start, end) in source_loc.line_spans() {
+        let lo = start.saturating_sub(SOURCE_CONTEXT_LINES).max(1);
+        let hi = (end + SOURCE_CONTEXT_LINES).min(lines.len());
+        if lo > hi {
+            continue;
+        }
+        match windows.last_mut() {
+            Some((_, last_hi)) if lo <= *last_hi + 1 => *last_hi = (*last_hi).max(hi),
+            _ => windows.push((lo, hi)),
+        }
+    }
+
+    if windows.is_empty() {
+        f.push_str(&format!(
+            "{}    lines {}\n",
+            prefix,
+            source_loc.line_ranges()
+        ));
+        return;
+    }
+
+    let matched: HashSet<usize> = source_loc.lines.iter().map(|line| line.number).collect();
+    for (i, (lo, hi)) in windows.into_iter().enumerate() {
+        if i > 0 {
+            f.push_str(&format!("{}    {:>4} :\n", prefix, "..."));
+        }
+        for (number, content) in (lo..=hi).zip(&lines[lo - 1..hi]) {
+            let gutter = if matched.contains(&number) { '>' } else { ' ' };
+            f.push_str(&format!(
+                "{}  {} {:>4} | {}\n",
+                prefix,
+                gutter,
+                number,
+                content.trim_end()
+            ));
         }
     }
 }
@@ -380,4 +550,128 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_render_with_source() -> Result<(), Box<dyn std::error::Error>> {
+        let root = std::env::temp_dir().join(format!("svql_render_{}", std::process::id()));
+        std::fs::create_dir_all(&root)?;
+        let source: String = (1..=20).map(|n| format!("line {n}\n")).collect();
+        std::fs::write(root.join("top.v"), source)?;
+
+        let at = |file: &str, numbers: &[usize]| SourceLocation {
+            file: file.into(),
+            lines: numbers
+                .iter()
+                .map(|&number| SourceLine {
+                    number,
+                    start_column: 1,
+                    end_column: 0,
+                })
+                .collect(),
+        };
+        let node = ReportNode {
+            name: "top".to_string(),
+            type_name: "Composite".to_string(),
+            details: None,
+            source_loc: Some(at("top.v", &[5, 6, 15])),
+            children: vec![ReportNode {
+                name: "y".to_string(),
+                type_name: "Wire".to_string(),
+                details: None,
+                source_loc: Some(at("missing.v", &[3, 4])),
+                children: Vec::new(),
+            }],
+        };
+
+        let rendered = node.render_with_source(&root);
+        std::fs::remove_dir_all(&root)?;
+
+        assert!(rendered.contains("top.v:5-6, 15"));
+        assert!(rendered.contains("  >    5 | line 5"));
+        assert!(rendered.contains("       3 | line 3"));
+        assert!(rendered.contains("     ... :"));
+        assert!(!rendered.contains("line 10"));
+        assert!(rendered.contains("lines 3-4"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_overlapping() {
+        let report = |name: &str, file: &str, lines: &[usize]| ReportNode {
+            name: name.to_string(),
+            type_name: "Cwe1234".to_string(),
+            details: None,
+            source_loc: None,
+            children: vec![ReportNode {
+                name: "y".to_string(),
+                type_name: "Wire".to_string(),
+                details: None,
+                source_loc: Some(SourceLocation {
+                    file: file.into(),
+                    lines: lines
+                        .iter()
+                        .map(|&number| SourceLine {
+                            number,
+                            start_column: 1,
+                            end_column: 0,
+                        })
+                        .collect(),
+                }),
+                children: Vec::new(),
+            }],
+        };
+
+        let nodes = vec![
+            report("first", "top.v", &[10, 11, 12, 13]),
+            report("other", "top.v", &[40, 41]),
+            report("second", "top.v", &[12, 13, 14, 15]),
+            report("elsewhere", "sub.v", &[10, 11, 12, 13]),
+        ];
+
+        let merged = ReportNode::merge_overlapping(nodes.clone(), 0.5);
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged[0].name, "first");
+        assert_eq!(merged[0].details.as_deref(), Some("2 overlapping matches"));
+        assert_eq!(merged[0].children.len(), 2);
+        assert_eq!(merged[0].children[1].name, "second");
+        assert_eq!(merged[1].name, "other");
+        assert_eq!(merged[2].name, "elsewhere");
+
+        // A stricter threshold keeps the half-overlapping reports apart
+        assert_eq!(ReportNode::merge_overlapping(nodes, 0.75).len(), 4);
+    }
+
+    #[test]
+    fn test_report_node_to_json() -> Result<(), Box<dyn std::error::Error>> {
+        let node = ReportNode {
+            name: "top".to_string(),
+            type_name: "Composite".to_string(),
+            details: None,
+            source_loc: Some(SourceLocation {
+                file: "top.v".into(),
+                lines: vec![SourceLine {
+                    number: 4,
+                    start_column: 1,
+                    end_column: 8,
+                }],
+            }),
+            children: vec![ReportNode {
+                name: "y".to_string(),
+                type_name: "Wire".to_string(),
+                details: Some("Output".to_string()),
+                source_loc: None,
+                children: Vec::new(),
+            }],
+        };
+
+        let value: serde_json::Value = serde_json::from_str(&node.to_json()?)?;
+        assert_eq!(value["name"], "top");
+        assert_eq!(value["source_loc"]["file"], "top.v");
+        assert_eq!(value["source_loc"]["lines"], serde_json::json!([4]));
+        assert_eq!(value["children"][0]["details"], "Output");
+        assert!(value["children"][0]["source_loc"].is_null());
+
+        Ok(())
+    }
 }
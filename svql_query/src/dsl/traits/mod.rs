@@ -24,10 +24,31 @@ use crate::session::EntryArray;
 /// Apply automatic deduplication to a list of entries.
 ///
 /// Removes rows with identical signatures (all wire references, submodule references,
-/// and metadata). This is always enabled to prevent redundant results.
-pub fn apply_deduplication(entries: &mut Vec<EntryArray>) {
+/// and metadata) unless the context's `Config::dedupe` keeps them. With
+/// `Dedupe::ByOutputDriver`, rows that agree on every column in `outputs` are
+/// collapsed as well.
+pub fn apply_deduplication(
+    entries: &mut Vec<EntryArray>,
+    outputs: &[usize],
+    ctx: &ExecutionContext,
+) {
+    let dedupe = ctx.config().dedupe;
+    if !dedupe.collapses_rows() {
+        return;
+    }
     let mut seen = HashSet::new();
     entries.retain(|entry| seen.insert(entry.signature()));
+
+    if dedupe.collapses_output_drivers() && !outputs.is_empty() {
+        let mut seen_outputs = HashSet::new();
+        entries.retain(|entry| {
+            let key: Vec<ColumnEntry> = outputs
+                .iter()
+                .map(|&idx| entry.entries[idx].clone())
+                .collect();
+            seen_outputs.insert(key)
+        });
+    }
 }
 
 /// Executes a search function and returns the result as a type-erased table.
@@ -129,6 +150,13 @@ pub trait Pattern: Sized + Send + Sync {
         Ok(Box::new(table))
     }
 
+    /// Render the execution plan for this pattern without running it.
+    #[must_use]
+    fn explain() -> String {
+        let (plan, _slots) = ExecutionPlan::build(Self::EXEC_INFO);
+        plan.explain()
+    }
+
     /// Entry point for plan execution.
     ///
     /// # Errors
@@ -138,6 +166,25 @@ pub trait Pattern: Sized + Send + Sync {
         driver: &Driver,
         design_key: &DriverKey,
         config: &svql_common::Config,
+    ) -> Result<Store, QueryError> {
+        Self::search_with_progress(driver, design_key, config, None)
+    }
+
+    /// Entry point for plan execution with structured progress reporting.
+    ///
+    /// `progress` receives [`subgraph::ProgressEvent::Indexing`] while the
+    /// needle and haystack are loaded, followed by the events of every
+    /// netlist sub-search in the plan. Dependencies run in parallel under
+    /// `config.parallel`, so events of different sub-searches may interleave.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `QueryError` if the search fails.
+    fn search_with_progress(
+        driver: &Driver,
+        design_key: &DriverKey,
+        config: &svql_common::Config,
+        progress: Option<subgraph::ProgressFn>,
     ) -> Result<Store, QueryError> {
         info!("═══════════════════════════════════════════════════════");
         info!(
@@ -158,6 +205,9 @@ pub trait Pattern: Sized + Send + Sync {
             std::any::type_name::<Self>(),
             design_key
         );
+        if let Some(progress) = &progress {
+            progress(subgraph::ProgressEvent::Indexing);
+        }
         Self::preload_driver(driver, design_key, config)
             .map_err(|e| QueryError::design_load(e.to_string()))?;
         info!("Driver preload complete");
@@ -166,7 +216,7 @@ pub trait Pattern: Sized + Send + Sync {
             "Executing search for pattern: {}",
             std::any::type_name::<Self>()
         );
-        let store = plan.execute(driver, design_key, config, slots)?;
+        let store = plan.execute_with_progress(driver, design_key, config, slots, progress)?;
 
         info!("═══════════════════════════════════════════════════════");
         info!(
@@ -193,6 +243,38 @@ pub trait Pattern: Sized + Send + Sync {
     where
         Self: Component + 'static;
 
+    /// Runs the search and rehydrates its matches as the iterator reaches them.
+    ///
+    /// Netlist patterns are streamed from the subgraph matcher, so dropping
+    /// the iterator early also stops the search. Other kinds run the whole
+    /// plan first and only defer rehydration.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `QueryError` if loading the designs or starting the search
+    /// fails.
+    fn search_rehydrated<'a>(
+        driver: &'a Driver,
+        key: &'a DriverKey,
+        config: &'a svql_common::Config,
+        progress: Option<subgraph::ProgressFn>,
+    ) -> Result<Box<dyn Iterator<Item = Self> + 'a>, QueryError>
+    where
+        Self: Component + 'static;
+
+    /// Resolve a single wire of a row without rehydrating the whole match.
+    ///
+    /// `path` is followed through submodule columns using only the tables in
+    /// `store`, so none of the other fields or submodule rows are built.
+    /// Returns `None` if the path does not name a wire or a submodule on the
+    /// way is unmatched.
+    fn rehydrate_field(row: &Row<Self>, store: &Store, path: Selector<'_>) -> Option<Wire>
+    where
+        Self: Component + 'static,
+    {
+        row.resolve_in_store(path, store)
+    }
+
     /// Create a hierarchical report node from a match row.
     fn row_to_report_node(
         row: &Row<Self>,
@@ -282,6 +364,32 @@ pub trait PatternInternal<K>: Sized {
     where
         Self: Component + PatternInternal<Self::Kind> + Send + Sync + 'static;
 
+    /// Runs the search and rehydrates its matches one at a time.
+    ///
+    /// The default runs the whole plan with [`Pattern::search_with_progress`]
+    /// and defers only rehydration. Kinds that can enumerate matches
+    /// incrementally override it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `QueryError` if the search fails.
+    fn internal_search_rehydrated<'a>(
+        driver: &'a Driver,
+        key: &'a DriverKey,
+        config: &'a svql_common::Config,
+        progress: Option<subgraph::ProgressFn>,
+    ) -> Result<Box<dyn Iterator<Item = Self> + 'a>, QueryError>
+    where
+        Self: Pattern + Component + 'static,
+    {
+        let store = Self::search_with_progress(driver, key, config, progress)?;
+        let len = store.get::<Self>().map_or(0, Table::len);
+        Ok(Box::new((0..len).filter_map(move |idx| {
+            let row = store.get::<Self>()?.row_at(u32::try_from(idx).ok()?)?;
+            Self::rehydrate(&row, &store, driver, key, config)
+        })))
+    }
+
     /// Generates a report node from stored row data.
     fn internal_row_to_report_node(
         row: &Row<Self>,
@@ -339,6 +447,15 @@ where
         T::internal_rehydrate(row, store, driver, key, config)
     }
 
+    fn search_rehydrated<'a>(
+        driver: &'a Driver,
+        key: &'a DriverKey,
+        config: &'a svql_common::Config,
+        progress: Option<subgraph::ProgressFn>,
+    ) -> Result<Box<dyn Iterator<Item = Self> + 'a>, QueryError> {
+        T::internal_search_rehydrated(driver, key, config, progress)
+    }
+
     fn row_to_report_node(
         row: &Row<Self>,
         store: &Store,
@@ -6,6 +6,47 @@ use crate::prelude::*;
 use prjunnamed_netlist::Value;
 use tracing::debug;
 
+/// Metadata column holding the functional verification verdict of a match.
+///
+/// Only filled when `Config::verify_functional` is set.
+const VERIFIED_COLUMN: &str = "__verified";
+
+/// Checks that every port declared by `T` exists on its needle design.
+fn validate_ports<T: Netlist>(needle: &GraphIndex<'_>) -> Result<(), QueryError> {
+    needle
+        .validate_port_decls("PORTS", T::PORTS)
+        .map_err(|e| {
+            QueryError::invalid_ports(format!("{} ({}): {e}", T::MODULE_NAME, T::FILE_PATH))
+        })
+}
+
+/// Rehydrated matches of a netlist pattern, found on a worker thread as the
+/// iterator is advanced.
+struct NetlistStream<T> {
+    /// Receives each match once the consumer asks for it.
+    receiver: Option<std::sync::mpsc::Receiver<T>>,
+    /// Thread running the subgraph search.
+    worker: Option<std::thread::JoinHandle<()>>,
+}
+
+impl<T> Iterator for NetlistStream<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.receiver.as_ref()?.recv().ok()
+    }
+}
+
+impl<T> Drop for NetlistStream<T> {
+    fn drop(&mut self) {
+        // Closing the channel makes the worker stop at its next match
+        self.receiver.take();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
 /// Extracts a physical cell ID from a netlist value.
 fn value_to_cell_id(value: &Value) -> Option<u32> {
     value
@@ -13,6 +54,51 @@ fn value_to_cell_id(value: &Value) -> Option<u32> {
         .and_then(|net| net.as_cell_index().map(|idx| idx as u32).ok())
 }
 
+/// A column predicate applied to netlist matches during enumeration.
+///
+/// Filters are evaluated on each resolved row before deduplication, so a
+/// constrained netlist pattern never materializes rejected matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanFilter {
+    /// Both ports must resolve to the same wire.
+    Equal(&'static str, &'static str),
+    /// The ports must resolve to different wires.
+    Distinct(&'static str, &'static str),
+    /// The port must be driven by a haystack cell of the given kind.
+    DrivenBy(&'static str, CellKind),
+}
+
+impl ScanFilter {
+    /// Evaluates the filter against a resolved row.
+    ///
+    /// Rows whose referenced ports are unknown or unassigned are rejected.
+    #[must_use]
+    pub fn accepts(
+        &self,
+        schema: &PatternSchema,
+        entries: &EntryArray,
+        haystack_index: &GraphIndex<'_>,
+    ) -> bool {
+        let wire = |port: &str| {
+            schema
+                .index_of(port)
+                .and_then(|idx| match entries.entries.get(idx) {
+                    Some(ColumnEntry::Wire(wire)) => Some(wire),
+                    _ => None,
+                })
+        };
+
+        match self {
+            Self::Equal(a, b) => matches!((wire(a), wire(b)), (Some(a), Some(b)) if a == b),
+            Self::Distinct(a, b) => matches!((wire(a), wire(b)), (Some(a), Some(b)) if a != b),
+            Self::DrivenBy(port, kind) => wire(port)
+                .and_then(Wire::cell_id)
+                .and_then(|id| haystack_index.get_cell_by_id(id.storage_key() as usize))
+                .is_some_and(|cell| cell.cell_type() == *kind),
+        }
+    }
+}
+
 /// Trait for netlist-based pattern components.
 ///
 /// Implemented by types generated with `#[netlist]`. Provides access to
@@ -21,18 +107,22 @@ pub trait Netlist: Sized + Component<Kind = kind::Netlist> + Send + Sync + 'stat
     /// The module name within the source file.
     const MODULE_NAME: &'static str;
 
-    /// Path to the netlist source file (.v, .il, or .json).
+    /// Path to the netlist source file (.v, .il, .json, .blif, or .edif).
     const FILE_PATH: &'static str;
 
     /// Port declarations (macro-generated)
     const PORTS: &'static [PortDecl];
 
+    /// Column predicates applied during enumeration (macro-generated)
+    const FILTERS: &'static [ScanFilter] = &[];
+
     /// Schema accessor (macro generates this with `OnceLock` pattern)
     fn netlist_schema() -> &'static crate::session::PatternSchema {
         static SCHEMA: std::sync::OnceLock<crate::session::PatternSchema> =
             std::sync::OnceLock::new();
         SCHEMA.get_or_init(|| {
             let mut defs = Self::ports_to_defs();
+            defs.push(ColumnDef::meta(VERIFIED_COLUMN));
 
             // Load needle design to discover internal cells
             let result = std::panic::catch_unwind(|| Self::discover_internal_cells());
@@ -88,7 +178,7 @@ pub trait Netlist: Sized + Component<Kind = kind::Netlist> + Send + Sync + 'stat
     fn discover_internal_cells() -> Result<Vec<ColumnDef>, Box<dyn std::error::Error>> {
         let ym = YosysModule::new(Self::FILE_PATH, Self::MODULE_NAME)?;
 
-        let yosys = match which::which("yosys") {
+        let yosys = match svql_common::find_yosys_binary() {
             Ok(path) => path,
             Err(_) => {
                 tracing::debug!(
@@ -147,14 +237,30 @@ pub trait Netlist: Sized + Component<Kind = kind::Netlist> + Send + Sync + 'stat
     }
 
     /// Resolves a hardware assignment into a columnar row entry.
-    #[must_use]
+    ///
+    /// # Errors
+    ///
+    /// Returns `QueryError::MissingPort` if a needle port has no column in
+    /// the schema, listing the port columns that do exist, and a
+    /// `QueryError::SubgraphMatch` if a needle output has no mapped driver.
     fn resolve(
         assignment: &SingleAssignment,
         needle_index: &GraphIndex<'_>,
         haystack_index: &GraphIndex<'_>,
-    ) -> EntryArray {
+    ) -> Result<EntryArray, QueryError> {
         let schema = Self::netlist_schema();
         let mut entries = vec![ColumnEntry::Null; schema.defs.len()];
+        let port_column = |name: &str| {
+            schema.index_of(name).ok_or_else(|| {
+                let available = schema
+                    .inputs
+                    .iter()
+                    .chain(&schema.outputs)
+                    .map(|&idx| schema.column(idx).name.to_string())
+                    .collect();
+                QueryError::missing_port(Self::MODULE_NAME, name, available)
+            })
+        };
 
         for (n_node, h_node) in assignment.needle_mapping() {
             let needle_wrapper = needle_index.get_cell_by_index(*n_node);
@@ -164,13 +270,18 @@ pub trait Netlist: Sized + Component<Kind = kind::Netlist> + Send + Sync + 'stat
 
             match needle_wrapper.get().as_ref() {
                 prjunnamed_netlist::Cell::Input(name, _) => {
-                    let col_idx = schema.index_of(name).expect("Input name in schema");
+                    let col_idx = port_column(name)?;
                     entries[col_idx] = ColumnEntry::Wire(Wire::from(haystack_physical));
                 }
                 prjunnamed_netlist::Cell::Output(name, output_value) => {
-                    let col_idx = schema.index_of(name).expect("Output name in schema");
+                    let col_idx = port_column(name)?;
                     let needle_output_driver_id: u32 =
-                        value_to_cell_id(output_value).expect("Output should have driver");
+                        value_to_cell_id(output_value).ok_or_else(|| {
+                            QueryError::subgraph_match(format!(
+                                "output `{name}` of {} has no driving cell",
+                                Self::MODULE_NAME
+                            ))
+                        })?;
                     let haystack_output_driver = assignment
                         .needle_mapping()
                         .iter()
@@ -182,7 +293,12 @@ pub trait Netlist: Sized + Component<Kind = kind::Netlist> + Send + Sync + 'stat
                                 == needle_output_driver_id
                         })
                         .map(|(_n_idx, h_idx)| h_idx)
-                        .expect("Should find haystack driver for output");
+                        .ok_or_else(|| {
+                            QueryError::subgraph_match(format!(
+                                "driver of output `{name}` of {} is not in the assignment",
+                                Self::MODULE_NAME
+                            ))
+                        })?;
 
                     entries[col_idx] = ColumnEntry::Wire(Wire::from(
                         haystack_index.resolve_physical(*haystack_output_driver),
@@ -205,7 +321,7 @@ pub trait Netlist: Sized + Component<Kind = kind::Netlist> + Send + Sync + 'stat
                 }
             }
         }
-        EntryArray::new(entries)
+        Ok(EntryArray::new(entries))
     }
 
     /// Rehydrates a storage row into a concrete Pattern struct.
@@ -312,10 +428,14 @@ pub trait Netlist: Sized + Component<Kind = kind::Netlist> + Send + Sync + 'stat
             });
         }
 
+        let verdict = row
+            .meta(VERIFIED_COLUMN)
+            .and_then(|meta| meta.as_verdict());
+
         ReportNode {
             name: short_name.to_string(),
             type_name: type_name.to_string(),
-            details: None,
+            details: verdict.map(|verdict| format!("verified: {verdict}")),
             source_loc: None,
             children,
         }
@@ -351,8 +471,8 @@ where
     where
         Self: Sized,
     {
-        driver.preload_design(&Self::driver_key(), &config.needle_options)?;
-        driver.preload_design(design_key, &config.haystack_options)?;
+        driver.preload_design_with(&Self::driver_key(), &config.needle_options, config.parallel)?;
+        driver.preload_design_with(design_key, &config.haystack_options, config.parallel)?;
         Ok(())
     }
 
@@ -379,11 +499,13 @@ where
             .map_err(|e| QueryError::needle_load(e.to_string()))?;
         tracing::debug!("[NETLIST] Needle design loaded");
 
+        validate_ports::<Self>(needle_container.index())?;
+
         let haystack_container = ctx.haystack_design();
         tracing::debug!("[NETLIST] Using cached haystack design");
 
         tracing::info!("[NETLIST] Starting subgraph matching...");
-        let assignments = subgraph::SubgraphMatcher::enumerate_with_indices(
+        let mut matcher = subgraph::SubgraphMatcherCore::new(
             needle_container.design(),
             haystack_container.design(),
             needle_container.index(),
@@ -392,31 +514,62 @@ where
             haystack_key.module_name().to_string(),
             ctx.config(),
         );
+        if let Some(progress) = ctx.progress() {
+            matcher = matcher.with_progress(std::sync::Arc::clone(progress));
+        }
+        let assignments = matcher.enumerate_assignments();
         tracing::info!(
             "[NETLIST] Subgraph matching complete: {} assignments found",
             assignments.items.len()
         );
 
         tracing::debug!("[NETLIST] Resolving assignments to table rows...");
+        let verified_column = Self::netlist_schema()
+            .index_of(VERIFIED_COLUMN)
+            .filter(|_| ctx.config().verify_functional);
         let mut row_matches: Vec<EntryArray> = assignments
             .items
             .iter()
             .map(|assignment| {
-                Self::resolve(
+                let mut entries = Self::resolve(
                     assignment,
                     needle_container.index(),
                     haystack_container.index(),
-                )
+                )?;
+                if let Some(col_idx) = verified_column {
+                    let verdict = subgraph::verify_match_functional(
+                        assignment,
+                        needle_container.index(),
+                        haystack_container.index(),
+                    );
+                    entries.entries[col_idx] = ColumnEntry::meta(MetaValue::Verdict(verdict));
+                }
+                Ok(entries)
             })
-            .collect();
+            .collect::<Result<_, QueryError>>()?;
         tracing::debug!(
             "[NETLIST] {} rows created from assignments",
             row_matches.len()
         );
 
+        if !Self::FILTERS.is_empty() {
+            let schema = Self::netlist_schema();
+            let before_filter = row_matches.len();
+            row_matches.retain(|entries| {
+                Self::FILTERS
+                    .iter()
+                    .all(|filter| filter.accepts(schema, entries, haystack_container.index()))
+            });
+            tracing::debug!(
+                "[NETLIST] Scan filters: {} -> {} rows",
+                before_filter,
+                row_matches.len()
+            );
+        }
+
         // Apply automatic row-level deduplication
         let before_dedup = row_matches.len();
-        crate::traits::apply_deduplication(&mut row_matches);
+        crate::traits::apply_deduplication(&mut row_matches, &Self::netlist_schema().outputs, ctx);
         if before_dedup != row_matches.len() {
             tracing::debug!(
                 "[NETLIST] Deduplication: {} -> {} rows ({} removed)",
@@ -426,7 +579,7 @@ where
             );
         }
 
-        let table = Table::<Self>::new(row_matches)?;
+        let table = Table::<Self>::new(row_matches)?.with_truncated(assignments.truncated);
         tracing::info!(
             "[NETLIST] Netlist search complete: {} total matches",
             table.len()
@@ -434,6 +587,117 @@ where
         Ok(table)
     }
 
+    fn internal_search_rehydrated<'a>(
+        driver: &'a Driver,
+        key: &'a DriverKey,
+        config: &'a svql_common::Config,
+        progress: Option<subgraph::ProgressFn>,
+    ) -> Result<Box<dyn Iterator<Item = Self> + 'a>, QueryError>
+    where
+        Self: Pattern + Component + 'static,
+    {
+        if let Some(progress) = &progress {
+            progress(subgraph::ProgressEvent::Indexing);
+        }
+        let needle_key = Self::driver_key();
+        let needle = driver
+            .get_design_with(&needle_key, &config.needle_options, config.parallel)
+            .map_err(|e| QueryError::needle_load(e.to_string()))?;
+        let haystack = driver
+            .get_design_with(key, &config.haystack_options, config.parallel)
+            .map_err(|e| QueryError::design_load(e.to_string()))?;
+        validate_ports::<Self>(needle.index())?;
+
+        // The matcher borrows both designs, so it runs on a worker thread
+        // that owns them. The rendezvous channel lets it find only one match
+        // ahead of the consumer.
+        let (sender, receiver) = std::sync::mpsc::sync_channel(0);
+        let (driver, key, config) = (driver.clone(), key.clone(), config.clone());
+        let worker = std::thread::spawn(move || {
+            let mut matcher = subgraph::SubgraphMatcherCore::new(
+                needle.design(),
+                haystack.design(),
+                needle.index(),
+                haystack.index(),
+                needle_key.module_name().to_string(),
+                key.module_name().to_string(),
+                &config,
+            );
+            if let Some(progress) = progress {
+                matcher = matcher.with_progress(progress);
+            }
+
+            let schema = Self::netlist_schema();
+            let verified_column = schema
+                .index_of(VERIFIED_COLUMN)
+                .filter(|_| config.verify_functional);
+            let mut seen = HashSet::new();
+            let mut seen_outputs = HashSet::new();
+            for assignment in subgraph::AssignmentIter::new(matcher) {
+                let mut entries = match Self::resolve(&assignment, needle.index(), haystack.index())
+                {
+                    Ok(entries) => entries,
+                    Err(e) => {
+                        tracing::warn!("[NETLIST] Stopping {} stream: {e}", Self::MODULE_NAME);
+                        return;
+                    }
+                };
+                if let Some(col_idx) = verified_column {
+                    let verdict = subgraph::verify_match_functional(
+                        &assignment,
+                        needle.index(),
+                        haystack.index(),
+                    );
+                    entries.entries[col_idx] = ColumnEntry::meta(MetaValue::Verdict(verdict));
+                }
+
+                // Same filters and deduplication as `search_table`, one row at a time
+                if !Self::FILTERS
+                    .iter()
+                    .all(|filter| filter.accepts(schema, &entries, haystack.index()))
+                {
+                    continue;
+                }
+                if config.dedupe.collapses_rows() {
+                    if !seen.insert(entries.signature()) {
+                        continue;
+                    }
+                    if config.dedupe.collapses_output_drivers() && !schema.outputs.is_empty() {
+                        let outputs: Vec<ColumnEntry> = schema
+                            .outputs
+                            .iter()
+                            .map(|&idx| entries.entries[idx].clone())
+                            .collect();
+                        if !seen_outputs.insert(outputs) {
+                            continue;
+                        }
+                    }
+                }
+
+                let Some(row) = Table::<Self>::new(vec![entries])
+                    .ok()
+                    .and_then(|table| table.row_at(0))
+                else {
+                    continue;
+                };
+                let Some(item) =
+                    Self::netlist_rehydrate(&row, &Store::new(), &driver, &key, &config)
+                else {
+                    continue;
+                };
+                if sender.send(item).is_err() {
+                    // The consumer dropped the iterator
+                    return;
+                }
+            }
+        });
+
+        Ok(Box::new(NetlistStream {
+            receiver: Some(receiver),
+            worker: Some(worker),
+        }))
+    }
+
     fn internal_rehydrate<'a>(
         row: &Row<Self>,
         store: &Store,
@@ -464,9 +728,23 @@ pub(crate) mod test {
 
     use crate::{Wire, prelude::PortDecl};
 
-    use super::{Component, Driver, DriverKey, Netlist, Port, Row, Store, kind};
+    use super::{
+        CellKind, Component, Driver, DriverKey, Netlist, Pattern, Port, Row, ScanFilter, Store,
+        kind,
+    };
+
+    use std::collections::HashSet;
 
+    use svql_common::{Config, ModuleConfig};
     use svql_query::query_test;
+    use svql_subgraph::{ProgressEvent, SubgraphMatcher};
+
+    use crate::dsl::analysis::driver_cell;
+    use crate::session::QueryError;
+    use crate::test_harness::small_and_tree;
+    use crate::{
+        patterns_equivalent, run_query_filtered, run_query_rehydrated, run_query_with_progress,
+    };
 
     #[derive(Debug, Clone, Netlist)]
     #[netlist(
@@ -486,6 +764,47 @@ pub(crate) mod test {
         pub y: Wire,
     }
 
+    #[derive(Debug, Clone, Netlist)]
+    #[netlist(
+        file = "examples/fixtures/basic/and/verilog/and_gate.v",
+        module = "and_gate"
+    )]
+    /// [`AndGate`] with its inputs bound under different field names.
+    pub struct RenamedAndGate {
+        /// First input, wired to `a`.
+        #[port(input, rename = "a")]
+        pub left: Wire,
+        /// Second input, wired to `b`.
+        #[port(input, rename = "b")]
+        pub right: Wire,
+        /// Output.
+        #[port(output)]
+        pub y: Wire,
+    }
+
+    #[test]
+    fn test_rename_binds_renamed_wire() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(
+            RenamedAndGate::PORTS
+                .iter()
+                .map(|port| port.name)
+                .collect::<Vec<_>>(),
+            ["a", "b", "y"]
+        );
+
+        let (driver, key) = small_and_tree()?;
+        let config = Config::default();
+        let expected: HashSet<_> = run_query_rehydrated::<AndGate>(&driver, &key, &config)?
+            .map(|gate| (gate.a, gate.b, gate.y))
+            .collect();
+        let renamed: HashSet<_> = run_query_rehydrated::<RenamedAndGate>(&driver, &key, &config)?
+            .map(|gate| (gate.left, gate.right, gate.y))
+            .collect();
+        assert_eq!(expected.len(), 3);
+        assert_eq!(renamed, expected);
+        Ok(())
+    }
+
     query_test!(
         name: test_and_mixed_and_tree,
         query: AndGate,
@@ -493,6 +812,39 @@ pub(crate) mod test {
         expect: 3  // Automatically deduplicated
     );
 
+    #[derive(Debug, Clone, Netlist)]
+    #[netlist(
+        file = "examples/fixtures/basic/and/verilog/and_gate.v",
+        module = "and_gate"
+    )]
+    #[scan_filter(ScanFilter::DrivenBy("a", CellKind::Input))]
+    /// An AND gate whose first input comes straight from a primary input.
+    pub struct LeafAndGate {
+        /// First input.
+        #[port(input)]
+        pub a: Wire,
+        /// Second input.
+        #[port(input)]
+        pub b: Wire,
+        /// Output.
+        #[port(output)]
+        pub y: Wire,
+    }
+
+    query_test!(
+        name: test_and_gate_unfiltered_small_tree,
+        query: AndGate,
+        haystack: ("examples/fixtures/basic/and/verilog/small_and_tree.v", "small_and_tree"),
+        expect: 3
+    );
+
+    query_test!(
+        name: test_scan_filter_small_tree,
+        query: LeafAndGate,
+        haystack: ("examples/fixtures/basic/and/verilog/small_and_tree.v", "small_and_tree"),
+        expect: 2
+    );
+
     // --- Reference Implementation (Manual) ---
 
     #[derive(Debug, Clone)]
@@ -539,6 +891,185 @@ pub(crate) mod test {
         name: test_manual_and_gate_small_tree,
         query: ManualAndGate,
         haystack: ("examples/fixtures/basic/and/verilog/small_and_tree.v", "small_and_tree"),
-        expect: 3  // With default Dedupe::Inner, identical rows are deduplicated
+        expect: 3  // With default Dedupe::All, identical rows are deduplicated
     );
+
+    #[derive(Debug, Clone, Netlist)]
+    #[netlist(
+        file = "examples/fixtures/basic/and/verilog/and_gate.v",
+        module = "and_gate"
+    )]
+    /// An AND gate pattern that leaves the needle's `b` input undeclared.
+    pub struct PartialAndGate {
+        /// First input.
+        #[port(input)]
+        pub a: Wire,
+        /// Output.
+        #[port(output)]
+        pub y: Wire,
+    }
+
+    #[test]
+    fn test_resolve_reports_missing_port() -> Result<(), Box<dyn std::error::Error>> {
+        let (driver, key) = small_and_tree()?;
+        let config = Config::default();
+        let needle = driver.get_design(&PartialAndGate::driver_key(), &config.needle_options)?;
+        let haystack = driver.get_design(&key, &config.haystack_options)?;
+
+        let assignments = SubgraphMatcher::enumerate_with_indices(
+            needle.design(),
+            haystack.design(),
+            needle.index(),
+            haystack.index(),
+            PartialAndGate::MODULE_NAME.to_string(),
+            key.module_name().to_string(),
+            &config,
+        );
+        let assignment = assignments.items.first().ok_or("No matches found")?;
+
+        let Err(QueryError::MissingPort {
+            module,
+            port,
+            available,
+        }) = PartialAndGate::resolve(assignment, needle.index(), haystack.index())
+        else {
+            return Err("Resolving an undeclared port should fail with MissingPort".into());
+        };
+        assert_eq!(module, "and_gate");
+        assert_eq!(port, "b");
+        assert_eq!(available, ["a", "y"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_query_filtered_top_level_input() -> Result<(), Box<dyn std::error::Error>> {
+        let (driver, key) = small_and_tree()?;
+        let config = Config::default();
+        let design = driver.get_design(&key, &config.haystack_options)?;
+
+        // Only gates whose `a` input comes straight from a module port
+        let store = run_query_filtered::<AndGate>(&driver, &key, &config, |row| {
+            row.wire("a")
+                .and_then(|wire| driver_cell(wire, design.index()))
+                .is_some_and(|cell| design.index().node_kind(cell).is_input())
+        })?;
+
+        assert_eq!(store.get::<AndGate>().ok_or("Table not found")?.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_query_rehydrated_yields_matches() -> Result<(), Box<dyn std::error::Error>> {
+        let (driver, key) = small_and_tree()?;
+        let config = Config::default();
+
+        assert_eq!(
+            run_query_rehydrated::<AndGate>(&driver, &key, &config)?.count(),
+            3
+        );
+        assert_eq!(
+            run_query_rehydrated::<AndGate>(&driver, &key, &config)?
+                .take(1)
+                .count(),
+            1
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_rehydrated_stops_early() -> Result<(), Box<dyn std::error::Error>> {
+        let (driver, key) = small_and_tree()?;
+        let config = Config::default();
+        let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = std::sync::Arc::clone(&events);
+
+        let first: Vec<AndGate> = AndGate::search_rehydrated(
+            &driver,
+            &key,
+            &config,
+            Some(std::sync::Arc::new(move |event| {
+                sink.lock().unwrap().push(event);
+            })),
+        )?
+        .take(1)
+        .collect();
+        assert_eq!(first.len(), 1);
+        // Dropping the iterator stopped the search before it was exhausted
+        assert!(
+            !events
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|event| matches!(event, ProgressEvent::Finished { .. }))
+        );
+
+        let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = std::sync::Arc::clone(&events);
+        let all = AndGate::search_rehydrated(
+            &driver,
+            &key,
+            &config,
+            Some(std::sync::Arc::new(move |event| {
+                sink.lock().unwrap().push(event);
+            })),
+        )?
+        .count();
+        assert_eq!(all, 3);
+        assert_eq!(
+            events.lock().unwrap().last(),
+            Some(&ProgressEvent::Finished { matches: 3 })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_query_with_progress() -> Result<(), Box<dyn std::error::Error>> {
+        let (driver, key) = small_and_tree()?;
+        let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = std::sync::Arc::clone(&events);
+        let store = run_query_with_progress::<AndGate>(
+            &driver,
+            &key,
+            &Config::default(),
+            std::sync::Arc::new(move |event| sink.lock().unwrap().push(event)),
+        )?;
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.first(), Some(&ProgressEvent::Indexing));
+        assert_eq!(
+            events.last(),
+            Some(&ProgressEvent::Finished {
+                matches: store.get::<AndGate>().ok_or("Table not found")?.len()
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_patterns_equivalent() -> Result<(), Box<dyn std::error::Error>> {
+        let driver = Driver::new_workspace()?;
+        let options = ModuleConfig::default();
+        let and_gate = driver.get_design(
+            &DriverKey::new("examples/patterns/basic/and/verilog/and_gate.v", "and_gate"),
+            &options,
+        )?;
+        let or_gate = driver.get_design(
+            &DriverKey::new("examples/patterns/basic/or/verilog/or_gate.v", "or_gate"),
+            &options,
+        )?;
+
+        let reordered = driver.get_design(
+            &DriverKey::new(
+                "examples/patterns/basic/and/verilog/and_gate_reordered.v",
+                "and_gate_reordered",
+            ),
+            &options,
+        )?;
+
+        assert!(patterns_equivalent(and_gate.design(), and_gate.design()));
+        // Same gate under another module name, with its ports declared in a different order
+        assert!(patterns_equivalent(and_gate.design(), reordered.design()));
+        assert!(!patterns_equivalent(and_gate.design(), or_gate.design()));
+        Ok(())
+    }
 }
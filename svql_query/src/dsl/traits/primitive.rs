@@ -157,7 +157,7 @@ where
         Self: Sized,
     {
         // Primitives only need the haystack design loaded
-        driver.preload_design(design_key, &config.haystack_options)?;
+        driver.preload_design_with(design_key, &config.haystack_options, config.parallel)?;
         Ok(())
     }
 
@@ -34,6 +34,17 @@ pub trait Variant: Sized + Component<Kind = kind::Variant> + Send + Sync + 'stat
     /// Dependencies (macro-generated)
     const DEPENDANCIES: &'static [&'static ExecInfo];
 
+    /// Merge rows from different arms that expose identical common-port wires.
+    ///
+    /// With the default (`false`) the variant behaves like SQL `UNION ALL`;
+    /// setting it gives `UNION` semantics, keeping the row from the first arm.
+    const DISTINCT: bool = false;
+
+    /// Custom validation hook applied to rows from every arm (override to add filtering logic)
+    fn validate_custom(_row: &Row<Self>, _ctx: &ExecutionContext) -> bool {
+        true // Default: no custom filtering
+    }
+
     /// Schema accessor (macro generates this with `OnceLock` pattern)
     fn variant_schema() -> &'static crate::session::PatternSchema {
         static SCHEMA: std::sync::OnceLock<crate::session::PatternSchema> =
@@ -62,6 +73,36 @@ pub trait Variant: Sized + Component<Kind = kind::Variant> + Send + Sync + 'stat
         defs
     }
 
+    /// Check that every arm maps each common port.
+    ///
+    /// # Errors
+    ///
+    /// Returns `QueryError::SchemaMismatch` if an arm lacks a port mapping.
+    fn check_arm_compatibility() -> Result<(), QueryError> {
+        if Self::PORT_MAPPINGS.len() != Self::NUM_VARIANTS {
+            return Err(QueryError::SchemaMismatch {
+                expected: format!("{} port mapping arms", Self::NUM_VARIANTS),
+                actual: format!("{} arms", Self::PORT_MAPPINGS.len()),
+            });
+        }
+
+        for (arm, port_maps) in Self::VARIANT_ARMS.iter().zip(Self::PORT_MAPPINGS) {
+            for port in Self::COMMON_PORTS {
+                if !port_maps.iter().any(|m| m.common_port == port.name) {
+                    return Err(QueryError::SchemaMismatch {
+                        expected: format!(
+                            "mapping for port `{}` in arm {}",
+                            port.name, arm.type_name
+                        ),
+                        actual: "no mapping".to_string(),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Concatenate results from all variant arms into a unified table.
     ///
     /// This is the core operation for variants - it unions the results from
@@ -71,6 +112,7 @@ pub trait Variant: Sized + Component<Kind = kind::Variant> + Send + Sync + 'stat
     /// # Errors
     ///
     /// Returns a `QueryError` if:
+    /// * An arm does not map every common port.
     /// * The variant schema is missing critical column definitions (e.g., "discriminant").
     /// * A variant arm's port mapping fails to resolve.
     fn concatenate(
@@ -82,6 +124,8 @@ pub trait Variant: Sized + Component<Kind = kind::Variant> + Send + Sync + 'stat
             std::any::type_name::<Self>()
         );
 
+        Self::check_arm_compatibility()?;
+
         let schema = Self::variant_schema();
         let mut all_entries = Vec::new();
 
@@ -140,6 +184,12 @@ pub trait Variant: Sized + Component<Kind = kind::Variant> + Send + Sync + 'stat
                     }
                 }
 
+                // 4. Apply the shared post-filter
+                let row = Row::<Self>::from_parts(RowIndex::from_raw(0), entry.clone());
+                if !Self::validate_custom(&row, ctx) {
+                    continue;
+                }
+
                 all_entries.push(entry);
             }
 
@@ -152,7 +202,7 @@ pub trait Variant: Sized + Component<Kind = kind::Variant> + Send + Sync + 'stat
 
         // Apply automatic deduplication
         let before_dedup = all_entries.len();
-        Self::apply_deduplication(&mut all_entries);
+        Self::apply_deduplication(&mut all_entries, ctx);
         if before_dedup != all_entries.len() {
             tracing::debug!(
                 "[VARIANT] Deduplication: {} -> {} entries ({} removed)",
@@ -162,6 +212,27 @@ pub trait Variant: Sized + Component<Kind = kind::Variant> + Send + Sync + 'stat
             );
         }
 
+        if Self::DISTINCT {
+            let before_distinct = all_entries.len();
+            let port_cols: Vec<usize> = Self::COMMON_PORTS
+                .iter()
+                .filter_map(|p| schema.index_of(p.name))
+                .collect();
+            let mut seen = HashSet::new();
+            all_entries.retain(|entry| {
+                let key: Vec<ColumnEntry> = port_cols
+                    .iter()
+                    .map(|&idx| entry.entries[idx].clone())
+                    .collect();
+                seen.insert(key)
+            });
+            tracing::debug!(
+                "[VARIANT] Distinct: {} -> {} entries",
+                before_distinct,
+                all_entries.len()
+            );
+        }
+
         tracing::info!(
             "[VARIANT] Variant concatenation complete: {} total matches",
             all_entries.len()
@@ -170,8 +241,8 @@ pub trait Variant: Sized + Component<Kind = kind::Variant> + Send + Sync + 'stat
     }
 
     /// Apply automatic deduplication.
-    fn apply_deduplication(entries: &mut Vec<EntryArray>) {
-        crate::traits::apply_deduplication(entries);
+    fn apply_deduplication(entries: &mut Vec<EntryArray>, ctx: &ExecutionContext) {
+        crate::traits::apply_deduplication(entries, &Self::variant_schema().outputs, ctx);
     }
 
     /// Pre-loads all possible variant designs into the driver.
@@ -300,7 +371,8 @@ where
         }
 
         // 2. Concatenate results from all arms
-        T::concatenate(ctx, &dep_tables)
+        let truncated = dep_tables.iter().any(|table| table.is_truncated());
+        Ok(T::concatenate(ctx, &dep_tables)?.with_truncated(truncated))
     }
 
     fn internal_rehydrate<'a>(
@@ -339,11 +411,12 @@ mod test {
     };
 
     use super::{
-        Component, Driver, DriverKey, Port, PortMap, Row, Store, Variant, VariantArm, kind,
+        Component, Driver, DriverKey, ExecutionContext, Port, PortMap, Row, Store, Variant,
+        VariantArm, driver_cell, kind,
     };
 
     use crate::traits::composite::test::And2Gates;
-    use crate::traits::netlist::test::AndGate;
+    use crate::traits::netlist::test::{AndGate, ManualAndGate};
 
     use svql_common::Selector;
     use svql_query::query_test;
@@ -367,6 +440,93 @@ mod test {
         expect: 5  // 3 AndGate + 2 And2Gates
     );
 
+    #[test]
+    fn test_variant_port_cells() -> Result<(), Box<dyn std::error::Error>> {
+        let driver = Driver::new_workspace()?;
+        let config = svql_common::Config::builder().build();
+        let key = DriverKey::new(
+            "examples/fixtures/basic/and/verilog/small_and_tree.v",
+            "small_and_tree",
+        );
+
+        let store = svql_query::run_query::<AndOrAnd2>(&driver, &key, &config)?;
+        let design = driver.get_design(&key, &config.haystack_options)?;
+        let table = store.get::<AndOrAnd2>().expect("Table should exist");
+
+        for (_, row) in table.rows() {
+            let cell = AndOrAnd2::y_cell(&row, design.index()).expect("Output should resolve");
+            assert!(cell.cell_type().is_logic_gate());
+        }
+        Ok(())
+    }
+
+    /// The same AND gate reached through two arms.
+    #[derive(Debug, Clone, Variant)]
+    #[variant_ports(input(a), input(b), output(y))]
+    pub enum AndTwice {
+        /// Derived netlist arm.
+        #[map(a = ["a"], b = ["b"], y = ["y"])]
+        Derived(AndGate),
+        /// Manually implemented netlist arm.
+        #[map(a = ["a"], b = ["b"], y = ["y"])]
+        Manual(ManualAndGate),
+    }
+
+    /// Keeps rows whose `a` port is driven directly by a module input.
+    fn a_from_input(row: &Row<AndOrAnd2Leaf>, ctx: &ExecutionContext) -> bool {
+        let index = ctx.haystack_design().index();
+        row.wire("a")
+            .and_then(|wire| driver_cell(wire, index))
+            .is_some_and(|cell| index.node_kind(cell).is_input())
+    }
+
+    /// `AndOrAnd2` restricted by a filter shared across both arms.
+    #[derive(Debug, Clone, Variant)]
+    #[variant_ports(input(a), input(b), output(y))]
+    #[filter(a_from_input)]
+    pub enum AndOrAnd2Leaf {
+        /// Direct match on a single AND gate.
+        #[map(a = ["a"], b = ["b"], y = ["y"])]
+        AndGate(AndGate),
+        /// Match on a composite structure of two AND gates.
+        #[map(a = ["a"], b = ["b"], y = ["y"])]
+        And2Gates(And2Gates),
+    }
+
+    query_test!(
+        name: test_variant_filter_small_tree,
+        query: AndOrAnd2Leaf,
+        haystack: ("examples/fixtures/basic/and/verilog/small_and_tree.v", "small_and_tree"),
+        expect: 4  // 2 AndGate + 2 And2Gates
+    );
+
+    query_test!(
+        name: test_variant_union_all_small_tree,
+        query: AndTwice,
+        haystack: ("examples/fixtures/basic/and/verilog/small_and_tree.v", "small_and_tree"),
+        expect: 6
+    );
+
+    /// The same AND gate reached through two arms, merged by `#[distinct]`.
+    #[derive(Debug, Clone, Variant)]
+    #[variant_ports(input(a), input(b), output(y))]
+    #[distinct]
+    pub enum AndTwiceDistinct {
+        /// Derived netlist arm.
+        #[map(a = ["a"], b = ["b"], y = ["y"])]
+        Derived(AndGate),
+        /// Manually implemented netlist arm.
+        #[map(a = ["a"], b = ["b"], y = ["y"])]
+        Manual(ManualAndGate),
+    }
+
+    query_test!(
+        name: test_variant_distinct_small_tree,
+        query: AndTwiceDistinct,
+        haystack: ("examples/fixtures/basic/and/verilog/small_and_tree.v", "small_and_tree"),
+        expect: 3
+    );
+
     /// Manually implemented variant for testing.
     #[derive(Debug, Clone)]
     pub enum ManualAndOrAnd2 {
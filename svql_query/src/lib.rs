@@ -2,6 +2,7 @@
 //!
 //! The `svql_query` crate provides:
 //! - **DSL**: Trait definitions for patterns (`Netlist`, `Composite`, `Primitive`, `Variant`, `Recursive`)
+//! - **Report**: Machine-readable exports of match reports (e.g. SARIF)
 //! - **Session**: Result storage with columnar tables, schemas, and execution planning
 //! - **Pattern Search**: Entry point via `run_query()` for executing pattern matches
 //!
@@ -21,6 +22,7 @@ extern crate self as svql_query;
 pub mod dsl;
 /// Common types and re-exports for building queries.
 pub mod prelude;
+pub mod report;
 pub mod session;
 
 /// Testing utilities and fixture orchestration.
@@ -74,3 +76,113 @@ where
 {
     P::search(driver, key, config).map_err(|err| Box::new(err) as Box<dyn std::error::Error>)
 }
+
+/// Executes a pattern query, reporting coarse progress to `progress`.
+///
+/// Behaves like [`run_query`], but `progress` receives the phases of the
+/// search (indexing, anchoring, enumerating) and periodic counts, so callers
+/// can drive a progress indicator.
+///
+/// # Errors
+///
+/// Returns an error if the query execution fails.
+pub fn run_query_with_progress<P>(
+    driver: &Driver,
+    key: &DriverKey,
+    config: &svql_common::Config,
+    progress: subgraph::ProgressFn,
+) -> Result<session::Store, Box<dyn std::error::Error>>
+where
+    P: Pattern + Send + Sync + 'static,
+{
+    P::search_with_progress(driver, key, config, Some(progress))
+        .map_err(|err| Box::new(err) as Box<dyn std::error::Error>)
+}
+
+/// Executes a pattern query and keeps only the matches accepted by `predicate`.
+///
+/// Runs [`run_query`] and then rebuilds the table for `P` from the rows for
+/// which `predicate` returns true, so one-off conditions can be applied
+/// without defining a new composite. Tables of sub-patterns are left intact,
+/// so submodule references in the kept rows stay valid.
+///
+/// # Errors
+///
+/// Returns an error if the query execution fails.
+pub fn run_query_filtered<P>(
+    driver: &Driver,
+    key: &DriverKey,
+    config: &svql_common::Config,
+    predicate: impl Fn(&session::Row<P>) -> bool,
+) -> Result<session::Store, Box<dyn std::error::Error>>
+where
+    P: Pattern + Component + Send + Sync + 'static,
+{
+    let mut store = run_query::<P>(driver, key, config)?;
+    if let Some(table) = store.get::<P>() {
+        let kept: Vec<session::EntryArray> = table
+            .rows()
+            .filter(|(_, row)| predicate(row))
+            .map(|(_, row)| row.entry_array().clone())
+            .collect();
+        let truncated = table.is_truncated();
+        store.insert(session::Table::<P>::new(kept)?.with_truncated(truncated));
+    }
+    Ok(store)
+}
+
+/// Executes a pattern query and rehydrates its matches on demand.
+///
+/// Netlist patterns are streamed: the subgraph search runs on a worker
+/// thread and only advances as the iterator is consumed, so callers that
+/// stop early also stop the search. Other pattern kinds still find and store
+/// every match as in [`run_query`] first and only defer rehydration.
+///
+/// # Errors
+///
+/// Returns an error if the query execution fails.
+pub fn run_query_rehydrated<'a, P>(
+    driver: &'a Driver,
+    key: &'a DriverKey,
+    config: &'a svql_common::Config,
+) -> Result<impl Iterator<Item = P> + 'a, Box<dyn std::error::Error>>
+where
+    P: Pattern + Component + Send + Sync + 'static,
+{
+    P::search_rehydrated(driver, key, config, None)
+        .map_err(|err| Box::new(err) as Box<dyn std::error::Error>)
+}
+
+/// Executes a pattern query against a design built in memory.
+///
+/// Registers `design` with the driver under `key` (see
+/// [`Driver::insert_design`]) and then runs [`run_query`] on it, so query
+/// logic can be tested against small hand-built netlists without fixture files.
+///
+/// # Errors
+///
+/// Returns an error if the query execution fails.
+pub fn run_query_on_design<P>(
+    driver: &Driver,
+    key: DriverKey,
+    design: svql_driver::Design,
+    config: &svql_common::Config,
+) -> Result<session::Store, Box<dyn std::error::Error>>
+where
+    P: Pattern + Send + Sync + 'static,
+{
+    let key = driver.insert_design(key, design);
+    run_query::<P>(driver, &key, config)
+}
+
+/// Checks whether two pattern designs are structurally identical.
+///
+/// Uses `prjunnamed_netlist::isomorphic`, so the designs must have the same
+/// cells connected the same way, with matching input and output port names.
+/// Needles that differ only in internal net or cell naming compare equal;
+/// needles that compute the same function with different gates do not.
+#[must_use]
+pub fn patterns_equivalent(a: &svql_driver::Design, b: &svql_driver::Design) -> bool {
+    prjunnamed_netlist::isomorphic(a, b).is_ok()
+}
+
@@ -9,13 +9,16 @@ pub use crate::run_query;
 pub use crate::session::{
     Alias, AnyTable, ColumnDef, ColumnEntry, ColumnKind, EntryArray, ExecInfo, ExecutionContext,
     ExecutionPlan, MetaValue, PatternSchema, PortMap, QueryError, Ref, Row, RowIndex, SearchFn,
-    Store, Submodule, Table, VariantIndex,
+    Store, Submodule, Table, TableView, VariantIndex,
 };
 
 pub use crate::session::execution::TableSlot;
 
+pub use crate::dsl::analysis::{combinational_fanin, driver_cell, fanin_depth};
+
 pub use crate::dsl::traits::{
     Component, Pattern, PatternInternal,
+    composite::Absent,
     composite::Composite,
     composite::Connection,
     composite::Connections,
@@ -0,0 +1,129 @@
+//! Graphviz DOT export for individual matches.
+//!
+//! A match is drawn as the set of haystack cells it touches. Edges are taken
+//! from the haystack fan-out restricted to those cells, and primary input and
+//! output cells are colored so the boundary of the sub-circuit stands out.
+
+use std::fmt::Write;
+
+use crate::prelude::*;
+
+/// Fill color for primary input cells.
+const INPUT_COLOR: &str = "lightblue";
+/// Fill color for primary output cells.
+const OUTPUT_COLOR: &str = "lightsalmon";
+/// Fill color for all other cells.
+const CELL_COLOR: &str = "white";
+
+/// Maps the nets of `wires` to the haystack cells driving them.
+///
+/// Constant bits and nets without a registered cell are skipped.
+#[must_use]
+pub fn wire_cells(wires: &[Wire], haystack: &GraphIndex<'_>) -> HashSet<GraphNodeIdx> {
+    wires
+        .iter()
+        .flat_map(Wire::iter)
+        .filter_map(|net| net.as_cell_index().ok())
+        .filter_map(|idx| haystack.get_cell_index_by_debug_id(idx))
+        .collect()
+}
+
+/// Renders the matched `cells` as a Graphviz digraph called `name`.
+///
+/// Nodes are labeled with their `CellKind` and physical id. Cells from a
+/// `SingleAssignment::haystack_cells` set or from [`wire_cells`] both work.
+#[must_use]
+pub fn cells_to_dot(
+    name: &str,
+    cells: &HashSet<GraphNodeIdx>,
+    haystack: &GraphIndex<'_>,
+) -> String {
+    let mut sorted: Vec<GraphNodeIdx> = cells.iter().copied().collect();
+    sorted.sort_unstable();
+
+    let mut out = String::new();
+    let _ = writeln!(out, "digraph \"{}\" {{", escape(name));
+    let _ = writeln!(out, "    node [shape=box, style=filled];");
+
+    for &node in &sorted {
+        let kind = haystack.node_kind(node);
+        let color = if kind.is_input() {
+            INPUT_COLOR
+        } else if kind.is_output() {
+            OUTPUT_COLOR
+        } else {
+            CELL_COLOR
+        };
+        let _ = writeln!(
+            out,
+            "    {node} [label=\"{kind}\\n{}\", fillcolor={color}];",
+            haystack.resolve_physical(node)
+        );
+    }
+
+    for &node in &sorted {
+        let mut targets: Vec<GraphNodeIdx> = haystack
+            .fanout_set(node)
+            .iter()
+            .copied()
+            .filter(|target| cells.contains(target))
+            .collect();
+        targets.sort_unstable();
+        for target in targets {
+            let _ = writeln!(out, "    {node} -> {target};");
+        }
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+/// Escapes a string for use inside a quoted DOT identifier.
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Netlist)]
+    #[netlist(
+        file = "examples/fixtures/basic/and/verilog/and_gate.v",
+        module = "and_gate"
+    )]
+    pub struct DotAndGate {
+        #[port(input)]
+        pub a: Wire,
+        #[port(input)]
+        pub b: Wire,
+        #[port(output)]
+        pub y: Wire,
+    }
+
+    #[test]
+    fn test_match_to_dot() -> Result<(), Box<dyn std::error::Error>> {
+        let driver = Driver::new_workspace()?;
+        let key = DriverKey::new(
+            "examples/fixtures/basic/and/json/mixed_and_tree.json",
+            "mixed_and_tree",
+        );
+        let config = Config::default();
+
+        let store = crate::run_query::<DotAndGate>(&driver, &key, &config)?;
+        let table = store
+            .get_any(std::any::TypeId::of::<DotAndGate>())
+            .ok_or("Table not found")?;
+        let design = driver.get_design(&key, &config.haystack_options)?;
+
+        let cells = wire_cells(&table.row_wires(0, &store), design.index());
+        assert!(!cells.is_empty());
+
+        let dot = cells_to_dot("DotAndGate", &cells, design.index());
+        assert!(dot.starts_with("digraph \"DotAndGate\" {"));
+        assert!(dot.contains("And"));
+        assert!(dot.trim_end().ends_with('}'));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+//! Triage filters for match reports.
+//!
+//! Filters operate on the [`ReportNode`] trees produced for each match, so they
+//! only see what the report exposes: the pattern type names and node details
+//! such as the `depth: N` reported by recursive tree patterns.
+
+use crate::prelude::*;
+use crate::traits::display::ReportNode;
+
+/// Prefix used by recursive patterns when reporting their tree depth.
+const DEPTH_DETAIL_PREFIX: &str = "depth: ";
+
+/// Predicates selecting which match reports to keep.
+///
+/// An empty filter (the default) keeps every report.
+#[derive(Debug, Clone, Default)]
+pub struct ReportFilter {
+    /// Minimum depth of the deepest recursive tree in the report, e.g. the
+    /// OR tree of a CWE-1234 match. Reports without any tree are dropped.
+    pub min_tree_depth: Option<u32>,
+    /// CWE identifiers to keep, as `1234`, `CWE-1234` or `cwe1234`.
+    /// Matched against the segments of the report's pattern type name.
+    pub cwe_ids: HashSet<String>,
+}
+
+impl ReportFilter {
+    /// Returns true if the filter keeps every report.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.min_tree_depth.is_none() && self.cwe_ids.is_empty()
+    }
+
+    /// Returns true if `report` satisfies every predicate of the filter.
+    #[must_use]
+    pub fn matches(&self, report: &ReportNode) -> bool {
+        let depth_ok = self
+            .min_tree_depth
+            .is_none_or(|min| max_tree_depth(report).is_some_and(|depth| depth >= min));
+
+        let cwe_ok = self.cwe_ids.is_empty() || {
+            let keys: HashSet<String> = self.cwe_ids.iter().map(|id| cwe_key(id)).collect();
+            report
+                .type_name
+                .split("::")
+                .any(|segment| keys.contains(&cwe_key(segment)))
+        };
+
+        depth_ok && cwe_ok
+    }
+}
+
+/// Keeps the reports accepted by `filter`, preserving their order.
+pub fn filter(
+    reports: impl IntoIterator<Item = ReportNode>,
+    filter: &ReportFilter,
+) -> Vec<ReportNode> {
+    reports
+        .into_iter()
+        .filter(|report| filter.matches(report))
+        .collect()
+}
+
+/// Returns the largest `depth: N` detail found anywhere in the report tree.
+fn max_tree_depth(node: &ReportNode) -> Option<u32> {
+    let own = node
+        .details
+        .as_deref()
+        .and_then(|details| details.strip_prefix(DEPTH_DETAIL_PREFIX))
+        .and_then(|depth| depth.parse().ok());
+
+    node.children
+        .iter()
+        .filter_map(max_tree_depth)
+        .chain(own)
+        .max()
+}
+
+/// Normalizes a CWE identifier or type-name segment for comparison.
+///
+/// Case and punctuation are ignored, and bare numbers get a `cwe` prefix.
+fn cwe_key(id: &str) -> String {
+    let key: String = id
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    if !key.is_empty() && key.chars().all(|c| c.is_ascii_digit()) {
+        format!("cwe{key}")
+    } else {
+        key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a report node with the given type name, details and children.
+    fn node(type_name: &str, details: Option<&str>, children: Vec<ReportNode>) -> ReportNode {
+        ReportNode {
+            name: type_name
+                .rsplit("::")
+                .next()
+                .unwrap_or(type_name)
+                .to_string(),
+            type_name: type_name.to_string(),
+            details: details.map(str::to_string),
+            source_loc: None,
+            children,
+        }
+    }
+
+    /// A CWE-1234 style report whose OR tree has the given depth.
+    fn cwe1234(depth: u32) -> ReportNode {
+        let rec_or = node(
+            "svql_query_lib::primitives::rec::RecOr",
+            Some(&format!("depth: {depth}")),
+            Vec::new(),
+        );
+        let unlock = node(
+            "svql_query_lib::security::cwe1234::unlock_logic::UnlockLogic",
+            None,
+            vec![rec_or],
+        );
+        node(
+            "svql_query_lib::security::cwe1234::Cwe1234",
+            None,
+            vec![unlock],
+        )
+    }
+
+    #[test]
+    fn test_empty_filter_keeps_everything() {
+        let reports = vec![cwe1234(0), node("Cwe1271", None, Vec::new())];
+        assert_eq!(filter(reports, &ReportFilter::default()).len(), 2);
+    }
+
+    #[test]
+    fn test_min_tree_depth() {
+        let min_two = ReportFilter {
+            min_tree_depth: Some(2),
+            ..Default::default()
+        };
+        assert!(min_two.matches(&cwe1234(3)));
+        assert!(min_two.matches(&cwe1234(2)));
+        assert!(!min_two.matches(&cwe1234(1)));
+        assert!(!min_two.matches(&node("Cwe1271", None, Vec::new())));
+    }
+
+    #[test]
+    fn test_cwe_ids_accept_any_spelling() {
+        for id in ["1234", "CWE-1234", "cwe1234"] {
+            let only = ReportFilter {
+                cwe_ids: [id.to_string()].into_iter().collect(),
+                ..Default::default()
+            };
+            let kept = filter(
+                vec![
+                    cwe1234(1),
+                    node(
+                        "svql_query_lib::security::cwe1271::Cwe1271",
+                        None,
+                        Vec::new(),
+                    ),
+                ],
+                &only,
+            );
+            assert_eq!(kept.len(), 1, "id {id}");
+            assert!(kept[0].type_name.ends_with("Cwe1234"));
+        }
+    }
+}
@@ -0,0 +1,11 @@
+//! Machine-readable exports and triage filters for match reports.
+//!
+//! Converters and filters in this module consume the [`ReportNode`](crate::traits::display::ReportNode)
+//! trees produced by `AnyTable::row_to_report_node`, either narrowing them down
+//! or turning them into formats understood by external tooling.
+
+pub mod dot;
+pub mod filter;
+pub mod sarif;
+
+pub use filter::{ReportFilter, filter};
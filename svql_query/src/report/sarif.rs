@@ -0,0 +1,382 @@
+//! SARIF 2.1.0 export for match reports.
+//!
+//! Each top-level [`ReportNode`] becomes one SARIF `result`. The node's
+//! `type_name` is used as the `ruleId`, and every source location found in the
+//! report tree becomes a `physicalLocation`, with runs of consecutive source
+//! lines merged into a single region. A run whose search stopped at a
+//! configured limit carries a warning notification on its invocation.
+
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+
+use crate::prelude::*;
+use crate::traits::display::ReportNode;
+
+/// URI of the SARIF 2.1.0 JSON schema.
+pub const SCHEMA_URI: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Name reported as the SARIF tool driver.
+const TOOL_NAME: &str = "svql";
+
+/// Top-level SARIF log.
+#[derive(Debug, Clone, Serialize)]
+pub struct Log {
+    /// Schema URI, serialized as `$schema`.
+    #[serde(rename = "$schema")]
+    pub schema: &'static str,
+    /// SARIF format version.
+    pub version: &'static str,
+    /// Individual analysis runs.
+    pub runs: Vec<Run>,
+}
+
+impl Log {
+    /// Wraps runs into a SARIF 2.1.0 log.
+    #[must_use]
+    pub const fn new(runs: Vec<Run>) -> Self {
+        Self {
+            schema: SCHEMA_URI,
+            version: "2.1.0",
+            runs,
+        }
+    }
+}
+
+/// A single invocation of the analysis tool and its results.
+#[derive(Debug, Clone, Serialize)]
+pub struct Run {
+    /// The tool that produced the results.
+    pub tool: Tool,
+    /// Findings produced by the run.
+    pub results: Vec<SarifResult>,
+    /// How the tool was run, if anything about it is worth reporting.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub invocations: Vec<Invocation>,
+}
+
+impl Run {
+    /// Converts a collection of per-match report trees into a SARIF run.
+    ///
+    /// A rule is declared for every distinct `type_name` among `nodes`.
+    #[must_use]
+    pub fn from_reports(nodes: &[ReportNode]) -> Self {
+        let rule_ids: BTreeSet<&str> = nodes.iter().map(|node| node.type_name.as_str()).collect();
+
+        Self {
+            tool: Tool {
+                driver: ToolComponent {
+                    name: TOOL_NAME,
+                    version: env!("CARGO_PKG_VERSION"),
+                    rules: rule_ids
+                        .into_iter()
+                        .map(|id| ReportingDescriptor { id: id.to_owned() })
+                        .collect(),
+                },
+            },
+            results: nodes.iter().map(SarifResult::from_report).collect(),
+            invocations: Vec::new(),
+        }
+    }
+
+    /// Records that the search producing this run stopped at a configured
+    /// limit, so `results` may be incomplete.
+    #[must_use]
+    pub fn with_truncated(mut self, truncated: bool) -> Self {
+        if truncated {
+            self.invocations.push(Invocation {
+                execution_successful: true,
+                tool_execution_notifications: vec![Notification {
+                    level: "warning",
+                    message: Message {
+                        text: "Search truncated by configured limits; results may be incomplete"
+                            .to_owned(),
+                    },
+                }],
+            });
+        }
+        self
+    }
+}
+
+/// A single invocation of the tool.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Invocation {
+    /// Whether the tool ran to completion.
+    pub execution_successful: bool,
+    /// Conditions encountered while running the tool.
+    pub tool_execution_notifications: Vec<Notification>,
+}
+
+/// A condition reported by the tool rather than a finding.
+#[derive(Debug, Clone, Serialize)]
+pub struct Notification {
+    /// Severity level.
+    pub level: &'static str,
+    /// Human-readable description.
+    pub message: Message,
+}
+
+/// Wrapper around the tool driver.
+#[derive(Debug, Clone, Serialize)]
+pub struct Tool {
+    /// The primary tool component.
+    pub driver: ToolComponent,
+}
+
+/// Description of the analysis tool.
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolComponent {
+    /// Tool name.
+    pub name: &'static str,
+    /// Tool version.
+    pub version: &'static str,
+    /// Rules (pattern types) referenced by results.
+    pub rules: Vec<ReportingDescriptor>,
+}
+
+/// A rule referenced by results.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportingDescriptor {
+    /// Rule identifier, the pattern's type name.
+    pub id: String,
+}
+
+/// A single finding.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    /// Identifier of the rule that was matched.
+    pub rule_id: String,
+    /// Severity level.
+    pub level: &'static str,
+    /// Human-readable description.
+    pub message: Message,
+    /// Source locations relevant to the finding.
+    pub locations: Vec<Location>,
+}
+
+impl SarifResult {
+    /// Converts a single report tree into a SARIF result.
+    #[must_use]
+    pub fn from_report(node: &ReportNode) -> Self {
+        let text = match &node.details {
+            Some(details) => format!("{} match `{}` ({details})", node.type_name, node.name),
+            None => format!("{} match `{}`", node.type_name, node.name),
+        };
+
+        let mut locations = Vec::new();
+        collect_locations(node, &mut locations);
+
+        Self {
+            rule_id: node.type_name.clone(),
+            level: "warning",
+            message: Message { text },
+            locations,
+        }
+    }
+}
+
+/// A plain-text message.
+#[derive(Debug, Clone, Serialize)]
+pub struct Message {
+    /// The message text.
+    pub text: String,
+}
+
+/// A location wrapper, as required by the SARIF schema.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    /// The physical file and region.
+    pub physical_location: PhysicalLocation,
+}
+
+/// A file and region within it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhysicalLocation {
+    /// The file containing the region.
+    pub artifact_location: ArtifactLocation,
+    /// The region within the file.
+    pub region: Region,
+}
+
+/// Reference to a source file.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct ArtifactLocation {
+    /// File URI, or a relative URI reference for relative paths.
+    pub uri: String,
+}
+
+impl ArtifactLocation {
+    /// Builds an artifact location from a source file path.
+    #[must_use]
+    pub fn from_path(path: &str) -> Self {
+        let path = path.replace('\\', "/");
+        let uri = if path.starts_with('/') {
+            format!("file://{path}")
+        } else {
+            path
+        };
+        Self { uri }
+    }
+}
+
+/// A contiguous range of lines, with optional column bounds.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Region {
+    /// 1-indexed first line.
+    pub start_line: usize,
+    /// 1-indexed first column, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_column: Option<usize>,
+    /// 1-indexed last line.
+    pub end_line: usize,
+    /// Column where the region ends, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<usize>,
+}
+
+impl Region {
+    /// Splits source lines into regions, one per run of consecutive line numbers.
+    ///
+    /// Lines are sorted and deduplicated first. Column offsets of `0` are
+    /// treated as unknown and omitted.
+    #[must_use]
+    pub fn from_lines(lines: &[SourceLine]) -> Vec<Self> {
+        let mut sorted: Vec<&SourceLine> = lines.iter().collect();
+        sorted.sort_by_key(|line| line.number);
+        sorted.dedup_by_key(|line| line.number);
+
+        let known = |column: usize| (column != 0).then_some(column);
+
+        sorted
+            .chunk_by(|prev, next| next.number == prev.number + 1)
+            .filter_map(|run| Some((*run.first()?, *run.last()?)))
+            .map(|(first, last)| Self {
+                start_line: first.number,
+                start_column: known(first.start_column),
+                end_line: last.number,
+                end_column: known(last.end_column),
+            })
+            .collect()
+    }
+}
+
+/// Collects the locations of `node` and its descendants, skipping duplicates.
+fn collect_locations(node: &ReportNode, out: &mut Vec<Location>) {
+    if let Some(loc) = &node.source_loc {
+        let artifact_location = ArtifactLocation::from_path(&loc.file);
+        for region in Region::from_lines(&loc.lines) {
+            let location = Location {
+                physical_location: PhysicalLocation {
+                    artifact_location: artifact_location.clone(),
+                    region,
+                },
+            };
+            if !out.contains(&location) {
+                out.push(location);
+            }
+        }
+    }
+    for child in &node.children {
+        collect_locations(child, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Shorthand for a source line with the given columns.
+    const fn line(number: usize, start_column: usize, end_column: usize) -> SourceLine {
+        SourceLine {
+            number,
+            start_column,
+            end_column,
+        }
+    }
+
+    #[test]
+    fn test_regions_merge_consecutive_lines() {
+        let regions = Region::from_lines(&[line(5, 3, 0), line(3, 1, 9), line(4, 2, 7)]);
+        assert_eq!(
+            regions,
+            vec![Region {
+                start_line: 3,
+                start_column: Some(1),
+                end_line: 5,
+                end_column: None,
+            }]
+        );
+
+        let regions = Region::from_lines(&[line(2, 1, 4), line(7, 5, 6), line(2, 1, 4)]);
+        assert_eq!(regions.len(), 2);
+        assert_eq!((regions[0].start_line, regions[0].end_line), (2, 2));
+        assert_eq!((regions[1].start_line, regions[1].end_line), (7, 7));
+    }
+
+    #[test]
+    fn test_run_from_reports() -> Result<(), Box<dyn std::error::Error>> {
+        let node = ReportNode {
+            name: "Cwe1234".to_string(),
+            type_name: "Cwe1234".to_string(),
+            details: None,
+            source_loc: None,
+            children: vec![ReportNode {
+                name: "y".to_string(),
+                type_name: "Output".to_string(),
+                details: None,
+                source_loc: Some(SourceLocation {
+                    file: "/work/top.v".into(),
+                    lines: vec![line(10, 4, 12), line(11, 1, 8)],
+                }),
+                children: Vec::new(),
+            }],
+        };
+
+        let log = Log::new(vec![Run::from_reports(&[node.clone(), node])]);
+        let value = serde_json::to_value(&log)?;
+
+        assert_eq!(value["version"], "2.1.0");
+        let run = &value["runs"][0];
+        assert_eq!(
+            run["tool"]["driver"]["rules"].as_array().map(Vec::len),
+            Some(1)
+        );
+        assert_eq!(run["results"].as_array().map(Vec::len), Some(2));
+
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "Cwe1234");
+        let physical = &result["locations"][0]["physicalLocation"];
+        assert_eq!(physical["artifactLocation"]["uri"], "file:///work/top.v");
+        assert_eq!(physical["region"]["startLine"], 10);
+        assert_eq!(physical["region"]["startColumn"], 4);
+        assert_eq!(physical["region"]["endLine"], 11);
+        assert_eq!(physical["region"]["endColumn"], 8);
+        assert!(run.get("invocations").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_truncated_run_reports_notification() -> Result<(), Box<dyn std::error::Error>> {
+        let run = Run::from_reports(&[]).with_truncated(true);
+        let value = serde_json::to_value(&run)?;
+
+        let invocation = &value["invocations"][0];
+        assert_eq!(invocation["executionSuccessful"], true);
+        let notification = &invocation["toolExecutionNotifications"][0];
+        assert_eq!(notification["level"], "warning");
+        assert!(
+            notification["message"]["text"]
+                .as_str()
+                .is_some_and(|text| text.contains("truncated"))
+        );
+
+        Ok(())
+    }
+}
@@ -37,6 +37,10 @@ pub enum QueryError {
     #[error("Schema didn't have: {0}")]
     SchemaLut(String),
 
+    /// Pattern port declarations reference ports missing from the needle design.
+    #[error("Invalid pattern ports: {0}")]
+    InvalidPorts(String),
+
     /// Pattern type was not registered before execution.
     #[error("Pattern type not registered: {0:?}")]
     MissingRegistration(TypeId),
@@ -50,6 +54,20 @@ pub enum QueryError {
     #[error("Subgraph matching failed: {0}")]
     SubgraphMatch(String),
 
+    /// A needle port has no matching column in the pattern schema.
+    #[error(
+        "Port `{port}` of {module} not found in pattern schema (available: {})",
+        .available.join(", ")
+    )]
+    MissingPort {
+        /// The needle module whose port failed to resolve.
+        module: String,
+        /// The port name that was looked up.
+        port: String,
+        /// The port columns the schema does have.
+        available: Vec<String>,
+    },
+
     /// A generic error occurring during the execution of a search plan.
     #[error("Execution error: {0}")]
     ExecutionError(String),
@@ -66,6 +84,24 @@ impl QueryError {
         Self::NeedleLoad(msg.into())
     }
 
+    /// Create an invalid pattern ports error.
+    pub fn invalid_ports(msg: impl Into<String>) -> Self {
+        Self::InvalidPorts(msg.into())
+    }
+
+    /// Create a missing port error.
+    pub fn missing_port(
+        module: impl Into<String>,
+        port: impl Into<String>,
+        available: Vec<String>,
+    ) -> Self {
+        Self::MissingPort {
+            module: module.into(),
+            port: port.into(),
+            available,
+        }
+    }
+
     /// Create a missing dependency error.
     pub fn missing_dep(name: impl Into<String>) -> Self {
         Self::MissingDependency(name.into())
@@ -76,3 +112,18 @@ impl QueryError {
         Self::SubgraphMatch(msg.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_port_lists_available_ports() {
+        let err =
+            QueryError::missing_port("and_gate", "c", vec!["a".into(), "b".into(), "y".into()]);
+        assert_eq!(
+            err.to_string(),
+            "Port `c` of and_gate not found in pattern schema (available: a, b, y)"
+        );
+    }
+}
@@ -37,6 +37,10 @@ impl BipartiteIndex {
         let mut reverse: HashMap<RowIndex, HashSet<RowIndex>> = HashMap::new();
         let mut edge_count = 0;
 
+        if matches!(connection.kind, ConnectionKind::FaninCone) {
+            return Self::build_fanin_cone(a_table, b_table, connection, ctx);
+        }
+
         let mut net_to_b_rows: HashMap<prjunnamed_netlist::Net, Vec<RowIndex>> = HashMap::new();
 
         match connection.kind {
@@ -46,7 +50,7 @@ impl BipartiteIndex {
                         b_table.resolve_path(b_idx as usize, connection.to.selector.tail(), ctx)
                     {
                         let b_row_idx = RowIndex::from_raw(b_idx);
-                        for net in b_cell.iter() {
+                        for net in ctx.connection_sources(&b_cell).iter() {
                             let vec = net_to_b_rows.entry(net).or_default();
                             if vec.last() != Some(&b_row_idx) {
                                 vec.push(b_row_idx);
@@ -64,7 +68,7 @@ impl BipartiteIndex {
                     ) {
                         let b_row_idx = RowIndex::from_raw(b_idx);
                         for wire in b_bundle {
-                            for net in wire.iter() {
+                            for net in ctx.connection_sources(&wire).iter() {
                                 let vec = net_to_b_rows.entry(net).or_default();
                                 if vec.last() != Some(&b_row_idx) {
                                     vec.push(b_row_idx);
@@ -74,6 +78,7 @@ impl BipartiteIndex {
                     }
                 }
             }
+            ConnectionKind::FaninCone => unreachable!("fan-in cone connections are built above"),
         }
 
         for a_idx in 0..a_table.len() as u32 {
@@ -109,6 +114,66 @@ impl BipartiteIndex {
         }
     }
 
+    /// Build the index for a fan-in cone connection.
+    ///
+    /// The cone of each B row is walked once and keyed by the cells it
+    /// contains; A rows are then looked up by the cell driving their wire.
+    ///
+    /// # Performance
+    /// - Time: O(|A| + Σ|cone(B)| + E)
+    fn build_fanin_cone(
+        a_table: &dyn AnyTable,
+        b_table: &dyn AnyTable,
+        connection: &Connection,
+        ctx: &ExecutionContext,
+    ) -> Self {
+        let index = ctx.haystack_design().index();
+        let mut forward: HashMap<RowIndex, HashSet<RowIndex>> = HashMap::new();
+        let mut reverse: HashMap<RowIndex, HashSet<RowIndex>> = HashMap::new();
+        let mut edge_count = 0;
+
+        let mut cell_to_b_rows: HashMap<GraphNodeIdx, Vec<RowIndex>> = HashMap::new();
+        for b_idx in 0..b_table.len() as u32 {
+            if let Some(b_wire) =
+                b_table.resolve_path(b_idx as usize, connection.to.selector.tail(), ctx)
+            {
+                let b_row_idx = RowIndex::from_raw(b_idx);
+                for cell in combinational_fanin(&b_wire, index) {
+                    cell_to_b_rows.entry(cell).or_default().push(b_row_idx);
+                }
+            }
+        }
+
+        for a_idx in 0..a_table.len() as u32 {
+            let Some(a_cell) = a_table
+                .resolve_path(a_idx as usize, connection.from.selector.tail(), ctx)
+                .and_then(|a_wire| driver_cell(&a_wire, index))
+            else {
+                continue;
+            };
+            let a_row_idx = RowIndex::from_raw(a_idx);
+            for &b_row_idx in cell_to_b_rows.get(&a_cell).into_iter().flatten() {
+                if forward.entry(a_row_idx).or_default().insert(b_row_idx) {
+                    reverse.entry(b_row_idx).or_default().insert(a_row_idx);
+                    edge_count += 1;
+                }
+            }
+        }
+
+        tracing::debug!(
+            "Built fan-in cone index: {}×{} → {} edges",
+            a_table.len(),
+            b_table.len(),
+            edge_count
+        );
+
+        Self {
+            forward,
+            reverse,
+            edge_count,
+        }
+    }
+
     /// Get all valid target rows for a given source row.
     #[inline]
     pub fn targets(&self, source_row: RowIndex) -> Option<&HashSet<RowIndex>> {
@@ -68,17 +68,31 @@ impl ExecutionNode {
     }
 
     /// Construct an execution node and recursively build its dependencies from `ExecInfo`.
-    fn from_dep(exec_info: &ExecInfo) -> Self {
-        let mut deps = vec![];
+    ///
+    /// Nodes are memoized in `built` by `TypeId`, so a pattern referenced
+    /// several times (e.g. three identical `AndGate` submodules) becomes a
+    /// single shared node and is searched once.
+    fn from_dep(exec_info: &ExecInfo, built: &mut HashMap<TypeId, Arc<Self>>) -> Arc<Self> {
+        if let Some(node) = built.get(&exec_info.type_id) {
+            return Arc::clone(node);
+        }
+
+        let mut deps: Vec<Arc<Self>> = Vec::with_capacity(exec_info.nested_dependancies.len());
         for nested in exec_info.nested_dependancies {
-            deps.push(Arc::new(Self::from_dep(nested)));
+            let dep = Self::from_dep(nested, built);
+            if !deps.iter().any(|d| d.type_id == dep.type_id) {
+                deps.push(dep);
+            }
         }
-        Self {
+
+        let node = Arc::new(Self {
             type_id: exec_info.type_id,
             type_name: exec_info.type_name,
             search_fn: exec_info.search_function,
             deps,
-        }
+        });
+        built.insert(exec_info.type_id, Arc::clone(&node));
+        node
     }
 }
 
@@ -121,7 +135,7 @@ impl ExecutionPlan {
     #[must_use]
     pub fn build(root: &super::ExecInfo) -> (Self, HashMap<TypeId, TableSlot>) {
         tracing::info!("Building execution plan for pattern: {}", root.type_name);
-        let root_node = Arc::new(ExecutionNode::from_dep(root));
+        let root_node = ExecutionNode::from_dep(root, &mut HashMap::new());
         let mut all_deps = root_node.flatten_deps();
         all_deps.push(Arc::clone(&root_node));
 
@@ -148,6 +162,71 @@ impl ExecutionPlan {
         )
     }
 
+    /// Number of distinct pattern nodes in the plan, including the root.
+    #[must_use]
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Render the plan DAG as an indented tree, similar to SQL `EXPLAIN`.
+    ///
+    /// Nodes shared by several dependents are expanded on their first
+    /// occurrence and marked `[shared]` afterwards.
+    #[must_use]
+    pub fn explain(&self) -> String {
+        let mut output = String::new();
+        let mut seen = HashSet::new();
+        Self::explain_recursive(&self.root, &mut output, "", true, true, &mut seen);
+        output
+    }
+
+    /// Internal depth-first traversal for `explain`.
+    fn explain_recursive(
+        node: &ExecutionNode,
+        f: &mut String,
+        prefix: &str,
+        is_last: bool,
+        is_root: bool,
+        seen: &mut HashSet<TypeId>,
+    ) {
+        let marker = if is_root {
+            ""
+        } else if is_last {
+            "+-- "
+        } else {
+            "|-- "
+        };
+
+        let short_name = node.type_name.rsplit("::").next().unwrap_or(node.type_name);
+        let shared = !seen.insert(node.type_id);
+
+        f.push_str(&format!(
+            "{}{}{} ({}){}\n",
+            prefix,
+            marker,
+            short_name,
+            node.type_name,
+            if shared { " [shared]" } else { "" }
+        ));
+
+        if shared {
+            return;
+        }
+
+        let new_prefix = if is_root {
+            String::new()
+        } else if is_last {
+            format!("{prefix}    ")
+        } else {
+            format!("{prefix}|   ")
+        };
+
+        for (i, dep) in node.deps.iter().enumerate() {
+            let last = i == node.deps.len() - 1;
+            Self::explain_recursive(dep, f, &new_prefix, last, false, seen);
+        }
+    }
+
     /// Execute the plan and return a Store with all results.
     ///
     /// # Arguments
@@ -169,6 +248,23 @@ impl ExecutionPlan {
         key: &DriverKey,
         config: &svql_common::Config,
         slots: HashMap<TypeId, TableSlot>,
+    ) -> Result<Store, QueryError> {
+        self.execute_with_progress(driver, key, config, slots, None)
+    }
+
+    /// Execute the plan like [`Self::execute`], forwarding subgraph search
+    /// progress to `progress`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `QueryError` under the same conditions as [`Self::execute`].
+    pub fn execute_with_progress(
+        self,
+        driver: &Driver,
+        key: &DriverKey,
+        config: &svql_common::Config,
+        slots: HashMap<TypeId, TableSlot>,
+        progress: Option<subgraph::ProgressFn>,
     ) -> Result<Store, QueryError> {
         tracing::info!("Starting execution plan for design: {:?}", key);
         tracing::info!(
@@ -188,13 +284,16 @@ impl ExecutionPlan {
         tracing::debug!("Haystack design loaded successfully");
 
         // Create shared context
-        let ctx = ExecutionContext::new(
+        let mut ctx = ExecutionContext::new(
             driver.clone(),
             key.clone(),
             haystack_design,
             config.clone(),
             slots,
         );
+        if let Some(progress) = progress {
+            ctx = ctx.with_progress(progress);
+        }
 
         if config.parallel {
             tracing::info!("Executing plan in parallel mode");
@@ -241,9 +340,8 @@ impl ExecutionPlan {
     /// Execute a single node, waiting for deps first.
     ///
     /// Coordination is done via `TableSlot::try_claim()` which is keyed by
-    /// `TypeId` in the shared slots map.  This avoids the problem of duplicate
-    /// `ExecutionNode` instances (from recursive `from_dep`) each carrying
-    /// their own atomic flag.
+    /// `TypeId` in the shared slots map, so parallel workers reaching the same
+    /// shared node through different dependents never run its search twice.
     ///
     /// Three possible paths:
     /// - **Ready**: slot already filled → return immediately (lock-free read)
@@ -299,17 +397,46 @@ impl ExecutionPlan {
             );
         }
 
+        // Leaf results depend on the haystack, the needle and the config.
+        // They are shared with later queries through the driver's result
+        // cache, which drops them whenever any cached design (including a
+        // re-imported needle) is replaced
+        let fingerprint =
+            (node.deps.is_empty() && ctx.config.cache_results).then(|| ctx.config_fingerprint());
+        if let Some(fingerprint) = fingerprint
+            && let Some(cached) = ctx
+                .driver
+                .cached_result(&ctx.design_key, node.type_id, fingerprint)
+                .and_then(|any| {
+                    any.downcast_ref::<Arc<dyn AnyTable + Send + Sync>>()
+                        .cloned()
+                })
+        {
+            tracing::info!("[SEARCH] Reusing cached results for: {}", node.type_name);
+            slot.set(cached);
+            return Ok(());
+        }
+
         // Execute search
         tracing::info!("[SEARCH] Starting search for: {}", node.type_name);
-        let result = (node.search_fn)(ctx)?;
+        let result: Arc<dyn AnyTable + Send + Sync> = Arc::from((node.search_fn)(ctx)?);
         tracing::info!(
             "[SEARCH] Completed search for: {} -> {} results",
             node.type_name,
             result.len()
         );
 
+        if let Some(fingerprint) = fingerprint {
+            ctx.driver.cache_result(
+                &ctx.design_key,
+                node.type_id,
+                fingerprint,
+                Arc::new(Arc::clone(&result)),
+            );
+        }
+
         // Store result and notify waiters
-        slot.set(Arc::from(result));
+        slot.set(result);
         tracing::trace!("Stored results for: {}", node.type_name);
 
         tracing::debug!("Node execution complete: {}", node.type_name);
@@ -374,6 +501,8 @@ pub struct ExecutionContext {
     config: svql_common::Config,
     /// Slots to hold results during execution.
     slots: HashMap<TypeId, TableSlot>,
+    /// Callback receiving subgraph search progress, if any.
+    progress: Option<subgraph::ProgressFn>,
 }
 
 impl ExecutionContext {
@@ -393,9 +522,23 @@ impl ExecutionContext {
             haystack_design,
             config,
             slots,
+            progress: None,
         }
     }
 
+    /// Attaches a callback that receives subgraph search progress.
+    #[must_use]
+    pub fn with_progress(mut self, progress: subgraph::ProgressFn) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Get the progress callback, if one is attached.
+    #[must_use]
+    pub const fn progress(&self) -> Option<&subgraph::ProgressFn> {
+        self.progress.as_ref()
+    }
+
     /// Get the driver.
     #[must_use]
     pub const fn driver(&self) -> &Driver {
@@ -420,6 +563,34 @@ impl ExecutionContext {
         &self.haystack_design
     }
 
+    /// Get the haystack nets a connection to `wire` may be driven from.
+    ///
+    /// This is `wire` itself, plus with `skip_transparent` every net feeding
+    /// it through a chain of buffers or inverters.
+    #[must_use]
+    pub fn connection_sources(&self, wire: &Wire) -> Wire {
+        if self.config.skip_transparent {
+            wire.with_transparent_sources(self.haystack_design.design())
+        } else {
+            wire.clone()
+        }
+    }
+
+    /// Check whether `src` drives `dst`, honouring `skip_transparent`.
+    #[must_use]
+    pub fn wire_drives(&self, src: &Wire, dst: &Wire) -> bool {
+        src.drives(&self.connection_sources(dst))
+    }
+
+    /// Hash of the configuration, used to key cached leaf results.
+    fn config_fingerprint(&self) -> u64 {
+        use std::hash::Hasher;
+
+        let mut hasher = svql_common::util::StableHasher::new();
+        self.config.hash(&mut hasher);
+        hasher.finish()
+    }
+
     /// Retrieve a completed dependency table by `TypeId`.
     ///
     /// Returns `None` if the table was not found or is not yet computed
@@ -429,3 +600,192 @@ impl ExecutionContext {
         self.slots.get(&type_id).and_then(|slot| slot.get_ref())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::traits::netlist::test::AndGate;
+
+    /// Marker type for the shared leaf node.
+    struct Leaf;
+
+    /// Marker type for the root node.
+    struct Root;
+
+    /// Number of times the leaf search function has run.
+    static LEAF_SEARCHES: AtomicUsize = AtomicUsize::new(0);
+
+    /// Leaf search that records each invocation.
+    fn search_leaf(_ctx: &ExecutionContext) -> Result<Box<dyn AnyTable + Send + Sync>, QueryError> {
+        LEAF_SEARCHES.fetch_add(1, Ordering::SeqCst);
+        Ok(Box::new(Table::<AndGate>::new(Vec::new())?))
+    }
+
+    /// Root search returning an empty table.
+    fn search_root(_ctx: &ExecutionContext) -> Result<Box<dyn AnyTable + Send + Sync>, QueryError> {
+        Ok(Box::new(Table::<AndGate>::new(Vec::new())?))
+    }
+
+    /// Leaf node referenced several times by the root.
+    static LEAF: ExecInfo = ExecInfo {
+        type_id: TypeId::of::<Leaf>(),
+        type_name: "Leaf",
+        search_function: search_leaf,
+        nested_dependancies: &[],
+    };
+
+    /// Root node with three identical leaf dependencies.
+    static ROOT: ExecInfo = ExecInfo {
+        type_id: TypeId::of::<Root>(),
+        type_name: "Root",
+        search_function: search_root,
+        nested_dependancies: &[&LEAF, &LEAF, &LEAF],
+    };
+
+    /// Two-input gate needle read from a file that the test rewrites.
+    #[derive(Debug, Clone)]
+    struct EditedNeedle;
+
+    impl Component for EditedNeedle {
+        type Kind = kind::Netlist;
+    }
+
+    impl Netlist for EditedNeedle {
+        const MODULE_NAME: &'static str = "edited_needle";
+        const FILE_PATH: &'static str = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../target/svql_test/edited_needle.v"
+        );
+
+        const PORTS: &'static [PortDecl] = &[
+            PortDecl::input("a"),
+            PortDecl::input("b"),
+            PortDecl::output("y"),
+        ];
+
+        fn netlist_rehydrate(
+            _row: &Row<Self>,
+            _store: &Store,
+            _driver: &Driver,
+            _key: &DriverKey,
+            _config: &svql_common::Config,
+        ) -> Option<Self> {
+            Some(Self)
+        }
+    }
+
+    /// Writes `EditedNeedle` as `y = a <op> b`, stamped with `modified`.
+    fn write_edited_needle(op: &str, modified: u64) -> std::io::Result<()> {
+        let path = std::path::Path::new(EditedNeedle::FILE_PATH);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(
+            path,
+            format!(
+                "module edited_needle\n(\ninput a,\ninput b,\noutput y\n);\n\nassign y = a {op} b;\nendmodule\n"
+            ),
+        )?;
+        std::fs::File::options()
+            .write(true)
+            .open(path)?
+            .set_modified(std::time::UNIX_EPOCH + std::time::Duration::from_secs(modified))
+    }
+
+    #[test]
+    fn test_cached_leaf_results_follow_needle_edits() -> Result<(), Box<dyn std::error::Error>> {
+        let (driver, key) = crate::test_harness::small_and_tree()?;
+        let config = svql_common::Config::default();
+
+        write_edited_needle("&", 1_000)?;
+        let store = crate::run_query::<EditedNeedle>(&driver, &key, &config)?;
+        assert_eq!(store.get::<EditedNeedle>().ok_or("Table not found")?.len(), 3);
+
+        // The re-imported needle must not be answered with the AND matches
+        write_edited_needle("|", 2_000)?;
+        let store = crate::run_query::<EditedNeedle>(&driver, &key, &config)?;
+        assert_eq!(store.get::<EditedNeedle>().ok_or("Table not found")?.len(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_wire_drives_through_buffer_only_with_skip_transparent()
+    -> Result<(), Box<dyn std::error::Error>> {
+        use prjunnamed_netlist::{Cell, Design};
+
+        let mut design = Design::new();
+        let a = design.add_input("a", 1);
+        let en = design.add_cell(Cell::Buf(a.clone()));
+        design.add_output("en", en.clone());
+        design.apply();
+
+        let driver = Driver::new_prjunnamed(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))?;
+        let key = driver.insert_design(DriverKey::new("in_memory", "buffered"), design);
+        let container = driver.get_design(&key, &ModuleConfig::default())?;
+        let (a, en) = (Wire::new(a), Wire::new(en));
+
+        let strict = ExecutionContext::new(
+            driver.clone(),
+            key.clone(),
+            Arc::clone(&container),
+            svql_common::Config::default(),
+            HashMap::new(),
+        );
+        assert!(!strict.wire_drives(&a, &en));
+
+        let transparent = ExecutionContext::new(
+            driver,
+            key,
+            container,
+            svql_common::Config::builder()
+                .skip_transparent(true)
+                .build(),
+            HashMap::new(),
+        );
+        assert!(transparent.wire_drives(&a, &en));
+        assert!(!transparent.wire_drives(&en, &a));
+        Ok(())
+    }
+
+    #[test]
+    fn test_explain_renders_shared_dependency_once() {
+        let (plan, _slots) = ExecutionPlan::build(&ROOT);
+        assert_eq!(plan.explain(), "Root (Root)\n+-- Leaf (Leaf)\n");
+    }
+
+    #[test]
+    fn test_identical_dependencies_share_one_node() -> Result<(), Box<dyn std::error::Error>> {
+        let (plan, slots) = ExecutionPlan::build(&ROOT);
+        assert_eq!(plan.node_count(), 2);
+        assert_eq!(plan.root.deps.len(), 1);
+
+        let driver = Driver::new_prjunnamed(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))?;
+        let key = DriverKey::new(
+            "examples/fixtures/basic/and/json/mixed_and_tree.json",
+            "mixed_and_tree",
+        );
+        let config = svql_common::Config::builder().parallel(true).build();
+        plan.execute(&driver, &key, &config, slots)?;
+        assert_eq!(LEAF_SEARCHES.load(Ordering::SeqCst), 1);
+
+        // A second query on the same design reuses the cached leaf results
+        let (plan, slots) = ExecutionPlan::build(&ROOT);
+        plan.execute(&driver, &key, &config, slots)?;
+        assert_eq!(LEAF_SEARCHES.load(Ordering::SeqCst), 1);
+
+        // Invalidating the design drops its cached results
+        driver.invalidate(&key);
+        let (plan, slots) = ExecutionPlan::build(&ROOT);
+        plan.execute(&driver, &key, &config, slots)?;
+        assert_eq!(LEAF_SEARCHES.load(Ordering::SeqCst), 2);
+
+        // Opting out of the result cache always re-runs the search
+        let uncached = config.into_builder().cache_results(false).build();
+        let (plan, slots) = ExecutionPlan::build(&ROOT);
+        plan.execute(&driver, &key, &uncached, slots)?;
+        assert_eq!(LEAF_SEARCHES.load(Ordering::SeqCst), 3);
+        Ok(())
+    }
+}
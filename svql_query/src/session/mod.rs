@@ -21,7 +21,8 @@ pub use schema::{
     Alias, ColumnDef, ColumnEntry, ColumnKind, EntryArray, PatternSchema, PortMap, Submodule,
 };
 pub use storage::{
-    AnyTable, ColumnStore, MetaValue, Ref, Row, RowIndex, Store, Table, VariantIndex,
+    AnyTable, ColumnStore, MetaValue, Ref, Row, RowIndex, Store, Table, TableView, VariantIndex,
+    wire_cell_in,
 };
 
 use thiserror::Error;
@@ -5,6 +5,7 @@
 
 use std::fmt;
 use svql_common::PhysicalCellId;
+use svql_subgraph::FunctionalVerdict;
 
 /// An opaque index into a `Variant`'s `VARIANT_ARMS` array.
 ///
@@ -43,6 +44,9 @@ pub enum MetaValue {
     Discriminant(VariantIndex),
     /// An unsigned integer counter (e.g., tree depth in `Recursive` patterns).
     Count(u32),
+    /// The outcome of checking a `Netlist` match against its needle with an
+    /// SMT solver.
+    Verdict(FunctionalVerdict),
 }
 
 impl MetaValue {
@@ -72,6 +76,15 @@ impl MetaValue {
             _ => None,
         }
     }
+
+    /// Extract the verification verdict if this is `Verdict`.
+    #[must_use]
+    pub const fn as_verdict(&self) -> Option<FunctionalVerdict> {
+        match self {
+            Self::Verdict(verdict) => Some(*verdict),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for MetaValue {
@@ -80,6 +93,7 @@ impl fmt::Display for MetaValue {
             Self::CellRef(id) => write!(f, "cell({})", id),
             Self::Discriminant(idx) => write!(f, "{}", idx),
             Self::Count(n) => write!(f, "count({})", n),
+            Self::Verdict(verdict) => write!(f, "verdict({})", verdict),
         }
     }
 }
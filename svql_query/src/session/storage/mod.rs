@@ -18,6 +18,8 @@ mod row_index;
 mod store;
 /// Table wrapper.
 mod table;
+/// Filtered table views.
+mod view;
 
 pub use columnar::ColumnStore;
 pub use entry::ColumnEntry;
@@ -28,3 +30,4 @@ pub use row::Row;
 pub use row_index::RowIndex;
 pub use store::Store;
 pub use table::{AnyTable, Table};
+pub use view::{TableView, wire_cell_in};
@@ -172,6 +172,36 @@ where
         sub_table.resolve_path(row_idx, selector.tail(), ctx)
     }
 
+    /// Resolve a selector path to a wire using the finished tables in `store`.
+    ///
+    /// Behaves like `resolve`, but for use after execution when only the
+    /// result `Store` is available.
+    #[must_use]
+    pub fn resolve_in_store(&self, selector: Selector<'_>, store: &Store) -> Option<Wire> {
+        if selector.is_empty() {
+            return None;
+        }
+
+        if selector.len() == 1 {
+            return self.wire(selector.head()?).cloned();
+        }
+
+        let head = selector.head()?;
+        let idx = T::schema().index_of(head)?;
+        let col_def = T::schema().column(idx);
+
+        let (row_idx, sub_type_id) = match &col_def.kind {
+            ColumnKind::Sub(tid) => {
+                let ri = self.entry_array.entries[idx].as_row_index()?;
+                (ri.raw() as usize, *tid)
+            }
+            _ => return None,
+        };
+
+        let sub_table = store.get_any(sub_type_id)?;
+        sub_table.resolve_path_in_store(row_idx, selector.tail(), store)
+    }
+
     /// Resolve a selector path to a wire bundle.
     #[must_use]
     pub fn resolve_bundle(
@@ -2,10 +2,13 @@ use super::AnyTable;
 use super::Ref;
 use super::Row;
 use super::Table;
+use super::TableView;
+use crate::prelude::{ColumnKind, PhysicalCellId, QueryError};
 use crate::traits::Component;
 use crate::traits::Pattern;
 use gxhash::{HashMap, HashMapExt};
 use std::any::TypeId;
+use std::path::Path;
 use std::sync::Arc;
 
 /// A store containing multiple tables, indexed by type ID.
@@ -79,6 +82,83 @@ impl Store {
         self.get_any(type_id)
     }
 
+    /// Find a table by pattern type name.
+    ///
+    /// Accepts either the full `std::any::type_name` of the pattern or its
+    /// trailing path segment (e.g. `"AndGate"`). Returns `None` if no table
+    /// matches or if a short name is ambiguous.
+    #[must_use]
+    pub fn get_by_type_name(&self, type_name: &str) -> Option<&(dyn AnyTable + Send + Sync)> {
+        let suffix = format!("::{type_name}");
+        let mut found = self
+            .tables()
+            .map(|(_, table)| table)
+            .filter(|table| table.type_name() == type_name || table.type_name().ends_with(&suffix));
+        let first = found.next()?;
+        found.next().is_none().then_some(first)
+    }
+
+    /// Filter the rows of a table without rehydrating them.
+    ///
+    /// `predicate` receives the type-erased table and a row index and is
+    /// evaluated directly against the stored columns. Every column listed in
+    /// the pattern's `PatternSchema` is queryable: wire columns through
+    /// `AnyTable::get_wire`, submodule columns through
+    /// `AnyTable::get_sub_ref`. See `wire_cell_in` for a ready-made
+    /// predicate over driving cell ids.
+    ///
+    /// Returns `None` if no table matches `type_name` (see `get_by_type_name`).
+    pub fn filter<F>(&self, type_name: &str, predicate: F) -> Option<TableView<'_>>
+    where
+        F: Fn(&dyn AnyTable, usize) -> bool,
+    {
+        self.get_by_type_name(type_name)
+            .map(|table| TableView::new(table, predicate))
+    }
+
+    /// Join the rows of two tables on a shared design cell.
+    ///
+    /// Returns every `(left_row, right_row)` pair of row indices where the
+    /// wire in `left_col` of the left table and the wire in `right_col` of the
+    /// right table are driven by the same cell. Rows whose column is null or
+    /// not cell-driven never join. Pairs are ordered by left row, then right
+    /// row.
+    ///
+    /// Table names are resolved as in `get_by_type_name`; returns `None` if
+    /// either table cannot be found.
+    #[must_use]
+    pub fn join_on_cell(
+        &self,
+        left_type: &str,
+        left_col: &str,
+        right_type: &str,
+        right_col: &str,
+    ) -> Option<Vec<(usize, usize)>> {
+        let left = self.get_by_type_name(left_type)?;
+        let right = self.get_by_type_name(right_type)?;
+
+        let mut by_cell: HashMap<PhysicalCellId, Vec<usize>> = HashMap::new();
+        for idx in 0..right.len() {
+            if let Some(id) = right
+                .get_wire(idx, right_col)
+                .and_then(|wire| wire.cell_id())
+            {
+                by_cell.entry(id).or_default().push(idx);
+            }
+        }
+
+        let mut pairs = Vec::new();
+        for idx in 0..left.len() {
+            let Some(id) = left.get_wire(idx, left_col).and_then(|wire| wire.cell_id()) else {
+                continue;
+            };
+            if let Some(matches) = by_cell.get(&id) {
+                pairs.extend(matches.iter().map(|&r| (idx, r)));
+            }
+        }
+        Some(pairs)
+    }
+
     /// Get the number of tables in the store.
     pub fn len(&self) -> usize {
         self.tables.len()
@@ -93,6 +173,92 @@ impl Store {
     pub fn clear(&mut self) {
         self.tables.clear();
     }
+
+    /// Write every table to `dir` as one CSV file per pattern type.
+    ///
+    /// Alongside the CSV files a `schema.json` manifest is written, listing
+    /// each table's type name, file name, row count and column definitions.
+    /// `Sub` columns record the type name of the table they reference so the
+    /// dump can be re-joined by external tools.
+    ///
+    /// # Errors
+    ///
+    /// Returns `QueryError::ExecutionError` if the directory cannot be
+    /// created or any file fails to write.
+    pub fn to_csv_dir<P: AsRef<Path>>(&self, dir: P) -> Result<(), QueryError> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir).map_err(|e| {
+            QueryError::ExecutionError(format!(
+                "Failed to create directory {}: {}",
+                dir.display(),
+                e
+            ))
+        })?;
+
+        let mut tables: Vec<_> = self.tables().map(|(_, table)| table).collect();
+        tables.sort_by(|a, b| a.type_name().cmp(b.type_name()));
+
+        let mut manifest = Vec::with_capacity(tables.len());
+        for table in tables {
+            let file = format!("{}.csv", csv_file_stem(table.type_name()));
+            table.to_csv(&dir.join(&file))?;
+
+            let columns: Vec<_> = table
+                .schema()
+                .columns()
+                .iter()
+                .map(|col| {
+                    let kind = match col.kind {
+                        ColumnKind::Wire => "wire".to_owned(),
+                        ColumnKind::WireArray => "wire_array".to_owned(),
+                        ColumnKind::Sub(tid) => format!(
+                            "sub:{}",
+                            self.get_any(tid).map_or("<unknown>", |t| t.type_name())
+                        ),
+                        ColumnKind::Meta => "meta".to_owned(),
+                    };
+                    serde_json::json!({
+                        "name": col.name,
+                        "kind": kind,
+                        "nullable": col.nullable,
+                        "direction": format!("{:?}", col.direction),
+                    })
+                })
+                .collect();
+
+            manifest.push(serde_json::json!({
+                "type_name": table.type_name(),
+                "file": file,
+                "rows": table.len(),
+                "columns": columns,
+            }));
+        }
+
+        let json = serde_json::to_string_pretty(&manifest).map_err(|e| {
+            QueryError::ExecutionError(format!("Failed to serialize schema manifest: {}", e))
+        })?;
+        std::fs::write(dir.join("schema.json"), json).map_err(|e| {
+            QueryError::ExecutionError(format!("Failed to write schema manifest: {}", e))
+        })
+    }
+}
+
+/// Turn a Rust type name into a file-system friendly stem.
+///
+/// Path separators become `.` and any other character outside
+/// `[A-Za-z0-9_.]` becomes `_`.
+fn csv_file_stem(type_name: &str) -> String {
+    type_name
+        .replace("::", ".")
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '.' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
 }
 
 impl Default for Store {
@@ -137,3 +303,79 @@ impl std::fmt::Display for Store {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use svql_common::{Config, HashSet};
+
+    use crate::run_query;
+    use crate::session::wire_cell_in;
+    use crate::test_harness::small_and_tree;
+    use crate::traits::netlist::test::AndGate;
+
+    use super::*;
+
+    #[test]
+    fn test_store_to_csv_dir_writes_manifest() -> Result<(), Box<dyn std::error::Error>> {
+        let (driver, key) = small_and_tree()?;
+        let store = run_query::<AndGate>(&driver, &key, &Config::default())?;
+
+        let dir = std::env::temp_dir().join(format!("svql_store_dump_{}", std::process::id()));
+        store.to_csv_dir(&dir)?;
+
+        let manifest: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(dir.join("schema.json"))?)?;
+        let tables = manifest.as_array().ok_or("Manifest is not an array")?;
+        assert_eq!(tables.len(), store.len());
+        for table in tables {
+            let file = table["file"].as_str().ok_or("Missing file name")?;
+            assert!(dir.join(file).is_file());
+        }
+
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_store_filter_by_cell_id() -> Result<(), Box<dyn std::error::Error>> {
+        let (driver, key) = small_and_tree()?;
+        let store = run_query::<AndGate>(&driver, &key, &Config::default())?;
+
+        let all = store
+            .filter("AndGate", |table, idx| table.get_wire(idx, "y").is_some())
+            .ok_or("Table not found")?;
+        assert_eq!(all.len(), 3);
+
+        let first_y = all
+            .wires("y")
+            .next()
+            .flatten()
+            .and_then(|wire| wire.cell_id())
+            .ok_or("Missing output cell")?;
+        let cells: HashSet<PhysicalCellId> = [first_y].into_iter().collect();
+        let view = store
+            .filter("AndGate", wire_cell_in("y", &cells))
+            .ok_or("Table not found")?;
+        assert_eq!(view.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_store_join_on_cell() -> Result<(), Box<dyn std::error::Error>> {
+        let (driver, key) = small_and_tree()?;
+        let store = run_query::<AndGate>(&driver, &key, &Config::default())?;
+
+        // Each gate joins itself on its own output
+        let self_join = store
+            .join_on_cell("AndGate", "y", "AndGate", "y")
+            .ok_or("Table not found")?;
+        assert_eq!(self_join.len(), 3);
+
+        // Only the root gate has its `a` input driven by another gate
+        let chained = store
+            .join_on_cell("AndGate", "y", "AndGate", "a")
+            .ok_or("Table not found")?;
+        assert_eq!(chained.len(), 1);
+        Ok(())
+    }
+}
@@ -33,6 +33,8 @@ use contracts::*;
 pub struct Table<T> {
     /// The underlying columnar storage.
     store: ColumnStore,
+    /// Whether the search stopped at a configured limit.
+    truncated: bool,
     /// Type marker.
     _marker: PhantomData<T>,
 }
@@ -66,6 +68,7 @@ where
 
         Ok(Self {
             store,
+            truncated: false,
             _marker: PhantomData,
         })
     }
@@ -86,10 +89,26 @@ where
 
         Ok(Self {
             store,
+            truncated: self.truncated,
             _marker: PhantomData,
         })
     }
 
+    /// Marks whether the search producing this table stopped at a limit.
+    #[must_use]
+    pub const fn with_truncated(mut self, truncated: bool) -> Self {
+        self.truncated = truncated;
+        self
+    }
+
+    /// Check whether the search producing this table stopped at a configured
+    /// limit (e.g. `max_matches` or `max_total_nodes`), so matches may be
+    /// missing.
+    #[inline]
+    pub const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
     /// Get the number of rows (matches) in this table.
     #[inline]
     pub const fn len(&self) -> usize {
@@ -282,6 +301,9 @@ impl<T> std::fmt::Display for Table<T> {
         let type_name = std::any::type_name::<T>();
         writeln!(f, "\nTable: {}", type_name)?;
         writeln!(f, "Rows: {}", self.store.height())?;
+        if self.truncated {
+            writeln!(f, "Truncated: search stopped at a configured limit")?;
+        }
         writeln!(f, "Columns: {}", self.store.column_names().len())?;
         write!(f, "{}", self.store)?;
         Ok(())
@@ -310,18 +332,27 @@ pub trait AnyTable: Send + Sync + std::fmt::Display + 'static {
         self.len() == 0
     }
 
+    /// Whether the search producing this table stopped at a configured limit.
+    fn is_truncated(&self) -> bool;
+
     /// Get the type name of the table.
     fn type_name(&self) -> &str;
 
     /// Get the `TypeId` of the pattern stored in this table.
     fn pattern_type_id(&self) -> std::any::TypeId;
 
+    /// Get the schema describing this table's columns.
+    fn schema(&self) -> &'static PatternSchema;
+
     /// Get a submodule reference (Row Index + `TypeId`) for a given column.
     fn get_sub_ref(&self, row_idx: usize, col_name: &str) -> Option<(RowIndex, std::any::TypeId)>;
 
     /// Get a wire by single column name (no path traversal).
     fn get_wire(&self, row_idx: usize, col_name: &str) -> Option<Wire>;
 
+    /// Collect every wire of a row, descending into submodule rows via `store`.
+    fn row_wires(&self, row_idx: usize, store: &Store) -> Vec<Wire>;
+
     /// Resolve a selector path within a specific row to a wire
     fn resolve_path(
         &self,
@@ -330,6 +361,15 @@ pub trait AnyTable: Send + Sync + std::fmt::Display + 'static {
         ctx: &crate::session::ExecutionContext,
     ) -> Option<Wire>;
 
+    /// Resolve a selector path within a specific row to a wire, following
+    /// submodule references through the tables of a finished `Store`.
+    fn resolve_path_in_store(
+        &self,
+        row_idx: usize,
+        selector: Selector<'_>,
+        store: &Store,
+    ) -> Option<Wire>;
+
     /// Resolve a selector path within a specific row to a wire bundle.
     /// Used for set-based connectivity checking with `#[connect_any]`.
     fn resolve_bundle_path(
@@ -359,10 +399,18 @@ where
         self.store.height()
     }
 
+    fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
     fn type_name(&self) -> &str {
         std::any::type_name::<T>()
     }
 
+    fn schema(&self) -> &'static PatternSchema {
+        T::schema()
+    }
+
     fn get_wire(&self, row_idx: usize, col_name: &str) -> Option<Wire> {
         // O(1) lookup
         let col_idx = T::schema().index_of(col_name)?;
@@ -405,6 +453,25 @@ where
         }
     }
 
+    fn row_wires(&self, row_idx: usize, store: &Store) -> Vec<Wire> {
+        let mut wires = Vec::new();
+        for col_def in T::schema().columns() {
+            match self.store.get_cell(col_def.name, row_idx) {
+                ColumnEntry::Wire(wire) => wires.push(wire.clone()),
+                ColumnEntry::WireArray(bundle) => wires.extend(bundle.iter().cloned()),
+                ColumnEntry::Sub(idx) => {
+                    if let Some(sub_table) =
+                        col_def.as_submodule().and_then(|tid| store.get_any(tid))
+                    {
+                        wires.extend(sub_table.row_wires(idx.raw() as usize, store));
+                    }
+                }
+                ColumnEntry::Null | ColumnEntry::Meta(_) => {}
+            }
+        }
+        wires
+    }
+
     fn resolve_path(
         &self,
         row_idx: usize,
@@ -444,6 +511,35 @@ where
         sub_table.resolve_path(sub_row_idx, selector.tail(), ctx)
     }
 
+    fn resolve_path_in_store(
+        &self,
+        row_idx: usize,
+        selector: Selector<'_>,
+        store: &Store,
+    ) -> Option<Wire> {
+        if selector.is_empty() {
+            return None;
+        }
+
+        let head = selector.head()?;
+        if selector.len() == 1 {
+            return match self.store.get_cell(head, row_idx) {
+                ColumnEntry::Wire(w) => Some(w.clone()),
+                _ => None,
+            };
+        }
+
+        let col_idx = T::schema().index_of(head)?;
+        let sub_type_id = T::schema().column(col_idx).as_submodule()?;
+        let sub_row_idx = match self.store.get_cell(head, row_idx) {
+            ColumnEntry::Sub(idx) => idx.raw() as usize,
+            _ => return None,
+        };
+
+        let sub_table = store.get_any(sub_type_id)?;
+        sub_table.resolve_path_in_store(sub_row_idx, selector.tail(), store)
+    }
+
     fn resolve_bundle_path(
         &self,
         row_idx: usize,
@@ -493,3 +589,26 @@ where
         Self::to_csv(self, path)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::small_and_tree;
+    use crate::traits::netlist::test::AndGate;
+
+    use super::*;
+
+    #[test]
+    fn test_run_query_marks_truncated_table() -> Result<(), Box<dyn std::error::Error>> {
+        let (driver, key) = small_and_tree()?;
+
+        let store = run_query::<AndGate>(&driver, &key, &Config::default())?;
+        let table = store.get::<AndGate>().ok_or("Table not found")?;
+        assert!(!table.is_truncated());
+
+        let limited = Config::builder().max_total_nodes(Some(1)).build();
+        let store = run_query::<AndGate>(&driver, &key, &limited)?;
+        let table = store.get::<AndGate>().ok_or("Table not found")?;
+        assert!(table.is_truncated());
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+//! Filtered, type-erased views over a single table.
+//!
+//! A `TableView` holds a borrowed table plus the subset of row indices that
+//! satisfied a predicate. Filtering operates on the stored columns directly,
+//! so matches can be pruned before any rehydration or rendering takes place.
+
+use super::AnyTable;
+use crate::prelude::*;
+use crate::wire::Wire;
+
+/// A filtered view over one table in a `Store`.
+///
+/// Produced by `Store::filter`. Rows are kept in table order.
+pub struct TableView<'a> {
+    /// The table the view was taken from.
+    table: &'a (dyn AnyTable + Send + Sync),
+    /// Indices of the rows that passed the filter.
+    rows: Vec<usize>,
+}
+
+impl<'a> TableView<'a> {
+    /// Build a view over `table` keeping only rows for which `predicate` holds.
+    pub(crate) fn new<F>(table: &'a (dyn AnyTable + Send + Sync), predicate: F) -> Self
+    where
+        F: Fn(&dyn AnyTable, usize) -> bool,
+    {
+        let rows = (0..table.len())
+            .filter(|&idx| predicate(table, idx))
+            .collect();
+        Self { table, rows }
+    }
+
+    /// The underlying table.
+    #[must_use]
+    pub const fn table(&self) -> &'a (dyn AnyTable + Send + Sync) {
+        self.table
+    }
+
+    /// Indices (into the underlying table) of the rows in this view.
+    #[must_use]
+    pub const fn row_indices(&self) -> &[usize] {
+        self.rows.as_slice()
+    }
+
+    /// Number of rows in the view.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Check if no rows passed the filter.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Iterate over the wires stored in `column` for each row in the view.
+    ///
+    /// Rows where the column is null or not a wire column yield `None`.
+    pub fn wires<'s>(&'s self, column: &'s str) -> impl Iterator<Item = Option<Wire>> + 's {
+        self.rows
+            .iter()
+            .map(move |&idx| self.table.get_wire(idx, column))
+    }
+}
+
+impl std::fmt::Debug for TableView<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TableView")
+            .field("type_name", &self.table.type_name())
+            .field("rows", &self.rows.len())
+            .finish()
+    }
+}
+
+/// Predicate keeping rows whose wire `column` is driven by one of `cells`.
+///
+/// Intended for `Store::filter`, e.g. to restrict findings to a region of
+/// the design identified by its cell ids.
+pub fn wire_cell_in<'c>(
+    column: &'c str,
+    cells: &'c HashSet<PhysicalCellId>,
+) -> impl Fn(&dyn AnyTable, usize) -> bool + 'c {
+    move |table, idx| {
+        table
+            .get_wire(idx, column)
+            .and_then(|wire| wire.cell_id())
+            .is_some_and(|id| cells.contains(&id))
+    }
+}
@@ -161,6 +161,108 @@ where
     Ok(())
 }
 
+/// Builds a result table for `P` from hand-written matches.
+///
+/// Lets tests that consume a `Store` (filters, joins, exports, report
+/// rendering) declare exactly the matches they need instead of searching a
+/// fixture. Ports left out of a match stay null, which makes it easy to
+/// reproduce incomplete rows.
+///
+/// ```ignore
+/// let store = SyntheticTableBuilder::<AndGate>::new()
+///     .add_match(&[("a", a), ("b", b), ("y", y)])
+///     .add_match(&[("a", a), ("y", y)])
+///     .build_store()?;
+/// ```
+pub struct SyntheticTableBuilder<P> {
+    /// Rows collected so far, in insertion order.
+    rows: Vec<EntryArray>,
+    /// Marker for the pattern type the rows belong to.
+    _marker: std::marker::PhantomData<P>,
+}
+
+impl<P> SyntheticTableBuilder<P>
+where
+    P: Pattern + Component + Send + Sync + 'static,
+{
+    /// Creates a builder with no matches.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            rows: Vec::new(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Appends a match binding each named column of `P` to a wire.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a name is not a wire column of `P`'s schema.
+    #[must_use]
+    #[track_caller]
+    pub fn add_match(mut self, port_map: &[(&str, Wire)]) -> Self {
+        let schema = P::schema();
+        let mut row = EntryArray::with_capacity(schema.defs.len());
+        for (name, wire) in port_map {
+            let idx = schema
+                .index_of(name)
+                .filter(|&idx| schema.column(idx).kind.is_wire())
+                .unwrap_or_else(|| {
+                    panic!(
+                        "{} has no wire column `{name}` (columns: {:?})",
+                        std::any::type_name::<P>(),
+                        schema.columns().iter().map(|c| c.name).collect::<Vec<_>>()
+                    )
+                });
+            row.entries[idx] = ColumnEntry::wire(wire.clone());
+        }
+        self.rows.push(row);
+        self
+    }
+
+    /// Number of matches added so far.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Check if no matches have been added.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Builds the table.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `QueryError` if the table cannot be constructed.
+    pub fn build(self) -> Result<Table<P>, QueryError> {
+        Table::new(self.rows)
+    }
+
+    /// Builds the table and wraps it in a `Store` holding only that table.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `QueryError` if the table cannot be constructed.
+    pub fn build_store(self) -> Result<Store, QueryError> {
+        let mut store = Store::new();
+        store.insert(self.build()?);
+        Ok(store)
+    }
+}
+
+impl<P> Default for SyntheticTableBuilder<P>
+where
+    P: Pattern + Component + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Helper macro to generate a boilerplate test for a pattern.
 #[macro_export]
 macro_rules! query_test {
@@ -199,3 +301,43 @@ macro_rules! query_test {
         }
     };
 }
+
+/// Opens the workspace driver and the key of the three-gate `small_and_tree`
+/// fixture shared by the in-crate tests.
+#[cfg(test)]
+pub(crate) fn small_and_tree() -> Result<(Driver, DriverKey), Box<dyn std::error::Error>> {
+    let driver = Driver::new_workspace()?;
+    let key = DriverKey::new(
+        "examples/fixtures/basic/and/verilog/small_and_tree.v",
+        "small_and_tree",
+    );
+    Ok((driver, key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::netlist::test::AndGate;
+
+    #[test]
+    fn test_synthetic_table_builder() -> Result<(), Box<dyn std::error::Error>> {
+        let wire = |cell| Wire::from(prjunnamed_netlist::Net::from_cell_index(cell));
+        let store = SyntheticTableBuilder::<AndGate>::new()
+            .add_match(&[("a", wire(1)), ("b", wire(2)), ("y", wire(3))])
+            .add_match(&[("a", wire(1)), ("y", wire(4))])
+            .build_store()?;
+
+        let table = store.get_by_type_name("AndGate").ok_or("Table not found")?;
+        assert_eq!(table.len(), 2);
+        assert!(table.get_wire(0, "b").is_some());
+        assert!(table.get_wire(1, "b").is_none());
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "no wire column `q`")]
+    fn test_synthetic_table_builder_rejects_unknown_port() {
+        let wire = Wire::from(prjunnamed_netlist::Net::from_cell_index(1));
+        let _ = SyntheticTableBuilder::<AndGate>::new().add_match(&[("q", wire)]);
+    }
+}
@@ -0,0 +1,21 @@
+//! Debug override logic feeding a register enable.
+
+use svql_query::prelude::*;
+
+/// Pattern for a functional enable that a debug signal can force high.
+#[derive(Debug, Clone, Netlist)]
+#[netlist(
+    file = "examples/patterns/security/debug/debug_enable/rtlil/debug_enable.il",
+    module = "debug_enable"
+)]
+pub struct DebugEnable {
+    /// Regular (lock-gated) write enable.
+    #[port(input)]
+    pub func_en: Wire,
+    /// Debug/JTAG override signal.
+    #[port(input)]
+    pub dbg_en: Wire,
+    /// Combined enable.
+    #[port(output)]
+    pub en: Wire,
+}
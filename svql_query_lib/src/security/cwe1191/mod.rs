@@ -0,0 +1,47 @@
+//! CWE-1191: On-Chip Debug and Test Interface With Improper Access Control.
+
+/// Debug override logic feeding register enables.
+pub mod debug_enable;
+
+use crate::security::primitives::locked_register::LockedRegister;
+use debug_enable::DebugEnable;
+use svql_query::prelude::*;
+
+/// Top-level pattern for CWE-1191.
+///
+/// A debug override ORed into the enable of a locked register, where one of
+/// the override's inputs comes straight from a primary input rather than
+/// through an authentication gate.
+#[derive(Debug, Clone, Composite)]
+#[connection(from = ["debug_enable", "en"], to = ["locked_register", "write_en"])]
+#[filter(Cwe1191::is_unauthenticated)]
+pub struct Cwe1191 {
+    /// The logic letting a debug signal force the enable.
+    #[submodule]
+    pub debug_enable: DebugEnable,
+    /// The register reachable through the debug override.
+    #[submodule]
+    pub locked_register: LockedRegister,
+}
+
+impl Cwe1191 {
+    /// Inputs of the debug override that may carry the raw debug signal.
+    ///
+    /// Both are checked because the OR is commutative.
+    const OVERRIDE_INPUTS: [Selector<'static>; 2] = [
+        Selector::static_path(&["debug_enable", "func_en"]),
+        Selector::static_path(&["debug_enable", "dbg_en"]),
+    ];
+
+    /// Returns true if an input of the debug override is driven directly by a
+    /// primary input, i.e. no gate sits between the debug pin and the enable.
+    pub fn is_unauthenticated(row: &Row<Self>, ctx: &ExecutionContext) -> bool {
+        let index = ctx.haystack_design().index();
+        Self::OVERRIDE_INPUTS.into_iter().any(|selector| {
+            row.resolve(selector, ctx)
+                .and_then(|wire| wire.cell_id())
+                .and_then(|id| index.get_cell_by_id(id.storage_key() as usize))
+                .is_some_and(|cell| cell.cell_type().is_input())
+        })
+    }
+}
@@ -0,0 +1,58 @@
+//! CWE-1231: Improper Prevention of Lock Bit Modification.
+
+use crate::security::primitives::locked_register::LockedRegister;
+use svql_query::prelude::*;
+
+/// Top-level pattern for CWE-1231.
+///
+/// A lock bit gating writes to a protected register, where the lock bit's own
+/// write enable does not depend on the lock bit, so software can clear it
+/// again after it has been set.
+#[derive(Debug, Clone, Composite)]
+#[connection(from = ["lock_reg", "data_out"], to = ["protected_reg", "write_en"], kind = "cone")]
+#[filter(Cwe1231::is_modifiable_lock)]
+pub struct Cwe1231 {
+    /// The lock/trust bit.
+    #[submodule]
+    pub lock_reg: LockedRegister,
+    /// The register whose writes are gated by the lock bit.
+    #[submodule]
+    pub protected_reg: LockedRegister,
+}
+
+impl Cwe1231 {
+    /// Output of the lock bit.
+    const LOCK_OUT: Selector<'static> = Selector::static_path(&["lock_reg", "data_out"]);
+    /// Write enable of the lock bit.
+    const LOCK_EN: Selector<'static> = Selector::static_path(&["lock_reg", "write_en"]);
+    /// Output of the protected register.
+    const PROTECTED_OUT: Selector<'static> = Selector::static_path(&["protected_reg", "data_out"]);
+
+    /// Returns true if the lock bit is missing from the combinational cone of
+    /// its own write enable.
+    ///
+    /// That the lock bit gates the protected register's write enable is
+    /// already enforced by the fan-in cone connection, which the join uses to
+    /// pair only registers that are actually gated by the candidate lock.
+    pub fn is_modifiable_lock(row: &Row<Self>, ctx: &ExecutionContext) -> bool {
+        let index = ctx.haystack_design().index();
+        let resolve = |selector| row.resolve(selector, ctx);
+
+        let (Some(lock_out), Some(lock_en), Some(protected_out)) = (
+            resolve(Self::LOCK_OUT),
+            resolve(Self::LOCK_EN),
+            resolve(Self::PROTECTED_OUT),
+        ) else {
+            return false;
+        };
+
+        let Some(lock_cell) = driver_cell(&lock_out, index) else {
+            return false;
+        };
+        if driver_cell(&protected_out, index) == Some(lock_cell) {
+            return false;
+        }
+
+        !combinational_fanin(&lock_en, index).contains(&lock_cell)
+    }
+}
@@ -0,0 +1,105 @@
+//! CWE-1276: Hardware Child Block Incorrectly Connected to Parent System.
+//!
+//! Checked here in its narrow "logic contradicts documentation" form: a
+//! register annotated with `(* svql_expected_reset = "..." *)` whose reset
+//! wiring loads a different constant.
+
+use prjunnamed_netlist::{Cell, Const, Trit};
+use svql_query::prelude::*;
+
+svql_query::define_dff_primitive!(
+    DffCwe1276,
+    [(clk, input), (d, input), (q, output)],
+    |cell| {
+        if let prjunnamed_netlist::Cell::Dff(ff) = cell {
+            ff.has_reset() || ff.has_clear()
+        } else {
+            false
+        }
+    }
+);
+
+/// Pattern identifying registers whose reset value contradicts their
+/// documented expected reset.
+#[derive(Debug, Clone, Variant)]
+#[variant_ports(input(clk), input(data_in), output(data_out))]
+#[filter(Cwe1276::contradicts_expected_reset)]
+pub enum Cwe1276 {
+    /// Instance of the resettable DFF matcher.
+    #[map(clk = ["clk"], data_in = ["d"], data_out = ["q"])]
+    Cwe1276Inst(DffCwe1276),
+}
+
+impl Cwe1276 {
+    /// Attribute carrying the documented reset value, as a Verilog literal.
+    pub const EXPECTED_RESET_ATTR: &'static str = "svql_expected_reset";
+
+    /// Returns true if the register carries the expected-reset attribute and
+    /// its actual reset constant differs from it.
+    ///
+    /// The asynchronous clear value is compared when the register has one,
+    /// otherwise the synchronous reset value. Registers without the
+    /// attribute, with an unparsable attribute, or with an undefined reset
+    /// bit are not reported.
+    pub fn contradicts_expected_reset(row: &Row<Self>, ctx: &ExecutionContext) -> bool {
+        let index = ctx.haystack_design().index();
+        let Some(cell) = Self::data_out_cell(row, index) else {
+            return false;
+        };
+        let Some(expected) = cell
+            .attr(Self::EXPECTED_RESET_ATTR)
+            .and_then(|value| parse_verilog_literal(&value))
+        else {
+            return false;
+        };
+        let Cell::Dff(ff) = cell.get().into_owned() else {
+            return false;
+        };
+
+        let actual = if ff.has_clear() {
+            ff.clear_value
+        } else {
+            ff.reset_value
+        };
+        const_to_u128(actual).is_some_and(|actual| actual != expected)
+    }
+}
+
+/// Parses a Verilog integer literal such as `8'hA5`, `'b1010` or `42`.
+///
+/// Surrounding quotes and `_` separators are ignored. Returns `None` for
+/// `x`/`z` digits and for values wider than 128 bits.
+fn parse_verilog_literal(text: &str) -> Option<u128> {
+    let text = text.trim().trim_matches('"').replace('_', "");
+    let (radix, digits) = match text.split_once('\'') {
+        Some((_, rest)) => {
+            let rest = rest.trim_start_matches(['s', 'S']);
+            let mut chars = rest.chars();
+            let radix = match chars.next()?.to_ascii_lowercase() {
+                'b' => 2,
+                'o' => 8,
+                'd' => 10,
+                'h' => 16,
+                _ => return None,
+            };
+            (radix, chars.as_str().to_owned())
+        }
+        None => (10, text),
+    };
+    u128::from_str_radix(&digits, radix).ok()
+}
+
+/// Interprets a constant as an unsigned integer, LSB first.
+///
+/// Returns `None` if any bit is undefined or the constant is wider than
+/// 128 bits.
+fn const_to_u128(value: Const) -> Option<u128> {
+    value
+        .into_iter()
+        .enumerate()
+        .try_fold(0u128, |acc, (bit, trit)| match trit {
+            Trit::One => Some(acc | 1u128.checked_shl(u32::try_from(bit).ok()?)?),
+            Trit::Zero => Some(acc),
+            Trit::Undef => None,
+        })
+}
@@ -1,15 +1,24 @@
 //! Security pattern implementations (CWE vulnerabilities).
 
+/// Detects debug interfaces reaching locked registers without authentication.
+pub mod cwe1191;
+/// Detects lock bits that remain writable after being set.
+pub mod cwe1231;
 /// Detects bypass of locks via internal modes.
 pub mod cwe1234;
 /// Detects uninitialized values on reset.
 pub mod cwe1271;
+/// Detects reset values contradicting their documented expected reset.
+pub mod cwe1276;
 /// Detects access control checks occurring after asset access.
 pub mod cwe1280;
 /// Reusable security-focused hardware building blocks.
 pub mod primitives;
 
+pub use cwe1191::*;
+pub use cwe1231::*;
 pub use cwe1234::*;
 pub use cwe1271::*;
+pub use cwe1276::*;
 pub use cwe1280::*;
 pub use primitives::*;
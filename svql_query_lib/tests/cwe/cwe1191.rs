@@ -0,0 +1,23 @@
+use crate::query_test;
+use svql_query::prelude::*;
+use svql_query_lib::security::cwe1191::Cwe1191;
+
+const fn cwe1191_config(c: ConfigBuilder) -> ConfigBuilder {
+    c.match_length(MatchLength::NeedleSubsetHaystack)
+}
+
+query_test!(
+    name: test_cwe1191_simple,
+    query: Cwe1191,
+    haystack: ("examples/fixtures/cwes/cwe1191/cwe1191_simple.v", "cwe1191_simple"),
+    expect: 1,
+    config: cwe1191_config
+);
+
+query_test!(
+    name: test_cwe1191_fixed,
+    query: Cwe1191,
+    haystack: ("examples/fixtures/cwes/cwe1191/cwe1191_fixed.v", "cwe1191_fixed"),
+    expect: 0,
+    config: cwe1191_config
+);
@@ -0,0 +1,23 @@
+use crate::query_test;
+use svql_query::prelude::*;
+use svql_query_lib::security::cwe1231::Cwe1231;
+
+const fn cwe1231_config(c: ConfigBuilder) -> ConfigBuilder {
+    c.match_length(MatchLength::NeedleSubsetHaystack)
+}
+
+query_test!(
+    name: test_cwe1231_simple,
+    query: Cwe1231,
+    haystack: ("examples/fixtures/cwes/cwe1231/cwe1231_simple.v", "cwe1231_simple"),
+    expect: 1,
+    config: cwe1231_config
+);
+
+query_test!(
+    name: test_cwe1231_fixed,
+    query: Cwe1231,
+    haystack: ("examples/fixtures/cwes/cwe1231/cwe1231_fixed.v", "cwe1231_fixed"),
+    expect: 0,
+    config: cwe1231_config
+);
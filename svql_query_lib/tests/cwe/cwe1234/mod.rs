@@ -3,6 +3,7 @@ mod unlock_logic;
 use crate::query_test;
 use svql_query::prelude::*;
 use svql_query_lib::security::cwe1234::Cwe1234;
+use svql_query_lib::security::cwe1234::unlock_logic::UnlockLogic;
 
 // Common config for CWE-1234 tests
 const fn cwe1234_config(c: ConfigBuilder) -> ConfigBuilder {
@@ -137,3 +138,53 @@ query_test!(
     expect: 0,
     config: cwe1234_config
 );
+
+/// Collects the sorted fan-in depth of each CWE-1234 match's unlock signal.
+fn unlock_depths(path: &str, module: &str) -> Result<Vec<usize>, Box<dyn std::error::Error>> {
+    let driver = Driver::new_workspace()?;
+    let key = DriverKey::new(path, module);
+    let config = cwe1234_config(Config::builder()).build();
+
+    let store = svql_query::run_query::<Cwe1234>(&driver, &key, &config)?;
+    let design = driver.get_design(&key, &config.haystack_options)?;
+    let cwe_table = store.get::<Cwe1234>().ok_or("Cwe1234 table missing")?;
+    let unlock_table = store
+        .get::<UnlockLogic>()
+        .ok_or("UnlockLogic table missing")?;
+
+    let mut depths = Vec::new();
+    for (_, row) in cwe_table.rows() {
+        let unlock_ref = row
+            .sub::<UnlockLogic>("unlock_logic")
+            .ok_or("no unlock_logic")?;
+        let unlock_row = unlock_table
+            .row(unlock_ref)
+            .ok_or("dangling unlock_logic")?;
+        let unlock = unlock_row.wire("unlock").ok_or("no unlock wire")?;
+        depths.push(fanin_depth(unlock, design.index()));
+    }
+    depths.sort_unstable();
+    Ok(depths)
+}
+
+#[test]
+fn test_cwe1234_fanin_depth_not_deep() -> Result<(), Box<dyn std::error::Error>> {
+    // AND over OR trees of one, two and three levels, each topped by a NOT
+    let depths = unlock_depths(
+        "examples/fixtures/cwes/cwe1234/cwe1234_not_deep.v",
+        "cwe1234_not_deep",
+    )?;
+    assert_eq!(depths, vec![3, 4, 5]);
+    Ok(())
+}
+
+#[test]
+fn test_cwe1234_fanin_depth_deep() -> Result<(), Box<dyn std::error::Error>> {
+    // AND over a left-leaning chain of three ORs topped by a NOT
+    let depths = unlock_depths(
+        "examples/fixtures/cwes/cwe1234/cwe1234_deep.v",
+        "cwe1234_deep",
+    )?;
+    assert_eq!(depths, vec![5]);
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use crate::query_test;
+use svql_query::prelude::*;
+use svql_query_lib::security::cwe1276::Cwe1276;
+
+const fn cwe1276_config(c: ConfigBuilder) -> ConfigBuilder {
+    c.match_length(MatchLength::NeedleSubsetHaystack)
+}
+
+query_test!(
+    name: test_cwe1276_simple,
+    query: Cwe1276,
+    haystack: ("examples/fixtures/cwes/cwe1276/cwe1276_simple.v", "cwe1276_simple"),
+    expect: 1,
+    config: cwe1276_config
+);
+
+query_test!(
+    name: test_cwe1276_fixed,
+    query: Cwe1276,
+    haystack: ("examples/fixtures/cwes/cwe1276/cwe1276_fixed.v", "cwe1276_fixed"),
+    expect: 0,
+    config: cwe1276_config
+);
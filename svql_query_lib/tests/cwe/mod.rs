@@ -1,3 +1,6 @@
+mod cwe1191;
+mod cwe1231;
 mod cwe1234;
 mod cwe1271;
+mod cwe1276;
 mod cwe1280;
@@ -142,3 +142,37 @@ query_test!(
     expect: 5,
     config: cwe1234_config
 );
+
+/// Runs `LockedRegister` on the multi-register fixture and returns the
+/// `data_out` wire of every result row.
+fn multi_reg_outputs(dedupe: Dedupe) -> Vec<Wire> {
+    let driver = Driver::new_workspace().unwrap();
+    let key = DriverKey::new(
+        "examples/fixtures/cwes/cwe1234/cwe1234_multi_reg.v",
+        "cwe1234_multi_reg",
+    );
+    let config = cwe1234_config(Config::builder()).dedupe(dedupe).build();
+
+    let store = svql_query::run_query::<LockedRegister>(&driver, &key, &config).unwrap();
+    let table = store
+        .get::<LockedRegister>()
+        .expect("Table should be present");
+    table
+        .rows()
+        .map(|(_, row)| row.wire("data_out").expect("data_out is a port").clone())
+        .collect()
+}
+
+#[test]
+fn test_locked_reg_multi_reg_dedupe_by_output_driver() {
+    let all = multi_reg_outputs(Dedupe::All);
+    let by_driver = multi_reg_outputs(Dedupe::ByOutputDriver);
+
+    assert!(!by_driver.is_empty());
+    assert!(by_driver.len() <= all.len());
+
+    // One finding per protected register, covering every register found before
+    let unique: std::collections::HashSet<&Wire> = by_driver.iter().collect();
+    assert_eq!(unique.len(), by_driver.len());
+    assert!(all.iter().all(|wire| unique.contains(wire)));
+}
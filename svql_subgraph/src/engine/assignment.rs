@@ -1,22 +1,34 @@
 //! Mapping between needle and haystack cells.
 
+use std::path::Path;
+
 use contracts::*;
+use serde::{Deserialize, Serialize};
 use svql_common::*;
 
 use crate::cell::GraphNodeIdx;
 
 /// A collection of mappings found during a search.
-#[derive(Clone, Debug, Default)]
+///
+/// Serializes to JSON (see [`Self::to_json_file`]), so the output of a search
+/// can be captured once and replayed against the same haystack later, e.g.
+/// in tests that should not depend on Yosys.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct AssignmentSet {
     /// The specific mappings found in the haystack.
     pub items: Vec<SingleAssignment>,
+    /// Whether a search-breadth cap cut the search short, so `items` may be incomplete.
+    pub truncated: bool,
 }
 
 impl AssignmentSet {
     /// Creates a set from a list of assignments.
     #[must_use]
     pub const fn new(items: Vec<SingleAssignment>) -> Self {
-        Self { items }
+        Self {
+            items,
+            truncated: false,
+        }
     }
 
     /// Returns true if no matches were found.
@@ -30,10 +42,139 @@ impl AssignmentSet {
     pub const fn len(&self) -> usize {
         self.items.len()
     }
+
+    /// Loads a set of assignments previously written by [`Self::to_json_file`].
+    ///
+    /// The indices are only meaningful for the needle and haystack indices
+    /// the set was produced from.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid assignment set.
+    pub fn from_json_file(path: impl AsRef<Path>) -> Result<Self, Box<dyn core::error::Error>> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)?;
+        serde_json::from_str(&contents)
+            .map_err(|e| format!("Invalid assignment file {}: {e}", path.display()).into())
+    }
+
+    /// Writes the assignments to a JSON file.
+    ///
+    /// Each assignment is stored as its needle-to-haystack pairs sorted by
+    /// needle index, so the same search always produces the same file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the set cannot be serialized or the file cannot be
+    /// written.
+    pub fn to_json_file(&self, path: impl AsRef<Path>) -> Result<(), Box<dyn core::error::Error>> {
+        let contents = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+
+    /// Builds a reverse index from haystack cells to the matches covering them.
+    ///
+    /// Building is linear in the total size of all matches; lookups through
+    /// [`Self::matches_covering`] are then constant time per cell.
+    #[must_use]
+    pub fn coverage_index(&self) -> CoverageIndex {
+        let mut by_haystack: HashMap<GraphNodeIdx, Vec<usize>> = HashMap::new();
+        for (match_idx, assignment) in self.items.iter().enumerate() {
+            for haystack in assignment.haystack_mapping().keys() {
+                by_haystack.entry(*haystack).or_default().push(match_idx);
+            }
+        }
+        for matches in by_haystack.values_mut() {
+            matches.sort_unstable();
+        }
+        CoverageIndex { by_haystack }
+    }
+
+    /// Returns every match that maps some needle cell onto `haystack`.
+    ///
+    /// `index` must have been built from this set by [`Self::coverage_index`].
+    /// To look up a cell by its netlist debug id, resolve it first with
+    /// `GraphIndex::get_cell_index_by_debug_id`.
+    #[must_use]
+    pub fn matches_covering(
+        &self,
+        index: &CoverageIndex,
+        haystack: GraphNodeIdx,
+    ) -> Vec<&SingleAssignment> {
+        index
+            .by_haystack
+            .get(&haystack)
+            .map_or_else(Vec::new, |matches| {
+                matches.iter().map(|&idx| &self.items[idx]).collect()
+            })
+    }
 }
 
-/// A partial mapping of cell assignments during the search.
+impl IntoIterator for AssignmentSet {
+    type Item = SingleAssignment;
+    type IntoIter = std::vec::IntoIter<SingleAssignment>;
+
+    /// Consumes the set, yielding matches in order without copying them.
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a AssignmentSet {
+    type Item = &'a SingleAssignment;
+    type IntoIter = std::slice::Iter<'a, SingleAssignment>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+/// Returns true if the two assignments cover at least one common haystack cell.
+#[must_use]
+pub fn assignments_overlap(a: &SingleAssignment, b: &SingleAssignment) -> bool {
+    a.haystack_to_needle
+        .keys()
+        .any(|haystack| b.haystack_to_needle.contains_key(haystack))
+}
+
+/// Returns the Jaccard similarity of the haystack cells covered by two assignments.
+///
+/// The result is in `0.0..=1.0`, where `1.0` means both cover exactly the same
+/// cells. Boundary cells (matched I/O ports) are included, so matches that only
+/// share e.g. a clock input still report a small non-zero overlap.
+#[must_use]
+pub fn overlap_ratio(a: &SingleAssignment, b: &SingleAssignment) -> f64 {
+    let shared = a
+        .haystack_to_needle
+        .keys()
+        .filter(|haystack| b.haystack_to_needle.contains_key(*haystack))
+        .count();
+    let union = a.haystack_to_needle.len() + b.haystack_to_needle.len() - shared;
+
+    if union == 0 {
+        return 0.0;
+    }
+
+    shared as f64 / union as f64
+}
+
+/// Reverse lookup from haystack cells to the indices of matches covering them.
 #[derive(Clone, Debug, Default)]
+pub struct CoverageIndex {
+    /// Match indices (ascending) keyed by covered haystack cell.
+    by_haystack: HashMap<GraphNodeIdx, Vec<usize>>,
+}
+
+/// A partial mapping of cell assignments during the search.
+///
+/// Serialized as the list of needle-to-haystack pairs; the reverse mapping is
+/// rebuilt on deserialization.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(
+    into = "Vec<(GraphNodeIdx, GraphNodeIdx)>",
+    from = "Vec<(GraphNodeIdx, GraphNodeIdx)>"
+)]
 pub struct SingleAssignment {
     /// Pattern to Design cell index mapping
     needle_to_haystack: HashMap<GraphNodeIdx, GraphNodeIdx>,
@@ -132,6 +273,12 @@ impl SingleAssignment {
         self.needle_to_haystack.is_empty()
     }
 
+    /// Returns the set of haystack cells covered by this mapping.
+    #[must_use]
+    pub fn haystack_cells(&self) -> HashSet<GraphNodeIdx> {
+        self.haystack_to_needle.keys().copied().collect()
+    }
+
     /// Access the raw haystack-to-needle mapping.
     #[must_use]
     pub const fn haystack_mapping(&self) -> &HashMap<GraphNodeIdx, Vec<GraphNodeIdx>> {
@@ -156,6 +303,20 @@ impl SingleAssignment {
         sig
     }
 
+    /// Returns every needle-to-haystack pair as raw indices, sorted by needle.
+    ///
+    /// Unlike the signatures, this identifies the assignment completely and
+    /// is used as a tie-breaker for deterministic ordering.
+    pub(super) fn sorted_pairs(&self) -> Vec<(usize, usize)> {
+        let mut pairs: Vec<(usize, usize)> = self
+            .needle_to_haystack
+            .iter()
+            .map(|(needle, haystack)| (needle.as_usize(), haystack.as_usize()))
+            .collect();
+        pairs.sort_unstable();
+        pairs
+    }
+
     /// Generates a bitmask-filtered signature for deduplication.
     pub fn signature_with_mask(&self, mask: &[bool]) -> Vec<u32> {
         self.needle_to_haystack
@@ -202,6 +363,24 @@ impl SingleAssignment {
     }
 }
 
+impl From<SingleAssignment> for Vec<(GraphNodeIdx, GraphNodeIdx)> {
+    fn from(assignment: SingleAssignment) -> Self {
+        let mut pairs: Self = assignment.needle_to_haystack.into_iter().collect();
+        pairs.sort_unstable();
+        pairs
+    }
+}
+
+impl From<Vec<(GraphNodeIdx, GraphNodeIdx)>> for SingleAssignment {
+    fn from(pairs: Vec<(GraphNodeIdx, GraphNodeIdx)>) -> Self {
+        let mut assignment = Self::new();
+        for (needle, haystack) in pairs {
+            assignment.assign(needle, haystack);
+        }
+        assignment
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -239,6 +418,72 @@ mod tests {
         assert!(needle_cells.contains(&n2));
     }
 
+    #[test]
+    fn test_assignment_set_into_iter_preserves_order() {
+        let items: Vec<SingleAssignment> = (0..3)
+            .map(|i| {
+                let mut assignment = SingleAssignment::new();
+                assignment.assign(GraphNodeIdx::new(0), GraphNodeIdx::new(i));
+                assignment
+            })
+            .collect();
+        let set = AssignmentSet::new(items);
+
+        let borrowed: Vec<_> = (&set)
+            .into_iter()
+            .map(|a| a.get_haystack_cell(GraphNodeIdx::new(0)))
+            .collect();
+        let owned: Vec<_> = set
+            .into_iter()
+            .map(|a| a.get_haystack_cell(GraphNodeIdx::new(0)))
+            .collect();
+
+        assert_eq!(borrowed, owned);
+        assert_eq!(owned[2], Some(GraphNodeIdx::new(2)));
+    }
+
+    #[test]
+    fn test_matches_covering() {
+        let mut first = SingleAssignment::new();
+        first.assign(GraphNodeIdx::new(1), GraphNodeIdx::new(10));
+        first.assign(GraphNodeIdx::new(2), GraphNodeIdx::new(11));
+
+        let mut second = SingleAssignment::new();
+        second.assign(GraphNodeIdx::new(1), GraphNodeIdx::new(11));
+        second.assign(GraphNodeIdx::new(2), GraphNodeIdx::new(12));
+
+        let set = AssignmentSet::new(vec![first, second]);
+        let index = set.coverage_index();
+
+        assert_eq!(set.matches_covering(&index, GraphNodeIdx::new(10)).len(), 1);
+        assert_eq!(set.matches_covering(&index, GraphNodeIdx::new(11)).len(), 2);
+        assert!(
+            set.matches_covering(&index, GraphNodeIdx::new(13))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_overlap() {
+        let mut a = SingleAssignment::new();
+        a.assign(GraphNodeIdx::new(1), GraphNodeIdx::new(10));
+        a.assign(GraphNodeIdx::new(2), GraphNodeIdx::new(11));
+
+        let mut b = SingleAssignment::new();
+        b.assign(GraphNodeIdx::new(1), GraphNodeIdx::new(11));
+        b.assign(GraphNodeIdx::new(2), GraphNodeIdx::new(12));
+
+        let mut c = SingleAssignment::new();
+        c.assign(GraphNodeIdx::new(1), GraphNodeIdx::new(20));
+
+        assert!(assignments_overlap(&a, &b));
+        assert!(!assignments_overlap(&a, &c));
+        assert!((overlap_ratio(&a, &b) - 1.0 / 3.0).abs() < f64::EPSILON);
+        assert!((overlap_ratio(&a, &a) - 1.0).abs() < f64::EPSILON);
+        assert!(overlap_ratio(&a, &c).abs() < f64::EPSILON);
+        assert_eq!(a.haystack_cells().len(), 2);
+    }
+
     #[test]
     fn test_assignment_remove() {
         let mut assignment = SingleAssignment::new();
@@ -252,6 +497,20 @@ mod tests {
         assert_eq!(assignment.len(), 0);
         assert!(assignment.is_empty());
     }
+
+    #[test]
+    fn test_assignment_json_round_trip() {
+        let mut assignment = SingleAssignment::new();
+        assignment.assign(GraphNodeIdx::new(2), GraphNodeIdx::new(20));
+        assignment.assign(GraphNodeIdx::new(1), GraphNodeIdx::new(10));
+
+        let json = serde_json::to_string(&assignment).unwrap();
+        assert_eq!(json, "[[1,10],[2,20]]");
+
+        let restored: SingleAssignment = serde_json::from_str(&json).unwrap();
+        assert!(restored.is_consistent());
+        assert_eq!(restored.needle_mapping(), assignment.needle_mapping());
+    }
 }
 
 #[cfg(test)]
@@ -6,7 +6,7 @@
 use super::assignment::SingleAssignment;
 use crate::SubgraphMatcherCore;
 use crate::cell::GraphNodeIdx;
-use prjunnamed_netlist::{Cell, FlipFlop, Net, Value};
+use prjunnamed_netlist::{Cell, Design, FlipFlop, Net, Value};
 
 impl SubgraphMatcherCore<'_, '_, '_> {
     /// Validates that the haystack cell's inputs match the mapped inputs of the needle cell.
@@ -40,39 +40,24 @@ impl SubgraphMatcherCore<'_, '_, '_> {
         match (needle_cell, haystack_cell) {
             (Buf(p_value), Buf(d_value)) => self.values_match_fan_in(p_value, d_value, mapping),
             (Not(p_value), Not(d_value)) => self.values_match_fan_in(p_value, d_value, mapping),
-            (And(p_a_value, p_b_value), And(d_a_value, d_b_value)) => {
-                let a_matches = self.values_match_fan_in(p_a_value, d_a_value, mapping);
-                let b_matches = self.values_match_fan_in(p_b_value, d_b_value, mapping);
-                let result_normal = a_matches && b_matches;
-
-                let a_swapped = self.values_match_fan_in(p_a_value, d_b_value, mapping);
-                let b_swapped = self.values_match_fan_in(p_b_value, d_a_value, mapping);
-                let result_swapped = a_swapped && b_swapped;
-
-                result_normal || result_swapped
-            }
-            (Or(p_a_value, p_b_value), Or(d_a_value, d_b_value)) => {
-                let a_matches = self.values_match_fan_in(p_a_value, d_a_value, mapping);
-                let b_matches = self.values_match_fan_in(p_b_value, d_b_value, mapping);
-                let result_normal = a_matches && b_matches;
-
-                let a_swapped = self.values_match_fan_in(p_a_value, d_b_value, mapping);
-                let b_swapped = self.values_match_fan_in(p_b_value, d_a_value, mapping);
-                let result_swapped = a_swapped && b_swapped;
-
-                result_normal || result_swapped
-            }
-            (Xor(p_a_value, p_b_value), Xor(d_a_value, d_b_value)) => {
-                let a_matches = self.values_match_fan_in(p_a_value, d_a_value, mapping);
-                let b_matches = self.values_match_fan_in(p_b_value, d_b_value, mapping);
-                let result_normal = a_matches && b_matches;
-
-                let a_swapped = self.values_match_fan_in(p_a_value, d_b_value, mapping);
-                let b_swapped = self.values_match_fan_in(p_b_value, d_a_value, mapping);
-                let result_swapped = a_swapped && b_swapped;
-
-                result_normal || result_swapped
-            }
+            (And(p_a_value, p_b_value), And(d_a_value, d_b_value)) => self
+                .commutative_values_match_fan_in(
+                    (p_a_value, p_b_value),
+                    (d_a_value, d_b_value),
+                    mapping,
+                ),
+            (Or(p_a_value, p_b_value), Or(d_a_value, d_b_value)) => self
+                .commutative_values_match_fan_in(
+                    (p_a_value, p_b_value),
+                    (d_a_value, d_b_value),
+                    mapping,
+                ),
+            (Xor(p_a_value, p_b_value), Xor(d_a_value, d_b_value)) => self
+                .commutative_values_match_fan_in(
+                    (p_a_value, p_b_value),
+                    (d_a_value, d_b_value),
+                    mapping,
+                ),
             (Mux(p_a_value, p_b_value, p_c_value), Mux(d_a_value, d_b_value, d_c_value)) => {
                 let a_matches = self.nets_match_fan_in(p_a_value, d_a_value, mapping);
                 let b_matches = self.values_match_fan_in(p_b_value, d_b_value, mapping);
@@ -80,10 +65,13 @@ impl SubgraphMatcherCore<'_, '_, '_> {
                 a_matches && b_matches && c_matches
             }
             (Adc(p_a_value, p_b_value, p_ci_net), Adc(d_a_value, d_b_value, d_ci_net)) => {
-                let a_matches = self.values_match_fan_in(p_a_value, d_a_value, mapping);
-                let b_matches = self.values_match_fan_in(p_b_value, d_b_value, mapping);
+                let ab_matches = self.commutative_values_match_fan_in(
+                    (p_a_value, p_b_value),
+                    (d_a_value, d_b_value),
+                    mapping,
+                );
                 let ci_matches = self.nets_match_fan_in(p_ci_net, d_ci_net, mapping);
-                a_matches && b_matches && ci_matches
+                ab_matches && ci_matches
             }
             (Aig(pa_control_net, pb_control_net), Aig(da_control_net, db_control_net)) => {
                 let a_matches =
@@ -131,10 +119,12 @@ impl SubgraphMatcherCore<'_, '_, '_> {
                 let c_matches = pc_u32 == dc_u32;
                 a_matches && b_matches && c_matches
             }
-            (Mul(pa_value, pb_value), Mul(da_value, db_value)) => {
-                self.values_match_fan_in(pa_value, da_value, mapping)
-                    && self.values_match_fan_in(pb_value, db_value, mapping)
-            }
+            (Mul(pa_value, pb_value), Mul(da_value, db_value)) => self
+                .commutative_values_match_fan_in(
+                    (pa_value, pb_value),
+                    (da_value, db_value),
+                    mapping,
+                ),
             (UDiv(pa_value, pb_value), UDiv(da_value, db_value)) => {
                 self.values_match_fan_in(pa_value, da_value, mapping)
                     && self.values_match_fan_in(pb_value, db_value, mapping)
@@ -179,6 +169,23 @@ impl SubgraphMatcherCore<'_, '_, '_> {
         }
     }
 
+    /// Compares the operands of a commutative cell (e.g. And, Or, Xor, Mul, Adc)
+    /// for fan-in compatibility, accepting either operand order.
+    fn commutative_values_match_fan_in(
+        &self,
+        (needle_a, needle_b): (&Value, &Value),
+        (haystack_a, haystack_b): (&Value, &Value),
+        mapping: &SingleAssignment,
+    ) -> bool {
+        let result_normal = self.values_match_fan_in(needle_a, haystack_a, mapping)
+            && self.values_match_fan_in(needle_b, haystack_b, mapping);
+
+        let result_swapped = self.values_match_fan_in(needle_a, haystack_b, mapping)
+            && self.values_match_fan_in(needle_b, haystack_a, mapping);
+
+        result_normal || result_swapped
+    }
+
     /// Compares two multi-bit values (vectors of nets) for fan-in compatibility.
     fn values_match_fan_in(
         &self,
@@ -199,7 +206,7 @@ impl SubgraphMatcherCore<'_, '_, '_> {
                 let first_d_net = haystack_nets_vec.first().unwrap();
                 self.nets_match_fan_in(first_p_net, first_d_net, mapping)
             }
-            svql_common::MatchLength::NeedleSubsetHaystack => {
+            svql_common::MatchLength::NeedleSubsetHaystack | svql_common::MatchLength::Induced => {
                 for p_net in &needle_nets_vec {
                     let mut found_match = false;
                     for d_net in &haystack_nets_vec {
@@ -214,8 +221,23 @@ impl SubgraphMatcherCore<'_, '_, '_> {
                 }
                 true
             }
+            svql_common::MatchLength::SupersetNeedle => {
+                for d_net in &haystack_nets_vec {
+                    let mut found_match = false;
+                    for p_net in &needle_nets_vec {
+                        if self.nets_match_fan_in(p_net, d_net, mapping) {
+                            found_match = true;
+                            break;
+                        }
+                    }
+                    if !found_match {
+                        return false;
+                    }
+                }
+                true
+            }
             svql_common::MatchLength::Exact => {
-                if needle_nets_vec.len() != haystack_nets_vec.len() {
+                if needle_nets_vec.len() != haystack_nets_vec.len() && !self.config.width_tolerant {
                     return false;
                 }
                 for (p_net, d_net) in needle_nets_vec.iter().zip(haystack_nets_vec.iter()) {
@@ -235,8 +257,15 @@ impl SubgraphMatcherCore<'_, '_, '_> {
         haystack_net: &prjunnamed_netlist::Net,
         mapping: &SingleAssignment,
     ) -> bool {
-        let actual_fan_in_haystack_cell = self.haystack.find_cell(*haystack_net);
-        let fan_in_needle_cell = self.needle.find_cell(*needle_net);
+        let haystack_net = self.skip_ignored_drivers(
+            self.haystack,
+            self.haystack_index.num_cells(),
+            *haystack_net,
+        );
+        let needle_net =
+            self.skip_ignored_drivers(self.needle, self.needle_index.num_cells(), *needle_net);
+        let actual_fan_in_haystack_cell = self.haystack.find_cell(haystack_net);
+        let fan_in_needle_cell = self.needle.find_cell(needle_net);
 
         match (actual_fan_in_haystack_cell, fan_in_needle_cell) {
             (Ok((d_fan_in_cell_ref, _d_fan_in_idx)), Ok((p_fan_in_cell_ref, _p_fan_in_idx))) => {
@@ -262,6 +291,7 @@ impl SubgraphMatcherCore<'_, '_, '_> {
                 if self.config.pattern_vars_match_design_consts {
                     let needle_kind = crate::cell::CellKind::from(needle_cell_ref.get().as_ref());
                     needle_kind.is_input()
+                        || (self.config.bidir_ports && needle_kind == crate::cell::CellKind::IoBuf)
                 } else {
                     false
                 }
@@ -270,6 +300,34 @@ impl SubgraphMatcherCore<'_, '_, '_> {
         }
     }
 
+    /// Follows `net` back through skipped `Buf` and `Not` cells to the net feeding them.
+    ///
+    /// Other skipped kinds are not looked through, since their output bits do
+    /// not correspond to a single input bit.
+    fn skip_ignored_drivers(&self, design: &Design, num_cells: usize, mut net: Net) -> Net {
+        if self.skipped_kinds.is_empty() {
+            return net;
+        }
+        // Bounded by the cell count in case of a combinational buffer loop
+        for _ in 0..num_cells {
+            let Ok((cell_ref, bit)) = design.find_cell(net) else {
+                break;
+            };
+            let cell = cell_ref.get();
+            net = match cell.as_ref() {
+                Cell::Buf(value) | Cell::Not(value)
+                    if self
+                        .skipped_kinds
+                        .contains(&crate::cell::CellKind::from(cell.as_ref())) =>
+                {
+                    value[bit]
+                }
+                _ => break,
+            };
+        }
+        net
+    }
+
     /// Compares single-bit control nets (e.g., clock, reset) for fan-in compatibility.
     fn control_net_match_fan_in(
         &self,
@@ -3,8 +3,11 @@
 //! Identifies instances of a needle design within a larger haystack design
 //! using a backtracking search algorithm with topological ordering.
 
-use std::collections::VecDeque;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::borrow::Cow;
+use std::collections::{BTreeSet, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
 use svql_common::*;
 
 use prjunnamed_netlist::Design;
@@ -13,6 +16,9 @@ use svql_common::Config;
 use rayon::prelude::*;
 
 use super::assignment::{AssignmentSet, SingleAssignment};
+use super::progress::{ProgressEvent, ProgressFn};
+use super::stats::SearchStats;
+use super::stream::AssignmentIter;
 
 /// Entry point for subgraph isomorphism searches.
 #[allow(dead_code)]
@@ -61,6 +67,74 @@ pub struct SubgraphMatcherCore<'needle, 'haystack, 'cfg> {
     pub(crate) initial_candidates_done: AtomicUsize,
     /// Pre-computed bitmask of which needle nodes are internal (not I/O).
     pub(crate) needle_internal_mask: Vec<bool>,
+    /// Needle cells driving an output port, sorted and deduplicated.
+    pub(crate) needle_output_drivers: Vec<GraphNodeIdx>,
+    /// Deduplication keys of accepted matches, tracked when `max_matches` is set.
+    pub(crate) accepted_keys: Mutex<HashSet<Vec<usize>>>,
+    /// Flag that aborts the search when set by another thread.
+    pub(crate) cancel: Option<Arc<AtomicBool>>,
+    /// Set once a search-breadth cap discards part of the search space.
+    pub(crate) truncated: AtomicBool,
+    /// Callback receiving coarse progress updates.
+    pub(crate) progress: Option<ProgressFn>,
+    /// Cell kinds skipped during matching, see `Config::skipped_cell_kinds`.
+    pub(crate) skipped_kinds: BTreeSet<CellKind>,
+    /// Needle gate chosen through `Config::anchor_preference`, if it replaces
+    /// the default anchor.
+    pub(crate) anchor_override: Option<GraphNodeIdx>,
+    /// Smallest haystack degree each needle gate can match, indexed by needle
+    /// cell; empty when degree pruning is off.
+    pub(crate) needle_required_degrees: Vec<DegreeSignature>,
+}
+
+/// A partially explored branch of the search.
+#[derive(Clone)]
+pub(super) struct SearchFrame {
+    /// Cells assigned so far on this branch.
+    pub(super) assignment: SingleAssignment,
+    /// Needle gates still to be matched.
+    pub(super) gate_queue: VecDeque<GraphNodeIdx>,
+    /// Needle input ports still to be matched.
+    pub(super) input_queue: VecDeque<GraphNodeIdx>,
+    /// Needle output ports still to be matched.
+    pub(super) output_queue: VecDeque<GraphNodeIdx>,
+    /// Whether this branch assigns one of the root anchor candidates.
+    pub(super) anchor: bool,
+}
+
+impl SearchFrame {
+    /// Returns one branch per candidate, in ascending index order, each with
+    /// `needle` assigned to that candidate.
+    fn branch_on(
+        &self,
+        needle: GraphNodeIdx,
+        mut candidates: Vec<GraphNodeIdx>,
+        anchor: bool,
+    ) -> Vec<Self> {
+        candidates.sort_unstable();
+        candidates
+            .into_iter()
+            .map(|candidate| {
+                let mut assignment = self.assignment.clone();
+                assignment.assign(needle, candidate);
+                Self {
+                    assignment,
+                    gate_queue: self.gate_queue.clone(),
+                    input_queue: self.input_queue.clone(),
+                    output_queue: self.output_queue.clone(),
+                    anchor,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Outcome of expanding a single search frame.
+pub(super) enum Expansion {
+    /// Branches to explore next, in order; empty at a dead end.
+    Branches(Vec<SearchFrame>),
+    /// An accepted, not yet deduplicated match.
+    Match(SingleAssignment),
 }
 
 impl<'needle, 'haystack, 'cfg> SubgraphMatcher<'needle, 'haystack, 'cfg> {
@@ -73,58 +147,257 @@ impl<'needle, 'haystack, 'cfg> SubgraphMatcher<'needle, 'haystack, 'cfg> {
         haystack_name: String,
         config: &'cfg Config,
     ) -> AssignmentSet {
-        let needle_index = GraphIndex::build(needle);
-        let haystack_index = GraphIndex::build(haystack);
+        let needle_index = GraphIndex::build_with(needle, config.parallel);
+        let haystack_index = GraphIndex::build_with(haystack, config.parallel);
 
-        let needle_internal_mask = (0..needle_index.num_cells())
-            .map(|i| {
-                let kind = needle_index
-                    .get_cell_by_index(GraphNodeIdx::new(i as u32))
-                    .cell_type();
-                !matches!(kind, CellKind::Input | CellKind::Output)
-            })
-            .collect();
+        SubgraphMatcherCore::new(
+            needle,
+            haystack,
+            &needle_index,
+            &haystack_index,
+            needle_name,
+            haystack_name,
+            config,
+        )
+        .enumerate_assignments()
+    }
 
-        let matcher = SubgraphMatcherCore {
+    /// Performs a subgraph isomorphism search using pre-built indices.
+    #[must_use]
+    pub fn enumerate_with_indices(
+        needle: &'needle Design,
+        haystack: &'haystack Design,
+        needle_index: &'cfg GraphIndex<'needle>,
+        haystack_index: &'cfg GraphIndex<'haystack>,
+        needle_name: String,
+        haystack_name: String,
+        config: &'cfg Config,
+    ) -> AssignmentSet {
+        SubgraphMatcherCore::new(
             needle,
             haystack,
-            needle_index: &needle_index,
-            haystack_index: &haystack_index,
+            needle_index,
+            haystack_index,
+            needle_name,
+            haystack_name,
             config,
+        )
+        .enumerate_assignments()
+    }
+
+    /// Lazily enumerates deduplicated matches using pre-built indices.
+    ///
+    /// Matches are yielded one at a time in discovery order, so callers can
+    /// stop early (e.g. with `take`) without materializing the full result
+    /// set. Unlike [`Self::enumerate_with_indices`], the order is not sorted.
+    #[must_use]
+    pub fn iter_with_indices(
+        needle: &'needle Design,
+        haystack: &'haystack Design,
+        needle_index: &'cfg GraphIndex<'needle>,
+        haystack_index: &'cfg GraphIndex<'haystack>,
+        needle_name: String,
+        haystack_name: String,
+        config: &'cfg Config,
+    ) -> AssignmentIter<'needle, 'haystack, 'cfg> {
+        AssignmentIter::new(SubgraphMatcherCore::new(
+            needle,
+            haystack,
+            needle_index,
+            haystack_index,
             needle_name,
             haystack_name,
-            branches_explored: AtomicUsize::new(0),
-            active_branches: AtomicUsize::new(0),
-            matches_found: AtomicUsize::new(0),
-            initial_candidates_total: AtomicUsize::new(0),
-            initial_candidates_done: AtomicUsize::new(0),
-            needle_internal_mask,
-        };
+            config,
+        ))
+    }
+
+    /// Performs a complete subgraph isomorphism search and reports
+    /// diagnostics about how the search went.
+    #[must_use]
+    pub fn enumerate_with_stats(
+        needle: &'needle Design,
+        haystack: &'haystack Design,
+        needle_name: String,
+        haystack_name: String,
+        config: &'cfg Config,
+    ) -> (AssignmentSet, SearchStats) {
+        let needle_index = GraphIndex::build_with(needle, config.parallel);
+        let haystack_index = GraphIndex::build_with(haystack, config.parallel);
 
-        matcher.enumerate_assignments()
+        SubgraphMatcherCore::new(
+            needle,
+            haystack,
+            &needle_index,
+            &haystack_index,
+            needle_name,
+            haystack_name,
+            config,
+        )
+        .enumerate_assignments_with_stats()
     }
 
-    /// Performs a subgraph isomorphism search using pre-built indices.
+    /// Returns the kind of the needle gate a search would be anchored on,
+    /// without running the search. Builds indices internally.
+    ///
+    /// Every haystack cell of this kind becomes a root candidate, so a kind
+    /// that is common in the haystack makes for a slow search. See
+    /// [`SubgraphMatcherCore::anchor_kind`] for how the anchor is chosen.
     #[must_use]
-    pub fn enumerate_with_indices(
+    pub fn anchor_kind(
+        needle: &'needle Design,
+        haystack: &'haystack Design,
+        config: &'cfg Config,
+    ) -> Option<CellKind> {
+        let needle_index = GraphIndex::build_with(needle, config.parallel);
+        let haystack_index = GraphIndex::build_with(haystack, config.parallel);
+
+        Self::anchor_kind_with_indices(needle, haystack, &needle_index, &haystack_index, config)
+    }
+
+    /// Returns the kind of the needle gate a search would be anchored on,
+    /// using pre-built indices.
+    #[must_use]
+    pub fn anchor_kind_with_indices(
         needle: &'needle Design,
         haystack: &'haystack Design,
         needle_index: &'cfg GraphIndex<'needle>,
         haystack_index: &'cfg GraphIndex<'haystack>,
+        config: &'cfg Config,
+    ) -> Option<CellKind> {
+        SubgraphMatcherCore::new(
+            needle,
+            haystack,
+            needle_index,
+            haystack_index,
+            String::new(),
+            String::new(),
+            config,
+        )
+        .anchor_kind()
+    }
+
+    /// Counts the deduplicated matches of a subgraph isomorphism search
+    /// without collecting them into an `AssignmentSet`.
+    ///
+    /// Shares the traversal and deduplication of [`Self::enumerate_all`], so
+    /// the result always equals `enumerate_all(..).len()`.
+    #[must_use]
+    pub fn count_all(
+        needle: &'needle Design,
+        haystack: &'haystack Design,
+        needle_name: String,
+        haystack_name: String,
+        config: &'cfg Config,
+    ) -> usize {
+        let needle_index = GraphIndex::build_with(needle, config.parallel);
+        let haystack_index = GraphIndex::build_with(haystack, config.parallel);
+
+        SubgraphMatcherCore::new(
+            needle,
+            haystack,
+            &needle_index,
+            &haystack_index,
+            needle_name,
+            haystack_name,
+            config,
+        )
+        .count_assignments()
+    }
+
+    /// Performs a complete subgraph isomorphism search that can be aborted.
+    ///
+    /// Setting `cancel` from another thread stops the search at the next
+    /// recursion step; the (deduplicated) matches found up to that point are
+    /// returned.
+    #[must_use]
+    pub fn enumerate_cancellable(
+        needle: &'needle Design,
+        haystack: &'haystack Design,
+        needle_name: String,
+        haystack_name: String,
+        config: &'cfg Config,
+        cancel: Arc<AtomicBool>,
+    ) -> AssignmentSet {
+        let needle_index = GraphIndex::build_with(needle, config.parallel);
+        let haystack_index = GraphIndex::build_with(haystack, config.parallel);
+
+        SubgraphMatcherCore::new(
+            needle,
+            haystack,
+            &needle_index,
+            &haystack_index,
+            needle_name,
+            haystack_name,
+            config,
+        )
+        .with_cancel(cancel)
+        .enumerate_assignments()
+    }
+
+    /// Performs a complete subgraph isomorphism search, reporting coarse
+    /// progress to `progress`.
+    ///
+    /// Emits [`ProgressEvent::Indexing`] before the indices are built, then
+    /// the events described in [`SubgraphMatcherCore::with_progress`].
+    #[must_use]
+    pub fn enumerate_with_progress(
+        needle: &'needle Design,
+        haystack: &'haystack Design,
         needle_name: String,
         haystack_name: String,
         config: &'cfg Config,
+        progress: ProgressFn,
     ) -> AssignmentSet {
+        progress(ProgressEvent::Indexing);
+        let needle_index = GraphIndex::build_with(needle, config.parallel);
+        let haystack_index = GraphIndex::build_with(haystack, config.parallel);
+
+        SubgraphMatcherCore::new(
+            needle,
+            haystack,
+            &needle_index,
+            &haystack_index,
+            needle_name,
+            haystack_name,
+            config,
+        )
+        .with_progress(progress)
+        .enumerate_assignments()
+    }
+}
+
+impl<'needle, 'haystack, 'cfg> SubgraphMatcherCore<'needle, 'haystack, 'cfg> {
+    /// Creates a matcher over pre-built indices with fresh progress counters.
+    #[must_use]
+    pub fn new(
+        needle: &'needle Design,
+        haystack: &'haystack Design,
+        needle_index: &'cfg GraphIndex<'needle>,
+        haystack_index: &'cfg GraphIndex<'haystack>,
+        needle_name: String,
+        haystack_name: String,
+        config: &'cfg Config,
+    ) -> Self {
         let needle_internal_mask = (0..needle_index.num_cells())
             .map(|i| {
                 let kind = needle_index
                     .get_cell_by_index(GraphNodeIdx::new(i as u32))
                     .cell_type();
-                !matches!(kind, CellKind::Input | CellKind::Output)
+                let is_bidir_port = config.bidir_ports && kind == CellKind::IoBuf;
+                !matches!(kind, CellKind::Input | CellKind::Output) && !is_bidir_port
             })
             .collect();
 
-        let matcher = SubgraphMatcherCore {
+        let mut needle_output_drivers: Vec<GraphNodeIdx> = needle_index
+            .get_output_fanin_by_name_indices()
+            .values()
+            .flatten()
+            .map(|&(driver, _)| driver)
+            .collect();
+        needle_output_drivers.sort_unstable();
+        needle_output_drivers.dedup();
+
+        let mut matcher = Self {
             needle,
             haystack,
             needle_index,
@@ -138,27 +411,146 @@ impl<'needle, 'haystack, 'cfg> SubgraphMatcher<'needle, 'haystack, 'cfg> {
             initial_candidates_total: AtomicUsize::new(0),
             initial_candidates_done: AtomicUsize::new(0),
             needle_internal_mask,
+            needle_output_drivers,
+            accepted_keys: Mutex::new(HashSet::new()),
+            cancel: None,
+            truncated: AtomicBool::new(false),
+            progress: None,
+            skipped_kinds: config.skipped_cell_kinds(),
+            anchor_override: None,
+            needle_required_degrees: Vec::new(),
         };
-        matcher.enumerate_assignments()
+        matcher.anchor_override = matcher.preferred_anchor();
+        matcher.needle_required_degrees = matcher.required_degrees();
+        matcher
+    }
+
+    /// Computes the smallest haystack degree signature each needle gate can
+    /// be matched to.
+    ///
+    /// Only edges to gates matched earlier (fan-in) or later (fan-out) in the
+    /// gate queue are counted, since candidate generation guarantees that
+    /// those map onto distinct haystack neighbours of the candidate. Pruning
+    /// is off for `SupersetNeedle`, where gates may stay unmapped, and when
+    /// cell kinds are skipped, where the visible degree differs from the
+    /// indexed one.
+    fn required_degrees(&self) -> Vec<DegreeSignature> {
+        if self.config.match_length.superset_needle() || !self.skipped_kinds.is_empty() {
+            return Vec::new();
+        }
+
+        let (_, gates, _) = self.prepare_search_queues();
+        let mut position = vec![None; self.needle_index.num_cells()];
+        for (pos, gate) in gates.iter().enumerate() {
+            position[gate.as_usize()] = Some(pos);
+        }
+
+        let mut required = vec![DegreeSignature::default(); self.needle_index.num_cells()];
+        for (pos, gate) in gates.iter().enumerate() {
+            let count = |neighbours: &HashSet<GraphNodeIdx>, earlier: bool| {
+                neighbours
+                    .iter()
+                    .filter_map(|n| position[n.as_usize()])
+                    .filter(|&other| if earlier { other < pos } else { other > pos })
+                    .count()
+            };
+            required[gate.as_usize()] = DegreeSignature {
+                fanin: count(self.needle_index.fanin_set(*gate), true),
+                fanout: count(self.needle_index.fanout_set(*gate), false),
+            };
+        }
+        required
+    }
+
+    /// Returns the needle gate to anchor on according to
+    /// `Config::anchor_preference`, or `None` to keep the default anchor.
+    ///
+    /// The preference is viable when a queued needle gate has the preferred
+    /// kind and the haystack has at least one cell of it.
+    fn preferred_anchor(&self) -> Option<GraphNodeIdx> {
+        let kind = self.config.anchor_preference?;
+        if self.haystack_index.cells_of_type_indices(kind).is_empty() {
+            return None;
+        }
+        let (_, gates, _) = self.prepare_search_queues();
+        let anchor = gates
+            .iter()
+            .copied()
+            .find(|&gate| self.needle_index.get_cell_by_index(gate).cell_type() == kind)?;
+        (gates.front() != Some(&anchor)).then_some(anchor)
+    }
+
+    /// Reorders `gates` breadth-first from `anchor`, so that every gate after
+    /// the anchor neighbours one matched before it where possible.
+    ///
+    /// Neighbours are visited in their original queue order; gates not
+    /// connected to the anchor keep their relative order at the end.
+    fn order_from_anchor(
+        &self,
+        anchor: GraphNodeIdx,
+        gates: &VecDeque<GraphNodeIdx>,
+    ) -> VecDeque<GraphNodeIdx> {
+        let position: HashMap<GraphNodeIdx, usize> = gates
+            .iter()
+            .enumerate()
+            .map(|(pos, &gate)| (gate, pos))
+            .collect();
+
+        let mut visited = HashSet::new();
+        visited.insert(anchor);
+        let mut order = VecDeque::with_capacity(gates.len());
+        let mut frontier = VecDeque::from([anchor]);
+        while let Some(gate) = frontier.pop_front() {
+            order.push_back(gate);
+            let fanin = self.visible_fanin(self.needle_index, gate);
+            let fanout = self.visible_fanout(self.needle_index, gate);
+            let mut neighbours: Vec<GraphNodeIdx> = fanin
+                .iter()
+                .chain(fanout.iter())
+                .copied()
+                .filter(|neighbour| position.contains_key(neighbour))
+                .collect();
+            neighbours.sort_unstable_by_key(|neighbour| position[neighbour]);
+            for neighbour in neighbours {
+                if visited.insert(neighbour) {
+                    frontier.push_back(neighbour);
+                }
+            }
+        }
+
+        order.extend(gates.iter().copied().filter(|gate| !visited.contains(gate)));
+        order
+    }
+
+    /// Attaches a cancellation flag, checked at the top of every recursion step.
+    #[must_use]
+    pub fn with_cancel(mut self, cancel: Arc<AtomicBool>) -> Self {
+        self.cancel = Some(cancel);
+        self
+    }
+
+    /// Attaches a progress callback.
+    ///
+    /// Events are emitted when the anchor candidates are known, every 512
+    /// branches while enumerating, and once when the search finishes.
+    #[must_use]
+    pub fn with_progress(mut self, progress: ProgressFn) -> Self {
+        self.progress = Some(progress);
+        self
     }
 }
 
 impl SubgraphMatcherCore<'_, '_, '_> {
     /// Executes the matching process and applies deduplication.
     pub fn enumerate_assignments(&self) -> AssignmentSet {
-        tracing::info!(
-            "[{} -> {}] starting subgraph search: needle cells: {}, haystack cells: {}",
-            self.needle_name,
-            self.haystack_name,
-            self.needle_index.num_cells(),
-            self.haystack_index.num_cells()
-        );
-
-        let (input_queue, gate_queue, output_queue) = self.prepare_search_queues();
-        let initial_assignment = SingleAssignment::new();
+        self.enumerate_assignments_with_stats().0
+    }
 
-        let mut results =
-            self.match_gate_cells(initial_assignment, gate_queue, input_queue, output_queue);
+    /// Executes the matching process, applies deduplication and collects
+    /// search diagnostics.
+    pub fn enumerate_assignments_with_stats(&self) -> (AssignmentSet, SearchStats) {
+        let start = Instant::now();
+        let mut results = self.search();
 
         let total_found = results.len();
 
@@ -172,19 +564,199 @@ impl SubgraphMatcherCore<'_, '_, '_> {
             results.len()
         );
 
-        AssignmentSet { items: results }
+        let stats = SearchStats {
+            anchor_kind: self.anchor_kind(),
+            anchor_candidates: self.initial_candidates_total.load(Ordering::SeqCst),
+            branches_explored: self.branches_explored.load(Ordering::Relaxed),
+            raw_matches: total_found,
+            deduplicated_matches: results.len(),
+            elapsed: start.elapsed(),
+            truncated: self.is_truncated(),
+        };
+
+        self.report(ProgressEvent::Finished {
+            matches: stats.deduplicated_matches,
+        });
+
+        if stats.truncated {
+            tracing::warn!(
+                "[{} -> {}] search truncated by configured limits; results may be incomplete",
+                self.needle_name,
+                self.haystack_name
+            );
+        }
+
+        (
+            AssignmentSet {
+                items: results,
+                truncated: stats.truncated,
+            },
+            stats,
+        )
+    }
+
+    /// Executes the matching process and returns the number of matches left
+    /// after deduplication.
+    ///
+    /// Only the deduplication keys of the matches are kept, not the
+    /// assignments themselves.
+    pub fn count_assignments(&self) -> usize {
+        let seen = Mutex::new(HashSet::new());
+        let raw = self
+            .explore(self.root_frame(), &|assignment| {
+                seen.lock()
+                    .expect("Counted match keys lock poisoned")
+                    .insert(self.dedup_key(&assignment));
+            })
+            .len();
+        let count = seen
+            .into_inner()
+            .expect("Counted match keys lock poisoned")
+            .len();
+
+        tracing::info!(
+            "[{} -> {}] count complete: found {} raw matches, {} after deduplication",
+            self.needle_name,
+            self.haystack_name,
+            raw,
+            count
+        );
+
+        self.report(ProgressEvent::Finished { matches: count });
+        count
     }
 
-    /// Recursive backtracking step for matching logic gates.
-    fn match_gate_cells(
-        &self,
-        assignment: SingleAssignment,
-        mut gate_queue: VecDeque<GraphNodeIdx>,
-        input_queue: VecDeque<GraphNodeIdx>,
-        output_queue: VecDeque<GraphNodeIdx>,
-    ) -> Vec<SingleAssignment> {
+    /// Runs the backtracking search from an empty assignment, returning every
+    /// raw (not yet deduplicated) match in discovery order.
+    fn search(&self) -> Vec<SingleAssignment> {
+        self.explore(self.root_frame(), &|assignment| assignment)
+    }
+
+    /// Returns the search frame with nothing assigned and every needle cell queued.
+    pub(super) fn root_frame(&self) -> SearchFrame {
+        tracing::info!(
+            "[{} -> {}] starting subgraph search: needle cells: {}, haystack cells: {}",
+            self.needle_name,
+            self.haystack_name,
+            self.needle_index.num_cells(),
+            self.haystack_index.num_cells()
+        );
+
+        let (input_queue, gate_queue, output_queue) = self.prepare_search_queues();
+        SearchFrame {
+            assignment: SingleAssignment::new(),
+            gate_queue,
+            input_queue,
+            output_queue,
+            anchor: false,
+        }
+    }
+
+    /// Returns the kind of the needle gate the search is anchored on.
+    ///
+    /// By default the anchor is the first needle gate in topological order,
+    /// i.e. one closest to the needle inputs. `Config::anchor_preference`
+    /// replaces it with a gate of the preferred kind when that kind is
+    /// present in both designs. Gates of skipped kinds are never anchors.
+    /// Returns `None` if the needle has no gates to anchor on.
+    #[must_use]
+    pub fn anchor_kind(&self) -> Option<CellKind> {
+        let (_, gate_queue, _) = self.prepare_search_queues();
+        gate_queue
+            .front()
+            .map(|&idx| self.needle_index.get_cell_by_index(idx).cell_type())
+    }
+
+    /// Recursively explores `frame`, mapping every accepted match through `visit`.
+    ///
+    /// Branches are explored in the order [`Self::expand`] returns them, so
+    /// results come back in the same discovery order as from
+    /// [`AssignmentIter`]. With `config.parallel`, branches (including the
+    /// root anchors) are expanded on the rayon pool; the order is kept.
+    fn explore<T, F>(&self, frame: SearchFrame, visit: &F) -> Vec<T>
+    where
+        T: Send,
+        F: Fn(SingleAssignment) -> T + Sync,
+    {
+        if self.should_stop() {
+            return vec![];
+        }
+
+        let branches = match self.expand(frame) {
+            Expansion::Match(assignment) => return vec![visit(assignment)],
+            Expansion::Branches(branches) => branches,
+        };
+
+        let explore_branch = |branch: SearchFrame| {
+            let anchor = branch.anchor;
+            let res = self.explore(branch, visit);
+            if anchor {
+                self.initial_candidates_done.fetch_add(1, Ordering::SeqCst);
+            }
+            res
+        };
+
+        self.active_branches.fetch_add(1, Ordering::SeqCst);
+        let results = if self.config.parallel {
+            branches.into_par_iter().flat_map(explore_branch).collect()
+        } else {
+            branches.into_iter().flat_map(explore_branch).collect()
+        };
+        self.active_branches.fetch_sub(1, Ordering::SeqCst);
+
+        results
+    }
+
+    /// Performs one backtracking step on `frame`.
+    ///
+    /// Matches the next queued needle cell (gates first, then inputs, then
+    /// outputs) and returns the resulting branches, or the completed match
+    /// once every queue is empty. Both the eager search and
+    /// [`AssignmentIter`] are driven by this step, so they share the search
+    /// bounds, progress events and branch order.
+    pub(super) fn expand(&self, mut frame: SearchFrame) -> Expansion {
+        let partial = self.config.match_length.superset_needle();
+
+        if let Some(needle) = frame.gate_queue.pop_front() {
+            return Expansion::Branches(self.expand_gate(frame, needle));
+        }
+
+        if partial && frame.assignment.is_empty() {
+            // A partial match must still cover at least one needle gate.
+            return Expansion::Branches(vec![]);
+        }
+
+        if let Some(needle) = frame.input_queue.pop_front() {
+            if self.port_is_unanchored(needle, &frame.assignment) {
+                return Expansion::Branches(vec![frame]);
+            }
+            let candidates = self.find_candidates_for_input(needle, &frame.assignment);
+            if candidates.is_empty() && self.config.pattern_vars_match_design_consts {
+                return Expansion::Branches(vec![frame]);
+            }
+            return Expansion::Branches(frame.branch_on(needle, candidates, false));
+        }
+
+        if let Some(needle) = frame.output_queue.pop_front() {
+            if self.port_is_unanchored(needle, &frame.assignment) {
+                return Expansion::Branches(vec![frame]);
+            }
+            let candidates = self.find_candidates_for_output(needle, &frame.assignment);
+            return Expansion::Branches(frame.branch_on(needle, candidates, false));
+        }
+
+        self.accept_match(frame.assignment)
+            .map_or_else(|| Expansion::Branches(vec![]), Expansion::Match)
+    }
+
+    /// Branches on the haystack candidates for the needle gate `needle`.
+    ///
+    /// Counts the step towards `max_total_nodes` and reports progress every
+    /// 512 steps. Under `SupersetNeedle`, leaving the gate unmatched is
+    /// explored after every candidate.
+    fn expand_gate(&self, frame: SearchFrame, needle: GraphNodeIdx) -> Vec<SearchFrame> {
         let total = self.branches_explored.fetch_add(1, Ordering::Relaxed);
-        let is_root = assignment.is_empty();
+        let is_root = frame.assignment.is_empty();
 
         if total.is_multiple_of(512) && total > 0 {
             let active = self.active_branches.load(Ordering::Relaxed);
@@ -202,175 +774,222 @@ impl SubgraphMatcherCore<'_, '_, '_> {
                 top_done,
                 top_total
             );
+            self.report(ProgressEvent::Enumerating {
+                anchors_done: top_done,
+                anchors_total: top_total,
+                branches: total,
+                matches_found: found,
+            });
         }
 
-        let Some(current_needle) = gate_queue.pop_front() else {
-            return self.match_input_cells(assignment, input_queue, output_queue);
-        };
-
-        let candidates = self.find_candidates_for_cell(current_needle, &assignment);
+        let candidates = self.find_candidates_for_cell(needle, &frame.assignment);
+        let partial = self.config.match_length.superset_needle();
 
-        if candidates.is_empty() {
-            if is_root {
-                tracing::warn!("[{}] no candidates found for root cell", self.needle_name);
-            }
-            return vec![];
+        if candidates.is_empty() && !partial && is_root {
+            tracing::warn!("[{}] no candidates found for root cell", self.needle_name);
         }
 
-        if is_root {
+        if is_root && !candidates.is_empty() {
             self.initial_candidates_total
                 .store(candidates.len(), Ordering::SeqCst);
+            self.report(ProgressEvent::Anchoring {
+                candidates: candidates.len(),
+            });
         }
 
-        self.active_branches.fetch_add(1, Ordering::SeqCst);
-
-        if self.config.parallel {
-            let results: Vec<_> = candidates
-                .into_par_iter()
-                .flat_map(|candidate| {
-                    let mut next_assignment = assignment.clone();
-                    next_assignment.assign(current_needle, candidate);
-
-                    let res = self.match_gate_cells(
-                        next_assignment,
-                        gate_queue.clone(),
-                        input_queue.clone(),
-                        output_queue.clone(),
-                    );
+        let mut branches = frame.branch_on(needle, candidates, is_root);
+        if partial {
+            branches.push(frame);
+        }
+        branches
+    }
 
-                    if is_root {
-                        self.initial_candidates_done.fetch_add(1, Ordering::SeqCst);
-                    }
-                    res
-                })
-                .collect();
-            self.active_branches.fetch_sub(1, Ordering::SeqCst);
-            return results;
+    /// Records a completed assignment, enforcing `max_matches` if configured.
+    ///
+    /// With a limit, duplicates and matches past the cap are rejected here so
+    /// that every slot goes to a distinct match. Under `MatchLength::Induced`,
+    /// assignments with extra edges among their gates are rejected first.
+    pub(super) fn accept_match(&self, assignment: SingleAssignment) -> Option<SingleAssignment> {
+        if self.config.match_length.induced() && !self.is_induced(&assignment) {
+            return None;
         }
 
-        let results: Vec<_> = candidates
-            .into_iter()
-            .flat_map(|candidate| {
-                let mut next_assignment = assignment.clone();
-                next_assignment.assign(current_needle, candidate);
+        if let Some(limit) = self.config.max_matches {
+            let mut accepted = self
+                .accepted_keys
+                .lock()
+                .expect("Accepted match keys lock poisoned");
+            if accepted.len() >= limit || !accepted.insert(self.dedup_key(&assignment)) {
+                return None;
+            }
+        }
 
-                let res = self.match_gate_cells(
-                    next_assignment,
-                    gate_queue.clone(),
-                    input_queue.clone(),
-                    output_queue.clone(),
-                );
+        self.matches_found.fetch_add(1, Ordering::Relaxed);
+        Some(assignment)
+    }
 
-                if is_root {
-                    self.initial_candidates_done.fetch_add(1, Ordering::SeqCst);
-                }
-                res
-            })
+    /// Returns true if the haystack cells mapped to needle gates are connected
+    /// only where the corresponding needle gates are.
+    ///
+    /// Port cells are ignored, as they legitimately bind to cells that also
+    /// drive logic outside the pattern.
+    pub(super) fn is_induced(&self, assignment: &SingleAssignment) -> bool {
+        let gates: Vec<(GraphNodeIdx, GraphNodeIdx)> = assignment
+            .needle_mapping()
+            .iter()
+            .filter(|(needle, _)| self.needle_internal_mask[needle.as_usize()])
+            .map(|(&needle, &haystack)| (needle, haystack))
             .collect();
 
-        self.active_branches.fetch_sub(1, Ordering::SeqCst);
-        results
+        gates.iter().all(|&(needle_from, haystack_from)| {
+            let needle_fanout = self.visible_fanout(self.needle_index, needle_from);
+            let haystack_fanout = self.visible_fanout(self.haystack_index, haystack_from);
+            gates.iter().all(|&(needle_to, haystack_to)| {
+                !haystack_fanout.contains(&haystack_to) || needle_fanout.contains(&needle_to)
+            })
+        })
     }
 
-    /// Recursive backtracking step for matching input ports.
-    fn match_input_cells(
-        &self,
-        assignment: SingleAssignment,
-        mut input_queue: VecDeque<GraphNodeIdx>,
-        output_queue: VecDeque<GraphNodeIdx>,
-    ) -> Vec<SingleAssignment> {
-        let Some(current_needle) = input_queue.pop_front() else {
-            return self.match_output_cells(assignment, output_queue);
-        };
-
-        let candidates = self.find_candidates_for_input(current_needle, &assignment);
-
-        if candidates.is_empty() && self.config.pattern_vars_match_design_consts {
-            return self.match_input_cells(assignment, input_queue, output_queue);
+    /// Forwards `event` to the progress callback, if one is attached.
+    pub(super) fn report(&self, event: ProgressEvent) {
+        if let Some(progress) = &self.progress {
+            progress(event);
         }
+    }
 
-        if self.config.parallel {
-            return candidates
-                .into_par_iter()
-                .flat_map(|candidate| {
-                    let mut next_assignment = assignment.clone();
-                    next_assignment.assign(current_needle, candidate);
+    /// Returns true if a search-breadth cap has discarded part of the search.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated.load(Ordering::Relaxed)
+    }
 
-                    self.match_input_cells(
-                        next_assignment,
-                        input_queue.clone(),
-                        output_queue.clone(),
-                    )
-                })
-                .collect();
+    /// Returns true once the search has been cancelled, `max_total_nodes`
+    /// branches have been visited or `max_matches` distinct matches have been
+    /// accepted.
+    pub(super) fn should_stop(&self) -> bool {
+        if self
+            .cancel
+            .as_ref()
+            .is_some_and(|cancel| cancel.load(Ordering::Relaxed))
+        {
+            return true;
         }
 
-        candidates
-            .into_iter()
-            .flat_map(|candidate| {
-                let mut next_assignment = assignment.clone();
-                next_assignment.assign(current_needle, candidate);
+        if self
+            .config
+            .max_total_nodes
+            .is_some_and(|limit| self.branches_explored.load(Ordering::Relaxed) >= limit)
+        {
+            self.truncated.store(true, Ordering::Relaxed);
+            return true;
+        }
 
-                self.match_input_cells(next_assignment, input_queue.clone(), output_queue.clone())
-            })
-            .collect()
+        self.config
+            .max_matches
+            .is_some_and(|limit| self.matches_found.load(Ordering::Relaxed) >= limit)
     }
 
-    /// Recursive backtracking step for matching output ports.
-    fn match_output_cells(
+    /// Returns true if partial matching is enabled and none of the gates
+    /// adjacent to the needle port were matched, so the port can be skipped.
+    pub(super) fn port_is_unanchored(
         &self,
-        assignment: SingleAssignment,
-        mut output_queue: VecDeque<GraphNodeIdx>,
-    ) -> Vec<SingleAssignment> {
-        let Some(current_needle) = output_queue.pop_front() else {
-            self.matches_found.fetch_add(1, Ordering::Relaxed);
-            return vec![assignment];
+        needle_port: GraphNodeIdx,
+        assignment: &SingleAssignment,
+    ) -> bool {
+        if !self.config.match_length.superset_needle() {
+            return false;
+        }
+
+        let neighbours = if self.is_input_like(needle_port) {
+            self.visible_fanout(self.needle_index, needle_port)
+        } else {
+            self.visible_fanin(self.needle_index, needle_port)
         };
 
-        let candidates = self.find_candidates_for_output(current_needle, &assignment);
+        neighbours
+            .iter()
+            .all(|neighbour| assignment.get_haystack_cell(*neighbour).is_none())
+    }
 
-        if candidates.is_empty() {
-            return vec![];
+    /// Returns the fan-out of `node`, looking through skipped cell kinds.
+    fn visible_fanout<'s>(
+        &self,
+        index: &'s GraphIndex<'_>,
+        node: GraphNodeIdx,
+    ) -> Cow<'s, HashSet<GraphNodeIdx>> {
+        if self.skipped_kinds.is_empty() {
+            Cow::Borrowed(index.fanout_set(node))
+        } else {
+            Cow::Owned(index.fanout_set_skipping(node, &self.skipped_kinds))
         }
+    }
 
-        if self.config.parallel {
-            return candidates
-                .into_par_iter()
-                .flat_map(|candidate| {
-                    let mut next_assignment = assignment.clone();
-                    next_assignment.assign(current_needle, candidate);
+    /// Returns the fan-in of `node`, looking through skipped cell kinds.
+    fn visible_fanin<'s>(
+        &self,
+        index: &'s GraphIndex<'_>,
+        node: GraphNodeIdx,
+    ) -> Cow<'s, HashSet<GraphNodeIdx>> {
+        if self.skipped_kinds.is_empty() {
+            Cow::Borrowed(index.fanin_set(node))
+        } else {
+            Cow::Owned(index.fanin_set_skipping(node, &self.skipped_kinds))
+        }
+    }
 
-                    self.match_output_cells(next_assignment, output_queue.clone())
-                })
-                .collect();
+    /// Returns the haystack cells in the visible fan-out of every cell in `cells`.
+    fn common_haystack_fanout(&self, cells: &[GraphNodeIdx]) -> HashSet<GraphNodeIdx> {
+        if self.skipped_kinds.is_empty() {
+            return self.haystack_index.intersect_fanout(cells);
+        }
+        let mut result: Option<HashSet<GraphNodeIdx>> = None;
+        for &cell in cells {
+            let fanout = self.visible_fanout(self.haystack_index, cell);
+            match &mut result {
+                None => result = Some(fanout.into_owned()),
+                Some(r) => r.retain(|x| fanout.contains(x)),
+            }
         }
+        result.unwrap_or_default()
+    }
 
-        candidates
-            .into_iter()
-            .flat_map(|candidate| {
-                let mut next_assignment = assignment.clone();
-                next_assignment.assign(current_needle, candidate);
+    /// Returns the haystack cells in the visible fan-in of every cell in `cells`.
+    fn common_haystack_fanin(&self, cells: &[GraphNodeIdx]) -> HashSet<GraphNodeIdx> {
+        if self.skipped_kinds.is_empty() {
+            return self.haystack_index.intersect_fanin(cells);
+        }
+        let mut result: Option<HashSet<GraphNodeIdx>> = None;
+        for &cell in cells {
+            let fanin = self.visible_fanin(self.haystack_index, cell);
+            match &mut result {
+                None => result = Some(fanin.into_owned()),
+                Some(r) => r.retain(|x| fanin.contains(x)),
+            }
+        }
+        result.unwrap_or_default()
+    }
 
-                self.match_output_cells(next_assignment, output_queue.clone())
-            })
-            .collect()
+    /// Returns true if the needle port is bound through its fan-out, i.e. it is
+    /// an input or (with `bidir_ports`) an inout pin the pattern reads.
+    fn is_input_like(&self, needle_port: GraphNodeIdx) -> bool {
+        match self.needle_index.get_cell_by_index(needle_port).cell_type() {
+            CellKind::Input => true,
+            CellKind::IoBuf => !self.needle_index.fanout(needle_port).is_empty(),
+            _ => false,
+        }
     }
 
     /// Filters haystack cells for output ports based on fan-in connectivity.
     /// Output cells in the pattern can match any logic gate in the haystack.
-    fn find_candidates_for_output(
+    pub(super) fn find_candidates_for_output(
         &self,
         needle_output: GraphNodeIdx,
         assignment: &SingleAssignment,
     ) -> Vec<GraphNodeIdx> {
-        let needle_fanin = self
-            .needle_index
-            .fanin_with_ports(needle_output)
-            .unwrap_or_default();
+        let needle_fanin = self.visible_fanin(self.needle_index, needle_output);
 
         let mapped_haystack_fanin: Vec<GraphNodeIdx> = needle_fanin
             .iter()
-            .filter_map(|(needle_pred, _)| assignment.get_haystack_cell(*needle_pred))
+            .filter_map(|needle_pred| assignment.get_haystack_cell(*needle_pred))
             .collect();
 
         if mapped_haystack_fanin.is_empty() {
@@ -378,33 +997,26 @@ impl SubgraphMatcherCore<'_, '_, '_> {
             return (0..self.haystack_index.num_cells())
                 .map(|i| GraphNodeIdx::new(i as u32))
                 .filter(|&idx| {
-                    self.haystack_index
-                        .get_cell_by_index(idx)
-                        .cell_type()
-                        .is_logic_gate()
+                    let kind = self.haystack_index.get_cell_by_index(idx).cell_type();
+                    kind.is_logic_gate() && !self.skipped_kinds.contains(&kind)
                 })
                 .filter(|candidate| assignment.haystack_is_free(*candidate))
                 .collect();
         }
 
-        let mut result: Option<HashSet<GraphNodeIdx>> = None;
-        for haystack_pred in &mapped_haystack_fanin {
-            let fanout = self.haystack_index.fanout_set(*haystack_pred);
-            match &mut result {
-                None => result = Some(fanout.clone()),
-                Some(r) => r.retain(|x| fanout.contains(x)),
-            }
-        }
-
-        result
-            .unwrap_or_default()
+        self.common_haystack_fanout(&mapped_haystack_fanin)
             .into_iter()
             .filter(|candidate| assignment.haystack_is_free(*candidate))
             .collect()
     }
 
     /// Separates needle cells into input, output, and gate queues for topological traversal.
-    fn prepare_search_queues(
+    ///
+    /// With `bidir_ports`, inout pins (`IoBuf` cells) join the input queue if
+    /// the pattern reads them and the output queue otherwise. Gates of skipped
+    /// kinds are left out of every queue. With an anchor override, the gate
+    /// queue starts at that gate and grows outwards from it.
+    pub(super) fn prepare_search_queues(
         &self,
     ) -> (
         VecDeque<GraphNodeIdx>,
@@ -421,45 +1033,69 @@ impl SubgraphMatcherCore<'_, '_, '_> {
             match cell.cell_type() {
                 CellKind::Output => outputs.push_back(idx),
                 CellKind::Input => inputs.push_back(idx),
+                CellKind::IoBuf if self.config.bidir_ports => {
+                    if self.is_input_like(idx) {
+                        inputs.push_back(idx);
+                    } else {
+                        outputs.push_back(idx);
+                    }
+                }
+                kind if self.skipped_kinds.contains(&kind) => {}
                 _ => gates.push_back(idx),
             }
         }
 
+        if let Some(anchor) = self.anchor_override {
+            gates = self.order_from_anchor(anchor, &gates);
+        }
+
         (inputs, gates, outputs)
     }
 
     /// Filters haystack cells based on type and fan-in constraints.
-    fn find_candidates_for_cell(
+    pub(super) fn find_candidates_for_cell(
         &self,
         needle_cell: GraphNodeIdx,
         assignment: &SingleAssignment,
     ) -> Vec<GraphNodeIdx> {
         let kind = self.needle_index.get_cell_by_index(needle_cell).cell_type();
-        let needle_fanin = self
-            .needle_index
-            .fanin_with_ports(needle_cell)
-            .unwrap_or_default();
+        let needle_fanin = self.visible_fanin(self.needle_index, needle_cell);
 
         let mapped_haystack_fanin: Vec<GraphNodeIdx> = needle_fanin
             .iter()
-            .filter_map(|(needle_pred, _)| assignment.get_haystack_cell(*needle_pred))
+            .filter_map(|needle_pred| assignment.get_haystack_cell(*needle_pred))
             .collect();
 
-        let unfiltered: Vec<GraphNodeIdx> = if mapped_haystack_fanin.is_empty() {
-            self.haystack_index.cells_of_type_indices(kind).to_vec()
+        // Outside the default order a gate may be reached from its fan-out,
+        // whose fan-in constraints then have to be checked against it.
+        let mapped_successors: Vec<(GraphNodeIdx, GraphNodeIdx)> = if self.anchor_override.is_some()
+        {
+            self.visible_fanout(self.needle_index, needle_cell)
+                .iter()
+                .filter_map(|&succ| Some((succ, assignment.get_haystack_cell(succ)?)))
+                .filter(|&(succ, _)| self.needle_internal_mask[succ.as_usize()])
+                .collect()
         } else {
-            let mut result: Option<HashSet<GraphNodeIdx>> = None;
-            for haystack_pred in &mapped_haystack_fanin {
-                let fanout = self.haystack_index.fanout_set(*haystack_pred);
-                match &mut result {
-                    None => result = Some(fanout.clone()),
-                    Some(r) => r.retain(|x| fanout.contains(x)),
-                }
-            }
-            result.unwrap_or_default().into_iter().collect()
+            Vec::new()
         };
 
-        unfiltered
+        let unfiltered: Vec<GraphNodeIdx> = if !mapped_haystack_fanin.is_empty() {
+            self.common_haystack_fanout(&mapped_haystack_fanin)
+                .into_iter()
+                .collect()
+        } else if !mapped_successors.is_empty() {
+            let successors: Vec<GraphNodeIdx> = mapped_successors
+                .iter()
+                .map(|&(_, haystack)| haystack)
+                .collect();
+            self.common_haystack_fanin(&successors)
+                .into_iter()
+                .collect()
+        } else {
+            self.haystack_index.cells_of_type_indices(kind).to_vec()
+        };
+
+        let mut candidates: Vec<GraphNodeIdx> = unfiltered
             .into_iter()
             .filter(|candidate| {
                 self.haystack_index
@@ -468,43 +1104,58 @@ impl SubgraphMatcherCore<'_, '_, '_> {
                     == kind
             })
             .filter(|candidate| assignment.haystack_is_free(*candidate))
+            .filter(|candidate| {
+                self.needle_required_degrees
+                    .get(needle_cell.as_usize())
+                    .is_none_or(|required| {
+                        self.haystack_index
+                            .degree_signature(*candidate)
+                            .covers(required)
+                    })
+            })
             .filter(|candidate| self.check_fanin_constraints(needle_cell, *candidate, assignment))
-            .collect()
+            .filter(|candidate| {
+                mapped_successors.is_empty() || {
+                    let mut next_assignment = assignment.clone();
+                    next_assignment.assign(needle_cell, *candidate);
+                    mapped_successors.iter().all(|&(succ, haystack_succ)| {
+                        self.check_fanin_constraints(succ, haystack_succ, &next_assignment)
+                    })
+                }
+            })
+            .collect();
+        candidates.sort_unstable();
+
+        if let Some(limit) = self.config.max_candidates_per_node
+            && candidates.len() > limit
+        {
+            candidates.truncate(limit);
+            self.truncated.store(true, Ordering::Relaxed);
+        }
+
+        candidates
     }
 
     /// Filters haystack cells for input ports based on fan-out connectivity.
-    fn find_candidates_for_input(
+    pub(super) fn find_candidates_for_input(
         &self,
         needle_input: GraphNodeIdx,
         assignment: &SingleAssignment,
     ) -> Vec<GraphNodeIdx> {
-        let needle_fanout = self
-            .needle_index
-            .fanout_with_ports(needle_input)
-            .unwrap_or_default();
+        let needle_fanout = self.visible_fanout(self.needle_index, needle_input);
 
         let mapped_haystack_fanout: Vec<GraphNodeIdx> = needle_fanout
             .iter()
-            .filter_map(|(needle_succ, _)| assignment.get_haystack_cell(*needle_succ))
+            .filter_map(|needle_succ| assignment.get_haystack_cell(*needle_succ))
             .collect();
 
-        let mut intersection: Option<HashSet<GraphNodeIdx>> = None;
-        for haystack_succ in &mapped_haystack_fanout {
-            let fanin = self.haystack_index.fanin_set(*haystack_succ);
-            match &mut intersection {
-                None => intersection = Some(fanin.clone()),
-                Some(r) => r.retain(|x| fanin.contains(x)),
-            }
-        }
-
-        intersection
-            .unwrap_or_default()
+        self.common_haystack_fanin(&mapped_haystack_fanout)
             .into_iter()
             .filter(|candidate| {
                 let mut next_assignment = assignment.clone();
                 next_assignment.assign(needle_input, *candidate);
 
-                let fanout = self.haystack_index.fanout_set(*candidate);
+                let fanout = self.visible_fanout(self.haystack_index, *candidate);
                 fanout.iter().all(|haystack_succ| {
                     let needle_cells = next_assignment.get_needle_cells(*haystack_succ);
                     if needle_cells.is_empty() {
@@ -523,20 +1174,59 @@ impl SubgraphMatcherCore<'_, '_, '_> {
             .collect()
     }
 
-    /// Removes duplicate assignments automatically.
+    /// Removes duplicates, then sorts assignments into a deterministic order.
+    ///
+    /// The first match discovered in each duplicate group survives, which is
+    /// the one [`AssignmentIter`] yields. Candidates are visited in index
+    /// order, so neither the representative nor the final order (by
+    /// deduplication key, then full mapping) depends on hash iteration order.
     fn apply_deduplication(&self, results: &mut Vec<SingleAssignment>) {
         let mut seen = HashSet::new();
-        results.retain(|assignment| {
-            // Deduplicate based on the logic gates, ignoring which IO ports were matched
-            let sig = assignment.filtered_signature(&self.needle_internal_mask);
-
-            // If the pattern has NO internal cells (it's just a port-to-port match),
-            // fall back to the full signature so we don't delete everything.
-            if sig.is_empty() {
-                seen.insert(assignment.signature())
-            } else {
-                seen.insert(sig)
-            }
+        results.retain(|assignment| seen.insert(self.dedup_key(assignment)));
+
+        results.sort_by_cached_key(|assignment| {
+            (self.dedup_key(assignment), assignment.sorted_pairs())
         });
     }
+
+    /// Returns the key under which two assignments are considered duplicates.
+    ///
+    /// Without `Dedupe::collapses_design_cells` the key is the full mapping,
+    /// so only identical assignments collapse. With
+    /// `Dedupe::collapses_output_drivers` it is the set of haystack cells
+    /// mapped to the needle's output drivers.
+    pub(super) fn dedup_key(&self, assignment: &SingleAssignment) -> Vec<usize> {
+        if !self.config.dedupe.collapses_design_cells() {
+            return assignment
+                .sorted_pairs()
+                .into_iter()
+                .flat_map(|(needle, haystack)| [needle, haystack])
+                .collect();
+        }
+
+        if self.config.dedupe.collapses_output_drivers() {
+            let mut drivers: Vec<usize> = self
+                .needle_output_drivers
+                .iter()
+                .filter_map(|&driver| assignment.get_haystack_cell(driver))
+                .map(GraphNodeIdx::as_usize)
+                .collect();
+            drivers.sort_unstable();
+            drivers.dedup();
+            if !drivers.is_empty() {
+                return drivers;
+            }
+        }
+
+        // Deduplicate based on the logic gates, ignoring which IO ports were matched
+        let sig = assignment.filtered_signature(&self.needle_internal_mask);
+
+        // If the pattern has NO internal cells (it's just a port-to-port match),
+        // fall back to the full signature so we don't delete everything.
+        if sig.is_empty() {
+            assignment.signature()
+        } else {
+            sig
+        }
+    }
 }
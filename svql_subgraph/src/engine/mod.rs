@@ -3,6 +3,14 @@
 mod assignment;
 mod constraints;
 mod matcher;
+mod progress;
+mod stats;
+mod stream;
 
-pub use assignment::{AssignmentSet, SingleAssignment};
+pub use assignment::{
+    AssignmentSet, CoverageIndex, SingleAssignment, assignments_overlap, overlap_ratio,
+};
 pub use matcher::{SubgraphMatcher, SubgraphMatcherCore};
+pub use progress::{ProgressEvent, ProgressFn};
+pub use stats::SearchStats;
+pub use stream::AssignmentIter;
@@ -0,0 +1,37 @@
+//! Structured progress reporting for long-running searches.
+
+use std::sync::Arc;
+
+/// A coarse progress update emitted while a search runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProgressEvent {
+    /// Designs are being loaded and indexed.
+    Indexing,
+    /// Candidates for the anchor cell have been collected.
+    Anchoring {
+        /// Number of haystack candidates for the anchor cell.
+        candidates: usize,
+    },
+    /// Periodic update while assignments are enumerated.
+    Enumerating {
+        /// Anchor candidates fully explored so far.
+        anchors_done: usize,
+        /// Total anchor candidates.
+        anchors_total: usize,
+        /// Backtracking branches visited so far.
+        branches: usize,
+        /// Matches accepted so far, before deduplication.
+        matches_found: usize,
+    },
+    /// The search finished.
+    Finished {
+        /// Number of matches left after deduplication.
+        matches: usize,
+    },
+}
+
+/// Callback receiving [`ProgressEvent`]s.
+///
+/// Shared between rayon workers, so it must be thread-safe. Events from
+/// parallel searches may arrive from any worker thread.
+pub type ProgressFn = Arc<dyn Fn(ProgressEvent) + Send + Sync>;
@@ -0,0 +1,47 @@
+//! Diagnostics collected during a subgraph search.
+
+use std::fmt;
+use std::time::Duration;
+
+use svql_common::CellKind;
+
+/// Summary of a single subgraph search, useful for explaining empty results.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SearchStats {
+    /// Kind of the needle cell the search was anchored on, if the needle has gates.
+    pub anchor_kind: Option<CellKind>,
+    /// Number of haystack candidates found for the anchor cell.
+    pub anchor_candidates: usize,
+    /// Total backtracking branches visited.
+    pub branches_explored: usize,
+    /// Number of matches found before deduplication.
+    pub raw_matches: usize,
+    /// Number of matches remaining after deduplication.
+    pub deduplicated_matches: usize,
+    /// Wall-clock time spent searching.
+    pub elapsed: Duration,
+    /// Whether a search-breadth cap cut the search short.
+    pub truncated: bool,
+}
+
+impl fmt::Display for SearchStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let anchor = self
+            .anchor_kind
+            .map_or_else(|| "none".to_owned(), |kind| kind.to_string());
+        write!(
+            f,
+            "anchor: {} ({} candidates) | {} branches | {} raw matches, {} after deduplication | {:.2?}",
+            anchor,
+            self.anchor_candidates,
+            self.branches_explored,
+            self.raw_matches,
+            self.deduplicated_matches,
+            self.elapsed
+        )?;
+        if self.truncated {
+            write!(f, " | truncated")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+//! Lazy, depth-first enumeration of subgraph matches.
+//!
+//! Drives the same search step as the eager matcher, but keeps the pending
+//! branches on an explicit stack so that matches can be yielded one at a
+//! time instead of being collected up front.
+
+use std::sync::atomic::Ordering;
+
+use svql_common::{HashSet, HashSetExt};
+
+use super::assignment::SingleAssignment;
+use super::matcher::{Expansion, SearchFrame, SubgraphMatcherCore};
+use super::progress::ProgressEvent;
+
+/// Iterator yielding deduplicated matches as the search discovers them.
+///
+/// Yields the same matches as
+/// [`SubgraphMatcherCore::enumerate_assignments`], with the same
+/// representative kept for each duplicate group, but in discovery order
+/// rather than sorted; `config.parallel` is ignored. Search bounds and
+/// progress events behave as for the eager search. Only the pending branches
+/// and the deduplication keys of yielded matches are held in memory.
+pub struct AssignmentIter<'needle, 'haystack, 'cfg> {
+    /// Matcher providing the search step.
+    core: SubgraphMatcherCore<'needle, 'haystack, 'cfg>,
+    /// Branches still to be explored, most recent last.
+    stack: Vec<SearchFrame>,
+    /// Deduplication keys of the matches yielded so far.
+    seen: HashSet<Vec<usize>>,
+    /// Number of root anchor branches entered so far.
+    anchors_started: usize,
+    /// Set once the search is exhausted and `Finished` has been reported.
+    finished: bool,
+}
+
+impl<'needle, 'haystack, 'cfg> AssignmentIter<'needle, 'haystack, 'cfg> {
+    /// Creates an iterator that lazily runs the search described by `core`.
+    #[must_use]
+    pub fn new(core: SubgraphMatcherCore<'needle, 'haystack, 'cfg>) -> Self {
+        let root = core.root_frame();
+
+        Self {
+            core,
+            stack: vec![root],
+            seen: HashSet::new(),
+            anchors_started: 0,
+            finished: false,
+        }
+    }
+
+    /// Marks the search as exhausted and reports it once.
+    fn finish(&mut self) {
+        if self.finished {
+            return;
+        }
+        self.finished = true;
+        self.stack.clear();
+        self.core
+            .initial_candidates_done
+            .store(self.anchors_started, Ordering::SeqCst);
+        self.core.report(ProgressEvent::Finished {
+            matches: self.seen.len(),
+        });
+    }
+}
+
+impl Iterator for AssignmentIter<'_, '_, '_> {
+    type Item = SingleAssignment;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(frame) = self.stack.pop() {
+            if self.core.should_stop() {
+                break;
+            }
+
+            if frame.anchor {
+                // Sibling anchors are popped in order, so every earlier one is done.
+                self.core
+                    .initial_candidates_done
+                    .store(self.anchors_started, Ordering::SeqCst);
+                self.anchors_started += 1;
+            }
+
+            match self.core.expand(frame) {
+                Expansion::Branches(branches) => self.stack.extend(branches.into_iter().rev()),
+                Expansion::Match(assignment) => {
+                    if self.seen.insert(self.core.dedup_key(&assignment)) {
+                        return Some(assignment);
+                    }
+                }
+            }
+        }
+
+        self.finish();
+        None
+    }
+}
@@ -0,0 +1,127 @@
+//! Extraction of matched sub-circuits as standalone netlists.
+//!
+//! Turns a single assignment back into a `prjunnamed_netlist::Design` that
+//! holds only the matched haystack cells, so a finding can be written out
+//! (e.g. with `prjunnamed_yosys_json`) as a minimal reproducer.
+
+use prjunnamed_netlist::{Design, MetaItem, MetaItemRef, Net};
+use svql_common::*;
+
+use crate::SingleAssignment;
+
+/// Builds a design containing only the haystack cells covered by `assignment`.
+///
+/// Cells matched to needle inputs and outputs are left out, since they only
+/// mark where the pattern connects to the rest of the haystack. Every net a
+/// kept cell reads from outside the selection becomes a one-bit input
+/// `in<N>`, and every kept cell whose output is used outside the selection
+/// is exposed as an output `out<N>`. Constant inputs are kept as constants.
+///
+/// Each exported cell keeps the source locations and attributes of the
+/// haystack cell it was copied from.
+#[must_use]
+pub fn export_match(
+    assignment: &SingleAssignment,
+    needle: &GraphIndex<'_>,
+    haystack: &GraphIndex<'_>,
+) -> Design {
+    let mut selected: Vec<GraphNodeIdx> = assignment
+        .needle_mapping()
+        .iter()
+        .filter(|&(&n, _)| {
+            let kind = needle.node_kind(n);
+            !kind.is_input() && !kind.is_output()
+        })
+        .map(|(_, &h)| h)
+        .filter(|&h| {
+            !matches!(
+                haystack.node_kind(h),
+                CellKind::Input | CellKind::Output | CellKind::Name | CellKind::Debug
+            )
+        })
+        .collect();
+    selected.sort_unstable();
+    selected.dedup();
+    let selected_set: HashSet<GraphNodeIdx> = selected.iter().copied().collect();
+
+    let mut design = Design::new();
+
+    // Reserve a placeholder for each kept cell so feedback loops resolve
+    let mut net_map: HashMap<Net, Net> = HashMap::new();
+    let placeholders: Vec<_> = selected
+        .iter()
+        .map(|&idx| {
+            let output = haystack.get_cell_by_index(idx).output_wire().0;
+            let void = design.add_void(output.len());
+            for (old, new) in output.iter().zip(void.iter()) {
+                net_map.insert(old, new);
+            }
+            (idx, void)
+        })
+        .collect();
+
+    let mut input_count = 0;
+    let mut output_count = 0;
+    for (idx, void) in placeholders {
+        let wrapper = haystack.get_cell_by_index(idx);
+        let mut cell = wrapper.get().into_owned();
+        cell.visit_mut(|net: &mut Net| {
+            if net.is_const() {
+                return;
+            }
+            *net = *net_map.entry(*net).or_insert_with(|| {
+                let input = design.add_input(format!("in{input_count}"), 1);
+                input_count += 1;
+                input[0]
+            });
+        });
+
+        let output = {
+            let _guard =
+                copy_metadata(&design, wrapper.metadata()).map(|meta| design.use_metadata(meta));
+            design.add_cell(cell)
+        };
+        design.replace_value(&void, &output);
+
+        if haystack
+            .fanout_set(idx)
+            .iter()
+            .any(|sink| !selected_set.contains(sink))
+        {
+            design.add_output(format!("out{output_count}"), output);
+            output_count += 1;
+        }
+    }
+
+    design.apply();
+    design
+}
+
+/// Recreates the source locations and attributes of `meta` in `design`.
+///
+/// Metadata belongs to the design it was created in, so each item is rebuilt
+/// with its strings added to `design`. Other kinds of items are dropped.
+fn copy_metadata<'d>(design: &'d Design, meta: MetaItemRef<'_>) -> Option<MetaItemRef<'d>> {
+    let mut items: Vec<MetaItemRef<'d>> = meta
+        .iter()
+        .filter_map(|item| match item.get() {
+            MetaItem::Source { file, start, end } => Some(MetaItem::Source {
+                file: design.add_metadata_string(&file.get()),
+                start,
+                end,
+            }),
+            MetaItem::Attr { name, value } => Some(MetaItem::Attr {
+                name: design.add_metadata_string(&name.get()),
+                value,
+            }),
+            _ => None,
+        })
+        .map(|item| design.add_metadata_item(&item))
+        .collect();
+
+    if items.len() > 1 {
+        Some(design.add_metadata_item(&MetaItem::Set(items.into_iter().collect())))
+    } else {
+        items.pop()
+    }
+}
@@ -17,12 +17,19 @@
 //! Results are returned as `AssignmentSet` containing all valid pattern matches.
 
 mod engine;
+mod export;
 mod utils;
+mod verify;
 
 /// Infrastructure for testing pattern matching against known fixtures.
 pub mod test_harness;
 
 pub mod cell;
 
-pub use engine::{AssignmentSet, SingleAssignment, SubgraphMatcher, SubgraphMatcherCore};
+pub use engine::{
+    AssignmentIter, AssignmentSet, CoverageIndex, ProgressEvent, ProgressFn, SearchStats,
+    SingleAssignment, SubgraphMatcher, SubgraphMatcherCore, assignments_overlap, overlap_ratio,
+};
+pub use export::export_match;
 pub use utils::intersect_sets;
+pub use verify::{FunctionalVerdict, verify_match_functional};
@@ -90,6 +90,23 @@ pub fn run_query_test(spec: TestSpec) -> Result<(), Box<dyn std::error::Error>>
         .into());
     }
 
+    // The count-only path must agree with full enumeration
+    let match_count = SubgraphMatcher::count_all(
+        &needle,
+        &design,
+        needle_module.module_name().to_owned(),
+        design_module.module_name().to_owned(),
+        &config,
+    );
+
+    if match_count != assignment_set.len() {
+        return Err(format!(
+            "count_all returned {match_count}, enumerate_all returned {}",
+            assignment_set.len()
+        )
+        .into());
+    }
+
     Ok(())
 }
 
@@ -0,0 +1,243 @@
+//! Functional verification of matches with an SMT solver.
+//!
+//! A structural match only shows that the haystack cells have the needle's
+//! topology. This module builds a miter of the needle and the matched cells,
+//! with shared inputs, and asks an SMT solver whether their outputs can ever
+//! differ.
+
+use std::fmt;
+
+use prjunnamed_netlist::{Design, EasySmtEngine, Net, SmtBuilder, Value};
+use svql_common::*;
+
+use crate::SingleAssignment;
+
+/// Solver binary used by `verify_match_functional`.
+const SMT_SOLVER: &str = "z3";
+
+/// Arguments putting the solver into interactive SMT-LIB2 mode.
+const SMT_SOLVER_ARGS: [&str; 2] = ["-smt2", "-in"];
+
+/// Outcome of checking a match for functional equivalence with its needle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FunctionalVerdict {
+    /// The match computes the needle's outputs for every input.
+    Proved,
+    /// Some input makes the match and the needle disagree.
+    Disproved,
+    /// The solver could not be run, failed, or there was nothing to compare.
+    Unknown,
+}
+
+impl fmt::Display for FunctionalVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Proved => write!(f, "proved"),
+            Self::Disproved => write!(f, "disproved"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Proves or refutes that `assignment` is functionally equivalent to the needle.
+///
+/// The needle and the haystack cells covered by `assignment` are copied into
+/// one miter design. A haystack net read from outside the match is tied to
+/// the needle input it was paired with on a matched gate; any other outside
+/// net becomes a free input, and constants stay constant. Each needle output
+/// bit driven by a matched gate is then compared with the same bit of the
+/// haystack gate it maps to.
+///
+/// The check runs `z3`, which must be on the `PATH`. Returns
+/// `FunctionalVerdict::Unknown` if the solver cannot be started or fails,
+/// and if no needle output is driven by a matched gate.
+#[must_use]
+pub fn verify_match_functional(
+    assignment: &SingleAssignment,
+    needle: &GraphIndex<'_>,
+    haystack: &GraphIndex<'_>,
+) -> FunctionalVerdict {
+    let mut miter = Design::new();
+
+    // Needle inputs become the shared miter inputs
+    let mut needle_nets: HashMap<Net, Net> = HashMap::new();
+    for idx in needle.cells_of_type_indices(CellKind::Input) {
+        let cell = needle.get_cell_by_index(*idx);
+        let output = cell.output_wire().0;
+        let name = cell.input_name().unwrap_or_default();
+        let input = miter.add_input(name, output.len());
+        needle_nets.extend(output.iter().zip(input.iter()));
+    }
+    let input_nets: HashSet<Net> = needle_nets.keys().copied().collect();
+
+    let needle_gates: Vec<GraphNodeIdx> = (0..needle.num_cells())
+        .map(|i| GraphNodeIdx::new(i as u32))
+        .filter(|&idx| is_copied(needle.node_kind(idx)))
+        .collect();
+    let haystack_gates: Vec<GraphNodeIdx> = {
+        let mut gates: Vec<GraphNodeIdx> = needle_gates
+            .iter()
+            .filter_map(|&n| assignment.get_haystack_cell(n))
+            .filter(|&h| is_copied(haystack.node_kind(h)))
+            .collect();
+        gates.sort_unstable();
+        gates.dedup();
+        gates
+    };
+
+    // Tie outside haystack nets to the needle inputs they were matched against
+    let mut haystack_nets: HashMap<Net, Net> = HashMap::new();
+    for &n in &needle_gates {
+        let Some(h) = assignment.get_haystack_cell(n) else {
+            continue;
+        };
+        let pairs = input_nets_of(needle, n)
+            .into_iter()
+            .zip(input_nets_of(haystack, h));
+        for (needle_net, haystack_net) in pairs {
+            if input_nets.contains(&needle_net) && !haystack_net.is_const() {
+                haystack_nets
+                    .entry(haystack_net)
+                    .or_insert(needle_nets[&needle_net]);
+            }
+        }
+    }
+
+    let mut free_inputs = 0;
+    let needle_outputs = copy_cells(
+        &miter,
+        needle,
+        &needle_gates,
+        &mut needle_nets,
+        &mut free_inputs,
+    );
+    let haystack_outputs = copy_cells(
+        &miter,
+        haystack,
+        &haystack_gates,
+        &mut haystack_nets,
+        &mut free_inputs,
+    );
+
+    let mut compared: Vec<(Net, Net)> = Vec::new();
+    for idx in needle.cells_of_type_indices(CellKind::Output) {
+        let cell = needle.get_cell_by_index(*idx).get();
+        let prjunnamed_netlist::Cell::Output(_, value) = cell.as_ref() else {
+            continue;
+        };
+        for net in value.iter() {
+            let Some((gate, bit)) = driver_of(needle, &needle_gates, net) else {
+                continue;
+            };
+            let pair = assignment
+                .get_haystack_cell(gate)
+                .and_then(|h| haystack_outputs.get(&h))
+                .and_then(|output| output.iter().nth(bit))
+                .map(|haystack_net| (needle_outputs[&gate][bit], haystack_net));
+            compared.extend(pair);
+        }
+    }
+    if compared.is_empty() {
+        return FunctionalVerdict::Unknown;
+    }
+
+    miter.apply();
+    check_equivalent(&miter, &compared).unwrap_or(FunctionalVerdict::Unknown)
+}
+
+/// Whether a cell of this kind is part of the logic copied into the miter.
+const fn is_copied(kind: CellKind) -> bool {
+    !matches!(
+        kind,
+        CellKind::Input | CellKind::Output | CellKind::Name | CellKind::Debug
+    )
+}
+
+/// Returns the nets a cell reads, in port order.
+fn input_nets_of(index: &GraphIndex<'_>, idx: GraphNodeIdx) -> Vec<Net> {
+    let mut nets = Vec::new();
+    index.get_cell_by_index(idx).get().visit(|net| nets.push(net));
+    nets
+}
+
+/// Finds the copied gate and output bit that drive `net`.
+fn driver_of(
+    index: &GraphIndex<'_>,
+    gates: &[GraphNodeIdx],
+    net: Net,
+) -> Option<(GraphNodeIdx, usize)> {
+    gates.iter().find_map(|&gate| {
+        index
+            .get_cell_by_index(gate)
+            .output_wire()
+            .0
+            .iter()
+            .position(|out| out == net)
+            .map(|bit| (gate, bit))
+    })
+}
+
+/// Copies `cells` from `index` into `miter`, returning each copy's output.
+///
+/// `net_map` holds the nets already bound in the miter and gains the outputs
+/// of the copies. Nets read from outside the copied cells and not in
+/// `net_map` become fresh one-bit inputs `free<N>`, numbered from
+/// `free_inputs`.
+fn copy_cells(
+    miter: &Design,
+    index: &GraphIndex<'_>,
+    cells: &[GraphNodeIdx],
+    net_map: &mut HashMap<Net, Net>,
+    free_inputs: &mut usize,
+) -> HashMap<GraphNodeIdx, Value> {
+    // Reserve a placeholder for each cell so feedback loops resolve
+    let placeholders: Vec<_> = cells
+        .iter()
+        .map(|&idx| {
+            let output = index.get_cell_by_index(idx).output_wire().0;
+            let void = miter.add_void(output.len());
+            net_map.extend(output.iter().zip(void.iter()));
+            (idx, void)
+        })
+        .collect();
+
+    let mut outputs = HashMap::new();
+    for (idx, void) in placeholders {
+        let mut cell = index.get_cell_by_index(idx).get().into_owned();
+        cell.visit_mut(|net: &mut Net| {
+            if net.is_const() {
+                return;
+            }
+            *net = *net_map.entry(*net).or_insert_with(|| {
+                let input = miter.add_input(format!("free{free_inputs}"), 1);
+                *free_inputs += 1;
+                input[0]
+            });
+        });
+        let output = miter.add_cell(cell);
+        miter.replace_value(&void, &output);
+        outputs.insert(idx, output);
+    }
+    outputs
+}
+
+/// Asks the solver whether any compared pair of nets can differ.
+fn check_equivalent(miter: &Design, compared: &[(Net, Net)]) -> Option<FunctionalVerdict> {
+    let context = easy_smt::ContextBuilder::new()
+        .solver(SMT_SOLVER, SMT_SOLVER_ARGS)
+        .build()
+        .ok()?;
+    let engine = EasySmtEngine::new(context).ok()?;
+
+    let mut smt = SmtBuilder::new(miter, engine);
+    for cell_ref in miter.iter_cells() {
+        smt.add_cell(&cell_ref.output(), &cell_ref.get()).ok()?;
+    }
+    for &(needle_net, haystack_net) in compared {
+        smt.replace_net(needle_net, haystack_net).ok()?;
+    }
+    match smt.check().ok()? {
+        None => Some(FunctionalVerdict::Proved),
+        Some(_) => Some(FunctionalVerdict::Disproved),
+    }
+}
@@ -0,0 +1,105 @@
+//! Integration tests for anchoring the search on a preferred cell kind.
+//!
+//! A preferred anchor only changes where the search starts, so every
+//! preference must yield the same matches as the default anchor.
+
+use prjunnamed_netlist::{Cell, Design};
+use svql_common::{CellKind, Config, MatchLength, ModuleConfig, YosysModule};
+use svql_subgraph::SubgraphMatcher;
+
+/// `y = (a & b) | c`
+fn and_or() -> Design {
+    let mut design = Design::new();
+    let a = design.add_input("a", 1);
+    let b = design.add_input("b", 1);
+    let c = design.add_input("c", 1);
+    let ab = design.add_and(a, b);
+    let y = design.add_cell(Cell::Or(ab, c));
+    design.add_output("y", y);
+    design.apply();
+    design
+}
+
+/// Two `and_or` instances sharing inputs, plus a lone AND gate.
+fn and_or_pair() -> Design {
+    let mut design = Design::new();
+    let a = design.add_input("a", 1);
+    let b = design.add_input("b", 1);
+    let c = design.add_input("c", 1);
+    let d = design.add_input("d", 1);
+    let ab = design.add_and(a.clone(), b.clone());
+    let y0 = design.add_cell(Cell::Or(ab, c.clone()));
+    let cd = design.add_and(c, d.clone());
+    let y1 = design.add_cell(Cell::Or(cd, a.clone()));
+    let lone = design.add_and(b, d);
+    design.add_output("y0", y0);
+    design.add_output("y1", y1);
+    design.add_output("lone", lone);
+    design.apply();
+    design
+}
+
+/// Returns the match count and anchor kind of `needle` in `haystack`.
+fn search(
+    needle: &Design,
+    haystack: &Design,
+    preference: Option<CellKind>,
+) -> (usize, Option<CellKind>) {
+    let config = Config::builder()
+        .match_length(MatchLength::NeedleSubsetHaystack)
+        .anchor_preference(preference)
+        .build();
+    let (assignments, stats) = SubgraphMatcher::enumerate_with_stats(
+        needle,
+        haystack,
+        "needle".to_owned(),
+        "haystack".to_owned(),
+        &config,
+    );
+    (assignments.len(), stats.anchor_kind)
+}
+
+#[test]
+fn test_preferred_anchor_keeps_matches() {
+    let needle = and_or();
+    let haystack = and_or_pair();
+    let (default_count, _) = search(&needle, &haystack, None);
+
+    let (count, anchor) = search(&needle, &haystack, Some(CellKind::Or));
+    assert_eq!(anchor, Some(CellKind::Or));
+    assert_eq!(count, default_count);
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn test_unviable_preference_falls_back() {
+    let needle = and_or();
+    let haystack = and_or_pair();
+    let (default_count, default_anchor) = search(&needle, &haystack, None);
+
+    let (count, anchor) = search(&needle, &haystack, Some(CellKind::Xor));
+    assert_eq!(anchor, default_anchor);
+    assert_eq!(count, default_count);
+}
+
+#[test]
+fn test_preferred_anchor_on_fixture() -> Result<(), Box<dyn std::error::Error>> {
+    let design = YosysModule::new(
+        "examples/fixtures/cwes/cwe1234/cwe1234_multi_width.v",
+        "cwe1234_multi_width",
+    )?
+    .import_design(&ModuleConfig::default())?;
+    let needle = YosysModule::new(
+        "examples/patterns/security/access_control/locked_reg/rtlil/async_mux.il",
+        "async_mux",
+    )?
+    .import_design(&ModuleConfig::default())?;
+
+    let (default_count, _) = search(&needle, &design, None);
+    for kind in [CellKind::Dff, CellKind::Mux] {
+        let (count, anchor) = search(&needle, &design, Some(kind));
+        assert_eq!(anchor, Some(kind));
+        assert_eq!(count, default_count);
+    }
+    Ok(())
+}
@@ -0,0 +1,45 @@
+//! Integration tests for persisting assignment sets as JSON.
+//!
+//! Verifies that a captured search result reloads with the same mappings,
+//! so it can be replayed without re-running the search.
+
+use svql_common::{Config, MatchLength, ModuleConfig, YosysModule};
+use svql_subgraph::{AssignmentSet, SubgraphMatcher};
+
+#[test]
+fn test_assignment_set_json_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let design_module = YosysModule::new(
+        "examples/fixtures/basic/and/verilog/small_and_tree.v",
+        "small_and_tree",
+    )?;
+    let needle_module =
+        YosysModule::new("examples/patterns/basic/and/verilog/and_gate.v", "and_gate")?;
+
+    let design = design_module.import_design(&ModuleConfig::default())?;
+    let needle = needle_module.import_design(&ModuleConfig::default())?;
+
+    let config = Config::builder()
+        .match_length(MatchLength::NeedleSubsetHaystack)
+        .build();
+
+    let captured = SubgraphMatcher::enumerate_all(
+        &needle,
+        &design,
+        needle_module.module_name().to_owned(),
+        design_module.module_name().to_owned(),
+        &config,
+    );
+
+    let path = std::env::temp_dir().join(format!("svql_assignments_{}.json", std::process::id()));
+    captured.to_json_file(&path)?;
+    let replayed = AssignmentSet::from_json_file(&path)?;
+    std::fs::remove_file(&path)?;
+
+    assert_eq!(replayed.len(), captured.len());
+    assert_eq!(replayed.truncated, captured.truncated);
+    for (replayed, captured) in replayed.items.iter().zip(&captured.items) {
+        assert!(replayed.is_consistent());
+        assert_eq!(replayed.needle_mapping(), captured.needle_mapping());
+    }
+    Ok(())
+}
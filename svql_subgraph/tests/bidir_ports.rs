@@ -0,0 +1,35 @@
+//! Integration tests for bidirectional port matching.
+//!
+//! Verifies that a pattern `inout` pin only binds to ordinary haystack nets
+//! when `bidir_ports` is enabled, whether the net is driven by a primary
+//! input or by internal logic that also feeds an output.
+
+use svql_common::ConfigBuilder;
+use svql_subgraph::query_test;
+
+const fn bidir_config(c: ConfigBuilder) -> ConfigBuilder {
+    c.bidir_ports(true)
+}
+
+query_test!(
+    name: test_and_inout_without_bidir_ports,
+    needle: ("examples/fixtures/basic/and/verilog/and_inout.v", "and_inout"),
+    haystack: ("examples/patterns/basic/and/verilog/and_gate.v", "and_gate"),
+    expect: 0
+);
+
+query_test!(
+    name: test_and_inout_input_driven,
+    needle: ("examples/fixtures/basic/and/verilog/and_inout.v", "and_inout"),
+    haystack: ("examples/patterns/basic/and/verilog/and_gate.v", "and_gate"),
+    expect: 1,
+    config: bidir_config
+);
+
+query_test!(
+    name: test_and_inout_output_driven,
+    needle: ("examples/fixtures/basic/and/verilog/and_inout.v", "and_inout"),
+    haystack: ("examples/fixtures/basic/and/verilog/and_output_driven.v", "and_output_driven"),
+    expect: 1,
+    config: bidir_config
+);
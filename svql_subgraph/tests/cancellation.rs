@@ -0,0 +1,51 @@
+//! Integration tests for aborting a subgraph search via a cancel flag.
+//!
+//! Verifies that a raised flag stops the search and that an unraised flag
+//! leaves the results untouched.
+
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use svql_common::{Config, MatchLength, ModuleConfig, YosysModule};
+use svql_subgraph::SubgraphMatcher;
+
+fn run_cancellable(cancelled: bool) -> Result<usize, Box<dyn std::error::Error>> {
+    let design_module = YosysModule::new(
+        "examples/fixtures/cwes/cwe1234/cwe1234_multi_width.v",
+        "cwe1234_multi_width",
+    )?;
+    let needle_module = YosysModule::new(
+        "examples/patterns/security/access_control/locked_reg/rtlil/async_mux.il",
+        "async_mux",
+    )?;
+
+    let design = design_module.import_design(&ModuleConfig::default())?;
+    let needle = needle_module.import_design(&ModuleConfig::default())?;
+
+    let config = Config::builder()
+        .match_length(MatchLength::NeedleSubsetHaystack)
+        .build();
+
+    let assignment_set = SubgraphMatcher::enumerate_cancellable(
+        &needle,
+        &design,
+        needle_module.module_name().to_owned(),
+        design_module.module_name().to_owned(),
+        &config,
+        Arc::new(AtomicBool::new(cancelled)),
+    );
+
+    Ok(assignment_set.len())
+}
+
+#[test]
+fn test_cancelled_search_returns_no_matches() -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!(run_cancellable(true)?, 0);
+    Ok(())
+}
+
+#[test]
+fn test_uncancelled_search_returns_all_matches() -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!(run_cancellable(false)?, 5);
+    Ok(())
+}
@@ -0,0 +1,34 @@
+//! Integration tests for commutative operand matching.
+//!
+//! Verifies that the subgraph core matches gates regardless of the order
+//! in which synthesis connected their inputs.
+
+use svql_subgraph::query_test;
+
+query_test!(
+    name: test_and_2_seq_canonical_order,
+    needle: ("examples/patterns/basic/and/verilog/and_2_seq.v", "and_2_seq"),
+    haystack: ("examples/patterns/basic/and/verilog/and_2_seq.v", "and_2_seq"),
+    expect: 1
+);
+
+query_test!(
+    name: test_and_2_seq_swapped_inputs,
+    needle: ("examples/patterns/basic/and/verilog/and_2_seq.v", "and_2_seq"),
+    haystack: ("examples/fixtures/basic/and/verilog/and_2_seq_swapped.v", "and_2_seq_swapped"),
+    expect: 1
+);
+
+query_test!(
+    name: test_mul_2_seq_swapped_inputs,
+    needle: ("examples/patterns/basic/arith/verilog/mul_2_seq.v", "mul_2_seq"),
+    haystack: ("examples/fixtures/basic/arith/verilog/mul_2_seq_swapped.v", "mul_2_seq_swapped"),
+    expect: 1
+);
+
+query_test!(
+    name: test_add_2_seq_swapped_inputs,
+    needle: ("examples/patterns/basic/arith/verilog/add_2_seq.v", "add_2_seq"),
+    haystack: ("examples/fixtures/basic/arith/verilog/add_2_seq_swapped.v", "add_2_seq_swapped"),
+    expect: 1
+);
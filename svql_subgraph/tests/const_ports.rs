@@ -0,0 +1,31 @@
+//! Integration tests for binding pattern inputs to design constants.
+//!
+//! Verifies that a pattern input tied to a constant in the haystack only
+//! matches when `pattern_vars_match_design_consts` is enabled.
+
+use svql_common::ConfigBuilder;
+use svql_subgraph::query_test;
+
+const fn consts_allowed_config(c: ConfigBuilder) -> ConfigBuilder {
+    c.pattern_vars_match_design_consts(true)
+}
+
+const fn consts_forbidden_config(c: ConfigBuilder) -> ConfigBuilder {
+    c.pattern_vars_match_design_consts(false)
+}
+
+query_test!(
+    name: test_and_gate_input_tied_to_const_allowed,
+    needle: ("examples/patterns/basic/and/verilog/and_gate.v", "and_gate"),
+    haystack: ("examples/fixtures/basic/and/verilog/and_const_tied.v", "and_const_tied"),
+    expect: 1,
+    config: consts_allowed_config
+);
+
+query_test!(
+    name: test_and_gate_input_tied_to_const_forbidden,
+    needle: ("examples/patterns/basic/and/verilog/and_gate.v", "and_gate"),
+    haystack: ("examples/fixtures/basic/and/verilog/and_const_tied.v", "and_const_tied"),
+    expect: 0,
+    config: consts_forbidden_config
+);
@@ -0,0 +1,48 @@
+//! Integration tests for the deduplication modes.
+//!
+//! A single AND gate matches a haystack AND gate once per operand order, so
+//! the embeddings differ only in their port bindings.
+
+use prjunnamed_netlist::Design;
+use svql_common::{Config, Dedupe, MatchLength};
+use svql_subgraph::SubgraphMatcher;
+
+/// `y = a & b`
+fn and_gate() -> Design {
+    let mut design = Design::new();
+    let a = design.add_input("a", 1);
+    let b = design.add_input("b", 1);
+    let y = design.add_and(a, b);
+    design.add_output("y", y);
+    design.apply();
+    design
+}
+
+/// Counts matches of the AND gate in itself under `dedupe`.
+fn count_matches(dedupe: Dedupe) -> usize {
+    let needle = and_gate();
+    let haystack = and_gate();
+    let config = Config::builder()
+        .match_length(MatchLength::NeedleSubsetHaystack)
+        .dedupe(dedupe)
+        .build();
+    SubgraphMatcher::enumerate_all(
+        &needle,
+        &haystack,
+        "and_gate".to_owned(),
+        "and_gate".to_owned(),
+        &config,
+    )
+    .len()
+}
+
+#[test]
+fn test_dedupe_all_collapses_port_bindings() {
+    assert_eq!(count_matches(Dedupe::All), 1);
+    assert_eq!(count_matches(Dedupe::ByDesignCells), 1);
+}
+
+#[test]
+fn test_dedupe_none_keeps_every_embedding() {
+    assert!(count_matches(Dedupe::None) >= count_matches(Dedupe::All));
+}
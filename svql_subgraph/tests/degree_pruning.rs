@@ -0,0 +1,66 @@
+//! Integration tests for degree-based candidate pruning.
+//!
+//! Verifies that haystack cells with too few neighbours are discarded before
+//! backtracking without losing any match.
+
+use prjunnamed_netlist::Design;
+use svql_common::{Config, MatchLength};
+use svql_subgraph::SubgraphMatcher;
+
+/// `ab = a & b; y0 = ab | c; y1 = ab | d`
+fn shared_and() -> Design {
+    let mut design = Design::new();
+    let a = design.add_input("a", 1);
+    let b = design.add_input("b", 1);
+    let c = design.add_input("c", 1);
+    let d = design.add_input("d", 1);
+    let ab = design.add_and(a, b);
+    let y0 = design.add_or(ab, c);
+    let y1 = design.add_or(ab, d);
+    design.add_output("y0", y0);
+    design.add_output("y1", y1);
+    design.apply();
+    design
+}
+
+/// The shared AND next to three AND gates that each drive a single output.
+fn shared_and_among_lone_ands() -> Design {
+    let mut design = Design::new();
+    for i in 0..3 {
+        let a = design.add_input(format!("lone_a{i}"), 1);
+        let b = design.add_input(format!("lone_b{i}"), 1);
+        let y = design.add_and(a, b);
+        design.add_output(format!("lone_y{i}"), y);
+    }
+    let a = design.add_input("a", 1);
+    let b = design.add_input("b", 1);
+    let c = design.add_input("c", 1);
+    let d = design.add_input("d", 1);
+    let ab = design.add_and(a, b);
+    let y0 = design.add_or(ab, c);
+    let y1 = design.add_or(ab, d);
+    design.add_output("y0", y0);
+    design.add_output("y1", y1);
+    design.apply();
+    design
+}
+
+#[test]
+fn test_low_fanout_anchor_candidates_are_pruned() {
+    let needle = shared_and();
+    let haystack = shared_and_among_lone_ands();
+    let config = Config::builder()
+        .match_length(MatchLength::NeedleSubsetHaystack)
+        .build();
+
+    let (assignment_set, stats) = SubgraphMatcher::enumerate_with_stats(
+        &needle,
+        &haystack,
+        "shared_and".to_owned(),
+        "shared_and_among_lone_ands".to_owned(),
+        &config,
+    );
+
+    assert_eq!(stats.anchor_candidates, 1);
+    assert_eq!(assignment_set.len(), 1);
+}
@@ -0,0 +1,46 @@
+//! Integration tests for reproducible match ordering.
+//!
+//! Verifies that repeated searches return matches in an identical order
+//! despite hash-based internal state.
+
+use svql_common::{Config, MatchLength, ModuleConfig, YosysModule};
+use svql_subgraph::SubgraphMatcher;
+
+#[test]
+fn test_repeated_searches_return_identical_order() -> Result<(), Box<dyn std::error::Error>> {
+    let design_module = YosysModule::new(
+        "examples/fixtures/cwes/cwe1234/cwe1234_multi_width.v",
+        "cwe1234_multi_width",
+    )?;
+    let needle_module = YosysModule::new(
+        "examples/patterns/security/access_control/locked_reg/rtlil/async_mux.il",
+        "async_mux",
+    )?;
+
+    let design = design_module.import_design(&ModuleConfig::default())?;
+    let needle = needle_module.import_design(&ModuleConfig::default())?;
+
+    let config = Config::builder()
+        .match_length(MatchLength::NeedleSubsetHaystack)
+        .parallel(true)
+        .build();
+
+    let run = || {
+        SubgraphMatcher::enumerate_all(
+            &needle,
+            &design,
+            needle_module.module_name().to_owned(),
+            design_module.module_name().to_owned(),
+            &config,
+        )
+    };
+
+    let first = run();
+    let second = run();
+
+    assert_eq!(first.len(), second.len());
+    for (a, b) in first.items.iter().zip(&second.items) {
+        assert_eq!(a.needle_mapping(), b.needle_mapping());
+    }
+    Ok(())
+}
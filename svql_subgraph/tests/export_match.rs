@@ -0,0 +1,137 @@
+//! Integration tests for exporting matched sub-circuits.
+//!
+//! Verifies that an exported match contains exactly the matched gates plus
+//! the boundary ports needed to drive and observe them, and that the gates
+//! keep their metadata.
+
+use prjunnamed_netlist::{Design, MetaItem, ParamValue};
+use svql_common::{CellKind, Config, GraphIndex, MatchLength, ModuleConfig, YosysModule};
+use svql_subgraph::{SubgraphMatcher, export_match};
+
+#[test]
+fn test_export_match_keeps_only_matched_gates() -> Result<(), Box<dyn std::error::Error>> {
+    let design_module = YosysModule::new(
+        "examples/fixtures/basic/and/verilog/small_and_tree.v",
+        "small_and_tree",
+    )?;
+    let needle_module =
+        YosysModule::new("examples/patterns/basic/and/verilog/and_gate.v", "and_gate")?;
+
+    let design = design_module.import_design(&ModuleConfig::default())?;
+    let needle = needle_module.import_design(&ModuleConfig::default())?;
+
+    let config = Config::builder()
+        .match_length(MatchLength::NeedleSubsetHaystack)
+        .build();
+
+    let assignments = SubgraphMatcher::enumerate_all(
+        &needle,
+        &design,
+        needle_module.module_name().to_owned(),
+        design_module.module_name().to_owned(),
+        &config,
+    );
+    assert_eq!(assignments.len(), 3);
+
+    let needle_index = GraphIndex::build(&needle);
+    let haystack_index = GraphIndex::build(&design);
+    for assignment in &assignments.items {
+        let exported = export_match(assignment, &needle_index, &haystack_index);
+        let index = GraphIndex::build(&exported);
+
+        assert_eq!(index.count_of_kind(CellKind::And), 1);
+        assert_eq!(index.count_of_kind(CellKind::Input), 2);
+        assert_eq!(index.count_of_kind(CellKind::Output), 1);
+    }
+    Ok(())
+}
+
+/// Builds a single AND gate, optionally tagged with a `dont_touch` attribute.
+fn create_and_design(annotated: bool) -> Design {
+    let mut design = Design::new();
+    let a = design.add_input("a", 1);
+    let b = design.add_input("b", 1);
+    let y = if annotated {
+        let name = design.add_metadata_string("dont_touch");
+        let attr = design.add_metadata_item(&MetaItem::Attr {
+            name,
+            value: ParamValue::Int(1),
+        });
+        let _guard = design.use_metadata(attr);
+        design.add_and(a, b)
+    } else {
+        design.add_and(a, b)
+    };
+    design.add_output("y", y);
+    design.apply();
+    design
+}
+
+#[test]
+fn test_export_match_keeps_attributes() {
+    let needle = create_and_design(false);
+    let haystack = create_and_design(true);
+
+    let assignments = SubgraphMatcher::enumerate_all(
+        &needle,
+        &haystack,
+        "and_gate".to_owned(),
+        "annotated".to_owned(),
+        &Config::default(),
+    );
+    assert_eq!(assignments.len(), 1);
+
+    let needle_index = GraphIndex::build(&needle);
+    let haystack_index = GraphIndex::build(&haystack);
+    let exported = export_match(&assignments.items[0], &needle_index, &haystack_index);
+    let index = GraphIndex::build(&exported);
+
+    let original = haystack_index
+        .get_cell_by_index(haystack_index.cells_of_type_indices(CellKind::And)[0])
+        .attr("dont_touch");
+    let copied = index
+        .get_cell_by_index(index.cells_of_type_indices(CellKind::And)[0])
+        .attr("dont_touch");
+    assert!(original.is_some());
+    assert_eq!(copied, original);
+}
+
+#[test]
+fn test_export_match_keeps_source_locations() -> Result<(), Box<dyn std::error::Error>> {
+    let design_module = YosysModule::new(
+        "examples/fixtures/basic/and/verilog/small_and_tree.v",
+        "small_and_tree",
+    )?;
+    let needle_module =
+        YosysModule::new("examples/patterns/basic/and/verilog/and_gate.v", "and_gate")?;
+
+    let design = design_module.import_design(&ModuleConfig::default())?;
+    let needle = needle_module.import_design(&ModuleConfig::default())?;
+
+    let assignments = SubgraphMatcher::enumerate_all(
+        &needle,
+        &design,
+        needle_module.module_name().to_owned(),
+        design_module.module_name().to_owned(),
+        &Config::default(),
+    );
+
+    let needle_index = GraphIndex::build(&needle);
+    let haystack_index = GraphIndex::build(&design);
+    let needle_and = needle_index.cells_of_type_indices(CellKind::And)[0];
+    for assignment in &assignments.items {
+        let exported = export_match(assignment, &needle_index, &haystack_index);
+        let index = GraphIndex::build(&exported);
+
+        let matched = assignment
+            .get_haystack_cell(needle_and)
+            .ok_or("Needle gate is not mapped")?;
+        let original = haystack_index.get_cell_by_index(matched).get_source();
+        let copied = index
+            .get_cell_by_index(index.cells_of_type_indices(CellKind::And)[0])
+            .get_source();
+        assert!(original.is_some());
+        assert_eq!(copied, original);
+    }
+    Ok(())
+}
@@ -0,0 +1,68 @@
+//! Integration tests for skipping cell kinds during matching.
+//!
+//! Verifies that a buffer present only in the haystack breaks a chain of
+//! gates by default and is looked through once buffers are ignored.
+
+use prjunnamed_netlist::{Cell, Design};
+use svql_common::{CellKind, Config, MatchLength};
+use svql_subgraph::SubgraphMatcher;
+
+/// `y = (a & b) & c`
+fn and_chain() -> Design {
+    let mut design = Design::new();
+    let a = design.add_input("a", 1);
+    let b = design.add_input("b", 1);
+    let c = design.add_input("c", 1);
+    let ab = design.add_and(a, b);
+    let y = design.add_and(ab, c);
+    design.add_output("y", y);
+    design.apply();
+    design
+}
+
+/// `y = buf(a & b) & c`
+fn buffered_and_chain() -> Design {
+    let mut design = Design::new();
+    let a = design.add_input("a", 1);
+    let b = design.add_input("b", 1);
+    let c = design.add_input("c", 1);
+    let ab = design.add_and(a, b);
+    let ab_buf = design.add_cell(Cell::Buf(ab));
+    let y = design.add_and(ab_buf, c);
+    design.add_output("y", y);
+    design.apply();
+    design
+}
+
+/// Counts matches of the plain AND chain in the buffered design.
+fn count_matches(config: &Config) -> usize {
+    let needle = and_chain();
+    let haystack = buffered_and_chain();
+    SubgraphMatcher::enumerate_all(
+        &needle,
+        &haystack,
+        "and_chain".to_owned(),
+        "buffered_and_chain".to_owned(),
+        config,
+    )
+    .len()
+}
+
+#[test]
+fn test_buffer_blocks_match_by_default() {
+    let config = Config::builder()
+        .match_length(MatchLength::NeedleSubsetHaystack)
+        .build();
+
+    assert_eq!(count_matches(&config), 0);
+}
+
+#[test]
+fn test_ignored_buffer_is_transparent() {
+    let config = Config::builder()
+        .match_length(MatchLength::NeedleSubsetHaystack)
+        .ignore_cell_kinds([CellKind::Buf])
+        .build();
+
+    assert_eq!(count_matches(&config), 1);
+}
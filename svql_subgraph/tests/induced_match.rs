@@ -0,0 +1,40 @@
+//! Integration tests for induced-subgraph matching.
+//!
+//! Verifies that `MatchLength::Induced` rejects matches whose gates are
+//! connected by edges the pattern does not have, while keeping isolated
+//! instances that `NeedleSubsetHaystack` also finds.
+
+use svql_common::{ConfigBuilder, MatchLength};
+use svql_subgraph::query_test;
+
+const fn subset_config(c: ConfigBuilder) -> ConfigBuilder {
+    c.match_length(MatchLength::NeedleSubsetHaystack)
+}
+
+const fn induced_config(c: ConfigBuilder) -> ConfigBuilder {
+    c.match_length(MatchLength::Induced)
+}
+
+query_test!(
+    name: test_and_tree_subset_on_cross_linked_tree,
+    needle: ("examples/fixtures/basic/and/verilog/small_and_tree.v", "small_and_tree"),
+    haystack: ("examples/fixtures/basic/and/verilog/and_tree_cross.v", "and_tree_cross"),
+    expect: 1,
+    config: subset_config
+);
+
+query_test!(
+    name: test_and_tree_induced_on_cross_linked_tree,
+    needle: ("examples/fixtures/basic/and/verilog/small_and_tree.v", "small_and_tree"),
+    haystack: ("examples/fixtures/basic/and/verilog/and_tree_cross.v", "and_tree_cross"),
+    expect: 0,
+    config: induced_config
+);
+
+query_test!(
+    name: test_and_tree_induced_on_isolated_tree,
+    needle: ("examples/fixtures/basic/and/verilog/small_and_tree.v", "small_and_tree"),
+    haystack: ("examples/fixtures/basic/and/verilog/small_and_tree.v", "small_and_tree"),
+    expect: 1,
+    config: induced_config
+);
@@ -0,0 +1,33 @@
+//! Integration tests for capping the number of enumerated matches.
+//!
+//! Verifies that the subgraph core stops at `max_matches` distinct results
+//! and is unaffected when the cap exceeds the total match count.
+
+use svql_common::{ConfigBuilder, MatchLength};
+use svql_subgraph::query_test;
+
+const fn first_match_config(c: ConfigBuilder) -> ConfigBuilder {
+    c.match_length(MatchLength::NeedleSubsetHaystack)
+        .max_matches(Some(1))
+}
+
+const fn loose_limit_config(c: ConfigBuilder) -> ConfigBuilder {
+    c.match_length(MatchLength::NeedleSubsetHaystack)
+        .max_matches(Some(10))
+}
+
+query_test!(
+    name: test_adffe_mux_enable_limited_to_one,
+    needle: ("examples/patterns/security/access_control/locked_reg/rtlil/async_mux.il", "async_mux"),
+    haystack: ("examples/fixtures/cwes/cwe1234/cwe1234_multi_width.v", "cwe1234_multi_width"),
+    expect: 1,
+    config: first_match_config
+);
+
+query_test!(
+    name: test_adffe_mux_enable_limit_above_total,
+    needle: ("examples/patterns/security/access_control/locked_reg/rtlil/async_mux.il", "async_mux"),
+    haystack: ("examples/fixtures/cwes/cwe1234/cwe1234_multi_width.v", "cwe1234_multi_width"),
+    expect: 5,
+    config: loose_limit_config
+);
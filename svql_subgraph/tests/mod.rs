@@ -3,4 +3,25 @@
 //! Focuses on raw graph matching performance and correctness
 //! without the high-level DSL orchestration.
 
+mod anchor_preference;
+mod assignment_json;
+mod bidir_ports;
+mod cancellation;
+mod commutative;
+mod const_ports;
+mod dedupe;
+mod degree_pruning;
+mod deterministic_order;
+mod export_match;
+mod ignore_cell_kinds;
+mod induced_match;
 mod locked_reg;
+mod match_limit;
+mod parallel_search;
+mod partial_match;
+mod progress;
+mod search_bounds;
+mod search_stats;
+mod streaming;
+mod verify_match;
+mod width_tolerant;
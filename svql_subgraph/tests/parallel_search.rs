@@ -0,0 +1,32 @@
+//! Integration tests for parallel anchor expansion.
+//!
+//! Verifies that running the search on the rayon pool yields the same
+//! deduplicated match counts as the sequential search.
+
+use svql_common::{ConfigBuilder, MatchLength};
+use svql_subgraph::query_test;
+
+const fn parallel_subset_config(c: ConfigBuilder) -> ConfigBuilder {
+    c.match_length(MatchLength::NeedleSubsetHaystack)
+        .parallel(true)
+}
+
+const fn parallel_partial_config(c: ConfigBuilder) -> ConfigBuilder {
+    c.match_length(MatchLength::SupersetNeedle).parallel(true)
+}
+
+query_test!(
+    name: test_adffe_mux_enable_multi_width_parallel,
+    needle: ("examples/patterns/security/access_control/locked_reg/rtlil/async_mux.il", "async_mux"),
+    haystack: ("examples/fixtures/cwes/cwe1234/cwe1234_multi_width.v", "cwe1234_multi_width"),
+    expect: 5,
+    config: parallel_subset_config
+);
+
+query_test!(
+    name: test_seq_2_sdffe_partial_on_seq_1_parallel,
+    needle: ("examples/fixtures/basic/ff/rtlil/seq_2_width_2_sdffe.il", "seq_2_width_2_sdffe"),
+    haystack: ("examples/fixtures/basic/ff/rtlil/seq_1_width_2_sdffe.il", "seq_1_width_2_sdffe"),
+    expect: 1,
+    config: parallel_partial_config
+);
@@ -0,0 +1,31 @@
+//! Integration tests for partial (superset-needle) pattern matching.
+//!
+//! Verifies that the subgraph core reports incomplete instances of a
+//! pattern only when partial matching is requested.
+
+use svql_common::{ConfigBuilder, MatchLength};
+use svql_subgraph::query_test;
+
+const fn exact_config(c: ConfigBuilder) -> ConfigBuilder {
+    c.match_length(MatchLength::Exact)
+}
+
+const fn partial_config(c: ConfigBuilder) -> ConfigBuilder {
+    c.match_length(MatchLength::SupersetNeedle)
+}
+
+query_test!(
+    name: test_seq_2_sdffe_exact_on_seq_1,
+    needle: ("examples/fixtures/basic/ff/rtlil/seq_2_width_2_sdffe.il", "seq_2_width_2_sdffe"),
+    haystack: ("examples/fixtures/basic/ff/rtlil/seq_1_width_2_sdffe.il", "seq_1_width_2_sdffe"),
+    expect: 0,
+    config: exact_config
+);
+
+query_test!(
+    name: test_seq_2_sdffe_partial_on_seq_1,
+    needle: ("examples/fixtures/basic/ff/rtlil/seq_2_width_2_sdffe.il", "seq_2_width_2_sdffe"),
+    haystack: ("examples/fixtures/basic/ff/rtlil/seq_1_width_2_sdffe.il", "seq_1_width_2_sdffe"),
+    expect: 1,
+    config: partial_config
+);
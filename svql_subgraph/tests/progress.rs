@@ -0,0 +1,53 @@
+//! Integration tests for progress reporting during a subgraph search.
+//!
+//! Verifies that a search reports its phases in order and that the final
+//! event agrees with the returned assignment set.
+
+use std::sync::{Arc, Mutex};
+
+use svql_common::{Config, MatchLength, ModuleConfig, YosysModule};
+use svql_subgraph::{ProgressEvent, SubgraphMatcher};
+
+#[test]
+fn test_progress_reports_phases() -> Result<(), Box<dyn std::error::Error>> {
+    let design_module = YosysModule::new(
+        "examples/fixtures/cwes/cwe1234/cwe1234_multi_width.v",
+        "cwe1234_multi_width",
+    )?;
+    let needle_module = YosysModule::new(
+        "examples/patterns/security/access_control/locked_reg/rtlil/async_mux.il",
+        "async_mux",
+    )?;
+
+    let design = design_module.import_design(&ModuleConfig::default())?;
+    let needle = needle_module.import_design(&ModuleConfig::default())?;
+
+    let config = Config::builder()
+        .match_length(MatchLength::NeedleSubsetHaystack)
+        .build();
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&events);
+    let assignment_set = SubgraphMatcher::enumerate_with_progress(
+        &needle,
+        &design,
+        needle_module.module_name().to_owned(),
+        design_module.module_name().to_owned(),
+        &config,
+        Arc::new(move |event| sink.lock().unwrap().push(event)),
+    );
+
+    let events = events.lock().unwrap();
+    assert_eq!(events.first(), Some(&ProgressEvent::Indexing));
+    assert!(matches!(
+        events.get(1),
+        Some(ProgressEvent::Anchoring { candidates }) if *candidates > 0
+    ));
+    assert_eq!(
+        events.last(),
+        Some(&ProgressEvent::Finished {
+            matches: assignment_set.len()
+        })
+    );
+    Ok(())
+}
@@ -0,0 +1,76 @@
+//! Integration tests for bounding the breadth of a search.
+//!
+//! Verifies that the candidate and branch caps cut the search short, flag the
+//! result as truncated, and leave it untouched when they are not reached.
+
+use svql_common::{Config, MatchLength, ModuleConfig, YosysModule};
+use svql_subgraph::{AssignmentSet, SearchStats, SubgraphMatcher};
+
+/// Runs the `async_mux` needle against the multi-width CWE-1234 fixture.
+fn search(config: &Config) -> Result<(AssignmentSet, SearchStats), Box<dyn std::error::Error>> {
+    let design_module = YosysModule::new(
+        "examples/fixtures/cwes/cwe1234/cwe1234_multi_width.v",
+        "cwe1234_multi_width",
+    )?;
+    let needle_module = YosysModule::new(
+        "examples/patterns/security/access_control/locked_reg/rtlil/async_mux.il",
+        "async_mux",
+    )?;
+
+    let design = design_module.import_design(&ModuleConfig::default())?;
+    let needle = needle_module.import_design(&ModuleConfig::default())?;
+
+    Ok(SubgraphMatcher::enumerate_with_stats(
+        &needle,
+        &design,
+        needle_module.module_name().to_owned(),
+        design_module.module_name().to_owned(),
+        config,
+    ))
+}
+
+#[test]
+fn test_candidate_cap_truncates() -> Result<(), Box<dyn std::error::Error>> {
+    let config = Config::builder()
+        .match_length(MatchLength::NeedleSubsetHaystack)
+        .max_candidates_per_node(Some(1))
+        .build();
+
+    let (assignment_set, stats) = search(&config)?;
+
+    assert!(assignment_set.truncated);
+    assert!(stats.truncated);
+    assert!(assignment_set.len() < 5);
+    Ok(())
+}
+
+#[test]
+fn test_branch_cap_truncates() -> Result<(), Box<dyn std::error::Error>> {
+    let config = Config::builder()
+        .match_length(MatchLength::NeedleSubsetHaystack)
+        .max_total_nodes(Some(1))
+        .build();
+
+    let (assignment_set, stats) = search(&config)?;
+
+    assert!(assignment_set.truncated);
+    assert!(stats.truncated);
+    assert!(assignment_set.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_loose_caps_do_not_truncate() -> Result<(), Box<dyn std::error::Error>> {
+    let config = Config::builder()
+        .match_length(MatchLength::NeedleSubsetHaystack)
+        .max_candidates_per_node(Some(1_000))
+        .max_total_nodes(Some(1_000_000))
+        .build();
+
+    let (assignment_set, stats) = search(&config)?;
+
+    assert!(!assignment_set.truncated);
+    assert!(!stats.truncated);
+    assert_eq!(assignment_set.len(), 5);
+    Ok(())
+}
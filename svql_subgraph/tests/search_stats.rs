@@ -0,0 +1,72 @@
+//! Integration tests for search diagnostics.
+//!
+//! Verifies that the statistics reported alongside a search agree with the
+//! returned matches.
+
+use svql_common::{Config, MatchLength, ModuleConfig, YosysModule};
+use svql_subgraph::SubgraphMatcher;
+
+#[test]
+fn test_stats_agree_with_results() -> Result<(), Box<dyn std::error::Error>> {
+    let design_module = YosysModule::new(
+        "examples/fixtures/cwes/cwe1234/cwe1234_multi_width.v",
+        "cwe1234_multi_width",
+    )?;
+    let needle_module = YosysModule::new(
+        "examples/patterns/security/access_control/locked_reg/rtlil/async_mux.il",
+        "async_mux",
+    )?;
+
+    let design = design_module.import_design(&ModuleConfig::default())?;
+    let needle = needle_module.import_design(&ModuleConfig::default())?;
+
+    let config = Config::builder()
+        .match_length(MatchLength::NeedleSubsetHaystack)
+        .build();
+
+    let (assignment_set, stats) = SubgraphMatcher::enumerate_with_stats(
+        &needle,
+        &design,
+        needle_module.module_name().to_owned(),
+        design_module.module_name().to_owned(),
+        &config,
+    );
+
+    assert_eq!(stats.deduplicated_matches, assignment_set.len());
+    assert!(stats.raw_matches >= stats.deduplicated_matches);
+    assert!(stats.anchor_kind.is_some());
+    assert!(stats.anchor_candidates > 0);
+    assert!(stats.branches_explored > 0);
+    Ok(())
+}
+
+#[test]
+fn test_anchor_kind_matches_search() -> Result<(), Box<dyn std::error::Error>> {
+    let design = YosysModule::new(
+        "examples/fixtures/cwes/cwe1234/cwe1234_multi_width.v",
+        "cwe1234_multi_width",
+    )?
+    .import_design(&ModuleConfig::default())?;
+    let needle = YosysModule::new(
+        "examples/patterns/security/access_control/locked_reg/rtlil/async_mux.il",
+        "async_mux",
+    )?
+    .import_design(&ModuleConfig::default())?;
+
+    let config = Config::builder()
+        .match_length(MatchLength::NeedleSubsetHaystack)
+        .build();
+
+    let predicted = SubgraphMatcher::anchor_kind(&needle, &design, &config);
+    let (_, stats) = SubgraphMatcher::enumerate_with_stats(
+        &needle,
+        &design,
+        "async_mux".to_owned(),
+        "cwe1234_multi_width".to_owned(),
+        &config,
+    );
+
+    assert!(predicted.is_some());
+    assert_eq!(predicted, stats.anchor_kind);
+    Ok(())
+}
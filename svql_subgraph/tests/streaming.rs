@@ -0,0 +1,176 @@
+//! Integration tests for lazily streamed matches.
+//!
+//! Verifies that the iterator yields the same matches as the collecting
+//! search, honours the same bounds and can be stopped early.
+
+use prjunnamed_netlist::Design;
+use svql_common::{Config, GraphIndex, GraphNodeIdx, MatchLength, ModuleConfig, YosysModule};
+use svql_subgraph::{SingleAssignment, SubgraphMatcher};
+
+#[test]
+fn test_iterator_matches_enumeration_count() -> Result<(), Box<dyn std::error::Error>> {
+    let design_module = YosysModule::new(
+        "examples/fixtures/cwes/cwe1234/cwe1234_multi_width.v",
+        "cwe1234_multi_width",
+    )?;
+    let needle_module = YosysModule::new(
+        "examples/patterns/security/access_control/locked_reg/rtlil/async_mux.il",
+        "async_mux",
+    )?;
+
+    let design = design_module.import_design(&ModuleConfig::default())?;
+    let needle = needle_module.import_design(&ModuleConfig::default())?;
+    let needle_index = GraphIndex::build(&needle);
+    let haystack_index = GraphIndex::build(&design);
+
+    let config = Config::builder()
+        .match_length(MatchLength::NeedleSubsetHaystack)
+        .build();
+
+    let collected = SubgraphMatcher::enumerate_with_indices(
+        &needle,
+        &design,
+        &needle_index,
+        &haystack_index,
+        needle_module.module_name().to_owned(),
+        design_module.module_name().to_owned(),
+        &config,
+    );
+
+    let streamed: Vec<_> = SubgraphMatcher::iter_with_indices(
+        &needle,
+        &design,
+        &needle_index,
+        &haystack_index,
+        needle_module.module_name().to_owned(),
+        design_module.module_name().to_owned(),
+        &config,
+    )
+    .collect();
+
+    assert_eq!(streamed.len(), collected.len());
+
+    let first_two = SubgraphMatcher::iter_with_indices(
+        &needle,
+        &design,
+        &needle_index,
+        &haystack_index,
+        needle_module.module_name().to_owned(),
+        design_module.module_name().to_owned(),
+        &config,
+    )
+    .take(2)
+    .count();
+    assert_eq!(first_two, 2);
+    Ok(())
+}
+
+/// Loads the `async_mux` pattern and the `cwe1234_multi_width` design.
+fn load_designs() -> Result<(Design, Design), Box<dyn std::error::Error>> {
+    let design = YosysModule::new(
+        "examples/fixtures/cwes/cwe1234/cwe1234_multi_width.v",
+        "cwe1234_multi_width",
+    )?
+    .import_design(&ModuleConfig::default())?;
+    let needle = YosysModule::new(
+        "examples/patterns/security/access_control/locked_reg/rtlil/async_mux.il",
+        "async_mux",
+    )?
+    .import_design(&ModuleConfig::default())?;
+    Ok((needle, design))
+}
+
+/// Returns the assignments' mappings in a canonical order.
+fn sorted_mappings<'a>(
+    assignments: impl IntoIterator<Item = &'a SingleAssignment>,
+) -> Vec<Vec<(GraphNodeIdx, GraphNodeIdx)>> {
+    let mut mappings: Vec<Vec<(GraphNodeIdx, GraphNodeIdx)>> = assignments
+        .into_iter()
+        .map(|assignment| {
+            let mut pairs: Vec<_> = assignment
+                .needle_mapping()
+                .iter()
+                .map(|(&needle, &haystack)| (needle, haystack))
+                .collect();
+            pairs.sort_unstable();
+            pairs
+        })
+        .collect();
+    mappings.sort_unstable();
+    mappings
+}
+
+#[test]
+fn test_iterator_keeps_same_representatives() -> Result<(), Box<dyn std::error::Error>> {
+    let (needle, design) = load_designs()?;
+    let needle_index = GraphIndex::build(&needle);
+    let haystack_index = GraphIndex::build(&design);
+
+    let config = Config::builder()
+        .match_length(MatchLength::NeedleSubsetHaystack)
+        .build();
+
+    let collected = SubgraphMatcher::enumerate_with_indices(
+        &needle,
+        &design,
+        &needle_index,
+        &haystack_index,
+        "async_mux".to_owned(),
+        "cwe1234_multi_width".to_owned(),
+        &config,
+    );
+    let streamed: Vec<_> = SubgraphMatcher::iter_with_indices(
+        &needle,
+        &design,
+        &needle_index,
+        &haystack_index,
+        "async_mux".to_owned(),
+        "cwe1234_multi_width".to_owned(),
+        &config,
+    )
+    .collect();
+
+    assert_eq!(
+        sorted_mappings(&streamed),
+        sorted_mappings(&collected.items)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_iterator_honours_max_total_nodes() -> Result<(), Box<dyn std::error::Error>> {
+    let (needle, design) = load_designs()?;
+    let needle_index = GraphIndex::build(&needle);
+    let haystack_index = GraphIndex::build(&design);
+
+    let config = Config::builder()
+        .match_length(MatchLength::NeedleSubsetHaystack)
+        .max_total_nodes(Some(1))
+        .build();
+
+    let collected = SubgraphMatcher::enumerate_with_indices(
+        &needle,
+        &design,
+        &needle_index,
+        &haystack_index,
+        "async_mux".to_owned(),
+        "cwe1234_multi_width".to_owned(),
+        &config,
+    );
+    let streamed = SubgraphMatcher::iter_with_indices(
+        &needle,
+        &design,
+        &needle_index,
+        &haystack_index,
+        "async_mux".to_owned(),
+        "cwe1234_multi_width".to_owned(),
+        &config,
+    )
+    .count();
+
+    // One gate step is not enough to complete a match on either path
+    assert!(collected.truncated);
+    assert_eq!(streamed, collected.len());
+    assert_eq!(streamed, 0);
+    Ok(())
+}
@@ -0,0 +1,67 @@
+//! Integration tests for functional verification of matches.
+//!
+//! Verifies that a structural match of the same logic is proved equivalent,
+//! and that a match binding a pattern input to a constant is refuted.
+
+use svql_common::{Config, GraphIndex, MatchLength, ModuleConfig, YosysModule};
+use svql_subgraph::{FunctionalVerdict, SubgraphMatcher, verify_match_functional};
+
+/// Returns the verdict of every match of `and_gate` in the given haystack.
+fn verdicts(
+    haystack_path: &str,
+    haystack_module: &str,
+    config: &Config,
+) -> Result<Vec<FunctionalVerdict>, Box<dyn std::error::Error>> {
+    let design_module = YosysModule::new(haystack_path, haystack_module)?;
+    let needle_module =
+        YosysModule::new("examples/patterns/basic/and/verilog/and_gate.v", "and_gate")?;
+
+    let design = design_module.import_design(&ModuleConfig::default())?;
+    let needle = needle_module.import_design(&ModuleConfig::default())?;
+
+    let assignments = SubgraphMatcher::enumerate_all(
+        &needle,
+        &design,
+        needle_module.module_name().to_owned(),
+        design_module.module_name().to_owned(),
+        config,
+    );
+
+    let needle_index = GraphIndex::build(&needle);
+    let haystack_index = GraphIndex::build(&design);
+    Ok(assignments
+        .items
+        .iter()
+        .map(|assignment| verify_match_functional(assignment, &needle_index, &haystack_index))
+        .collect())
+}
+
+#[test]
+fn test_verify_proves_structural_and_matches() -> Result<(), Box<dyn std::error::Error>> {
+    let config = Config::builder()
+        .match_length(MatchLength::NeedleSubsetHaystack)
+        .build();
+    let verdicts = verdicts(
+        "examples/fixtures/basic/and/verilog/small_and_tree.v",
+        "small_and_tree",
+        &config,
+    )?;
+
+    assert_eq!(verdicts.len(), 3);
+    assert!(verdicts.iter().all(|v| *v == FunctionalVerdict::Proved));
+    Ok(())
+}
+
+#[test]
+fn test_verify_disproves_input_tied_to_const() -> Result<(), Box<dyn std::error::Error>> {
+    // `y = a & 1'b0` only matches `y = a & b` structurally
+    let config = Config::builder().pattern_vars_match_design_consts(true).build();
+    let verdicts = verdicts(
+        "examples/fixtures/basic/and/verilog/and_const_tied.v",
+        "and_const_tied",
+        &config,
+    )?;
+
+    assert_eq!(verdicts, vec![FunctionalVerdict::Disproved]);
+    Ok(())
+}
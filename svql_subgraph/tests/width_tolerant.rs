@@ -0,0 +1,31 @@
+//! Integration tests for width-tolerant exact matching.
+//!
+//! Verifies that a narrow pattern only matches a structurally identical but
+//! wider design when width tolerance is enabled.
+
+use svql_common::{ConfigBuilder, MatchLength};
+use svql_subgraph::query_test;
+
+const fn exact_config(c: ConfigBuilder) -> ConfigBuilder {
+    c.match_length(MatchLength::Exact)
+}
+
+const fn width_tolerant_config(c: ConfigBuilder) -> ConfigBuilder {
+    c.match_length(MatchLength::Exact).width_tolerant(true)
+}
+
+query_test!(
+    name: test_sdff_exact_on_width_2,
+    needle: ("examples/patterns/basic/ff/rtlil/sdff.il", "sdff"),
+    haystack: ("examples/fixtures/basic/ff/rtlil/seq_1_width_2_sdffe.il", "seq_1_width_2_sdffe"),
+    expect: 0,
+    config: exact_config
+);
+
+query_test!(
+    name: test_sdff_width_tolerant_on_width_2,
+    needle: ("examples/patterns/basic/ff/rtlil/sdff.il", "sdff"),
+    haystack: ("examples/fixtures/basic/ff/rtlil/seq_1_width_2_sdffe.il", "seq_1_width_2_sdffe"),
+    expect: 1,
+    config: width_tolerant_config
+);